@@ -0,0 +1,109 @@
+//! Multiplicative blinding of Edwards points.
+//!
+//! A client blinds its input point `P` as `[r]P` with a secret non-zero `r`, a server
+//! evaluates some function on the blinded point, e.g. `[k][r]P` in an OPRF, and the client
+//! unblinds the answer with `r⁻¹` to get `[k]P` without the server ever seeing `P`.
+use crate::{EdwardsPoint, Scalar};
+use rand_core::{CryptoRng, RngCore};
+use std::fmt::{self, Debug, Formatter};
+use subtle::CtOption;
+
+/// A blinding factor `r` together with its cached inverse.
+///
+/// Unblinding exactly undoes blinding for points in the prime-order subgroup.
+/// For a point with a torsion component the torsion part may change,
+/// so clear the cofactor or check [`EdwardsPoint::is_torsion_free`] first.
+#[derive(Clone)]
+pub struct Blinder {
+    r: Scalar,
+    r_inv: Scalar,
+}
+
+impl Debug for Blinder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blinder").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Blinder {
+    fn zeroize(&mut self) {
+        self.r.zeroize();
+        self.r_inv.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Blinder {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Blinder {}
+
+impl Blinder {
+    /// Use `r` as the blinding factor, which fails if `r` is zero.
+    pub fn new(r: Scalar) -> CtOption<Self> {
+        let is_zero = r.is_zero();
+        CtOption::new(
+            Self {
+                r,
+                r_inv: r.invert(),
+            },
+            !is_zero,
+        )
+    }
+
+    /// Pick a fresh random non-zero blinding factor.
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            let blinder = Self::new(Scalar::random(&mut *rng));
+            if blinder.is_some().into() {
+                return blinder.unwrap();
+            }
+        }
+    }
+
+    /// Blind a point, computing `[r]P`.
+    pub fn blind(&self, point: &EdwardsPoint) -> EdwardsPoint {
+        point * self.r
+    }
+
+    /// Unblind a point, computing `[r⁻¹]P`.
+    pub fn unblind(&self, point: &EdwardsPoint) -> EdwardsPoint {
+        point * self.r_inv
+    }
+
+    /// The blinding factor `r`
+    pub fn factor(&self) -> Scalar {
+        self.r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn blind_unblind() {
+        let blinder = Blinder::random(&mut OsRng);
+        let p = EdwardsPoint::GENERATOR * Scalar::random(&mut OsRng);
+        let blinded = blinder.blind(&p);
+        assert_ne!(blinded, p);
+        assert_eq!(blinder.unblind(&blinded), p);
+
+        // Blinding commutes with the evaluation, as in an OPRF
+        let k = Scalar::random(&mut OsRng);
+        assert_eq!(blinder.unblind(&(blinded * k)), p * k);
+    }
+
+    #[test]
+    fn rejects_zero() {
+        assert!(bool::from(Blinder::new(Scalar::ZERO).is_none()));
+        let blinder = Blinder::new(Scalar::from(5u8)).unwrap();
+        assert_eq!(blinder.factor(), Scalar::from(5u8));
+    }
+}
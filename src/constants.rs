@@ -1,5 +1,6 @@
 use crate::*;
 use crate::{decaf::DecafPoint, Scalar};
+use elliptic_curve::bigint::U448;
 
 pub const DECAF_BASEPOINT: DecafPoint = DecafPoint(curve::twedwards::extended::ExtendedPoint {
     X: TWISTED_EDWARDS_BASE_POINT.X,
@@ -12,7 +13,6 @@ pub const DECAF_BASEPOINT: DecafPoint = DecafPoint(curve::twedwards::extended::E
 /// $$
 /// \ell = 2^\{446\} + 0x8335dc163bb124b65129c96fde933d8d723a70aadc873d6d54a7bb0d.
 /// $$
-pub const BASEPOINT_ORDER: Scalar = Scalar([
-    0xab5844f3, 0x2378c292, 0x8dc58f55, 0x216cc272, 0xaed63690, 0xc44edb49, 0x7cca23e9, 0xffffffff,
-    0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x3fffffff,
-]);
+pub const BASEPOINT_ORDER: Scalar = Scalar(U448::from_be_hex(
+        "3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3",
+    ));
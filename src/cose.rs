@@ -0,0 +1,379 @@
+//! COSE_Key encoding of keys, as specified for Octet Key Pairs in [RFC 9053 § 7.2].
+//!
+//! A key is the CBOR map `{1: 1, 3: -8, -1: 7, -2: x}` of key type OKP, algorithm
+//! EdDSA, curve Ed448 and the public key bytes, and a private key adds `-4: d`,
+//! the secret seed. X448 keys are `{1: 1, -1: 5, -2: x}` without an algorithm,
+//! as the same key may be used with any of the ECDH algorithms. Keys are written
+//! in the deterministic encoding of [RFC 8949 § 4.2], so equal keys always encode
+//! to equal bytes.
+//!
+//! [RFC 9053 § 7.2]: https://www.rfc-editor.org/rfc/rfc9053#section-7.2
+//! [RFC 8949 § 4.2]: https://www.rfc-editor.org/rfc/rfc8949#section-4.2
+use crate::x448::{self, KEY_LENGTH};
+use crate::{SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH};
+use ciborium::Value;
+use std::fmt::{self, Display, Formatter};
+
+const LABEL_KTY: i128 = 1;
+const LABEL_ALG: i128 = 3;
+const LABEL_CRV: i128 = -1;
+const LABEL_X: i128 = -2;
+const LABEL_D: i128 = -4;
+
+const KTY_OKP: i128 = 1;
+const ALG_EDDSA: i128 = -8;
+const CRV_X448: i128 = 5;
+const CRV_ED448: i128 = 7;
+
+/// Errors produced when decoding a COSE_Key
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CoseKeyError {
+    /// The bytes are not a single CBOR map with integer labels and no duplicates
+    InvalidEncoding,
+    /// The key type is not OKP
+    UnsupportedKeyType,
+    /// The curve is not the curve of the requested key type
+    UnsupportedCurve,
+    /// The algorithm does not match the curve
+    UnsupportedAlgorithm,
+    /// `x` is missing, or is not a valid public key or does not match `d`
+    InvalidKey,
+    /// A private key was requested but `d` is absent
+    MissingPrivateKey,
+}
+
+impl Display for CoseKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CoseKeyError::InvalidEncoding => write!(f, "COSE_Key encoding is invalid"),
+            CoseKeyError::UnsupportedKeyType => write!(f, "key type is not OKP"),
+            CoseKeyError::UnsupportedCurve => write!(f, "curve is not supported"),
+            CoseKeyError::UnsupportedAlgorithm => write!(f, "algorithm is not supported"),
+            CoseKeyError::InvalidKey => write!(f, "key is invalid"),
+            CoseKeyError::MissingPrivateKey => write!(f, "private key is missing"),
+        }
+    }
+}
+
+impl std::error::Error for CoseKeyError {}
+
+/// The members of an OKP COSE_Key this crate reads
+struct OkpKey {
+    alg: Option<i128>,
+    crv: i128,
+    x: Option<Vec<u8>>,
+    d: Option<Vec<u8>>,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for OkpKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.d);
+    }
+}
+
+impl OkpKey {
+    /// Parse a COSE_Key, ignoring members other than those of [`OkpKey`]
+    fn from_bytes(mut bytes: &[u8]) -> Result<Self, CoseKeyError> {
+        let value: Value =
+            ciborium::from_reader(&mut bytes).map_err(|_| CoseKeyError::InvalidEncoding)?;
+        if !bytes.is_empty() {
+            return Err(CoseKeyError::InvalidEncoding);
+        }
+        let entries = match value {
+            Value::Map(entries) => entries,
+            _ => return Err(CoseKeyError::InvalidEncoding),
+        };
+
+        let mut labels = Vec::with_capacity(entries.len());
+        let mut kty = None;
+        let mut key = OkpKey {
+            alg: None,
+            crv: 0,
+            x: None,
+            d: None,
+        };
+        let mut crv = None;
+        for (label, value) in entries {
+            let label = label
+                .as_integer()
+                .map(i128::from)
+                .ok_or(CoseKeyError::InvalidEncoding)?;
+            if labels.contains(&label) {
+                return Err(CoseKeyError::InvalidEncoding);
+            }
+            labels.push(label);
+
+            match label {
+                LABEL_KTY => kty = Some(integer(value)?),
+                LABEL_ALG => key.alg = Some(integer(value)?),
+                LABEL_CRV => crv = Some(integer(value)?),
+                LABEL_X => key.x = Some(byte_string(value)?),
+                LABEL_D => key.d = Some(byte_string(value)?),
+                _ => {}
+            }
+        }
+
+        if kty != Some(KTY_OKP) {
+            return Err(CoseKeyError::UnsupportedKeyType);
+        }
+        key.crv = crv.ok_or(CoseKeyError::UnsupportedCurve)?;
+        Ok(key)
+    }
+
+    /// Check the curve and, if `alg` is given, the algorithm, and return `x`
+    fn public_bytes<const N: usize>(
+        &self,
+        crv: i128,
+        alg: Option<i128>,
+    ) -> Result<[u8; N], CoseKeyError> {
+        if self.crv != crv {
+            return Err(CoseKeyError::UnsupportedCurve);
+        }
+        if alg.is_some() && self.alg.is_some() && self.alg != alg {
+            return Err(CoseKeyError::UnsupportedAlgorithm);
+        }
+        let x = self.x.as_deref().ok_or(CoseKeyError::InvalidKey)?;
+        x.try_into().map_err(|_| CoseKeyError::InvalidKey)
+    }
+
+    fn private_bytes<const N: usize>(&self) -> Result<[u8; N], CoseKeyError> {
+        let d = self.d.as_deref().ok_or(CoseKeyError::MissingPrivateKey)?;
+        d.try_into().map_err(|_| CoseKeyError::InvalidKey)
+    }
+}
+
+fn integer(value: Value) -> Result<i128, CoseKeyError> {
+    value
+        .as_integer()
+        .map(i128::from)
+        .ok_or(CoseKeyError::InvalidEncoding)
+}
+
+fn byte_string(value: Value) -> Result<Vec<u8>, CoseKeyError> {
+    value
+        .into_bytes()
+        .map_err(|_| CoseKeyError::InvalidEncoding)
+}
+
+/// Encode an OKP COSE_Key, the labels are in deterministic order
+fn encode(crv: i128, alg: Option<i128>, x: &[u8], d: Option<&[u8]>) -> Vec<u8> {
+    let int = |i: i128| Value::Integer(i.try_into().expect("label fits in a CBOR integer"));
+    let mut entries = vec![(int(LABEL_KTY), int(KTY_OKP))];
+    if let Some(alg) = alg {
+        entries.push((int(LABEL_ALG), int(alg)));
+    }
+    entries.push((int(LABEL_CRV), int(crv)));
+    entries.push((int(LABEL_X), Value::Bytes(x.to_vec())));
+    if let Some(d) = d {
+        entries.push((int(LABEL_D), Value::Bytes(d.to_vec())));
+    }
+    #[allow(unused_mut)]
+    let mut value = Value::Map(entries);
+
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&value, &mut bytes).expect("writing to a Vec can't fail");
+
+    #[cfg(feature = "zeroize")]
+    if let Value::Map(entries) = &mut value {
+        for (_, member) in entries.iter_mut() {
+            if let Value::Bytes(b) = member {
+                zeroize::Zeroize::zeroize(b);
+            }
+        }
+    }
+    drop(value);
+    bytes
+}
+
+impl VerifyingKey {
+    /// Encode this key as a COSE_Key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        encode(CRV_ED448, Some(ALG_EDDSA), self.as_bytes(), None)
+    }
+
+    /// Decode a COSE_Key, which may also be a private key
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, CoseKeyError> {
+        let key = OkpKey::from_bytes(bytes)?;
+        let x = key.public_bytes::<PUBLIC_KEY_LENGTH>(CRV_ED448, Some(ALG_EDDSA))?;
+        VerifyingKey::from_bytes(&x).map_err(|_| CoseKeyError::InvalidKey)
+    }
+}
+
+impl SigningKey {
+    /// Encode this key as a COSE_Key including the private key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        encode(
+            CRV_ED448,
+            Some(ALG_EDDSA),
+            self.verifying_key.as_bytes(),
+            Some(self.as_bytes()),
+        )
+    }
+
+    /// Decode a private COSE_Key, `x` must be the public key of `d`
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, CoseKeyError> {
+        let key = OkpKey::from_bytes(bytes)?;
+        let x = key.public_bytes::<PUBLIC_KEY_LENGTH>(CRV_ED448, Some(ALG_EDDSA))?;
+        #[allow(unused_mut)]
+        let mut d = key.private_bytes::<SECRET_KEY_LENGTH>()?;
+        let signing_key = SigningKey::from_bytes(&d);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut d);
+        if signing_key.verifying_key.as_bytes() != &x {
+            return Err(CoseKeyError::InvalidKey);
+        }
+        Ok(signing_key)
+    }
+}
+
+impl x448::PublicKey {
+    /// Encode this key as a COSE_Key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        encode(CRV_X448, None, self.as_bytes(), None)
+    }
+
+    /// Decode a COSE_Key, which may also be a private key
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, CoseKeyError> {
+        let key = OkpKey::from_bytes(bytes)?;
+        Ok(key.public_bytes::<KEY_LENGTH>(CRV_X448, None)?.into())
+    }
+}
+
+impl x448::StaticSecret {
+    /// Encode this key as a COSE_Key including the private key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        let public = x448::PublicKey::from(self);
+        encode(CRV_X448, None, public.as_bytes(), Some(self.as_bytes()))
+    }
+
+    /// Decode a private COSE_Key, `x` must be the public key of `d`
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, CoseKeyError> {
+        let key = OkpKey::from_bytes(bytes)?;
+        let x = key.public_bytes::<KEY_LENGTH>(CRV_X448, None)?;
+        #[allow(unused_mut)]
+        let mut d = key.private_bytes::<KEY_LENGTH>()?;
+        let secret = x448::StaticSecret::from(d);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut d);
+        if x448::PublicKey::from(&secret).as_bytes() != &x {
+            return Err(CoseKeyError::InvalidKey);
+        }
+        Ok(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const SEED: [u8; 57] = hex!("6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b");
+    const PUBLIC: [u8; 57] = hex!("5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180");
+
+    #[test]
+    fn round_trip() {
+        let key = SigningKey::from_bytes(&SEED);
+        let public = key.verifying_key();
+
+        // a4 01 01 03 27 20 07 21 58 39 x
+        let mut expected = hex!("a40101032720072158 39").to_vec();
+        expected.extend_from_slice(&PUBLIC);
+        assert_eq!(public.to_cose_key(), expected);
+        assert_eq!(VerifyingKey::from_cose_key(&expected).unwrap(), public);
+
+        let encoded = key.to_cose_key();
+        assert_eq!(encoded[0], 0xa5);
+        assert_eq!(SigningKey::from_cose_key(&encoded).unwrap(), key);
+        assert_eq!(VerifyingKey::from_cose_key(&encoded).unwrap(), public);
+        assert_eq!(
+            SigningKey::from_cose_key(&expected),
+            Err(CoseKeyError::MissingPrivateKey)
+        );
+    }
+
+    #[test]
+    fn x448() {
+        let secret = x448::StaticSecret::from([7u8; 56]);
+        let public = x448::PublicKey::from(&secret);
+
+        // a3 01 01 20 05 21 58 38 x
+        let mut expected = hex!("a301012005215838").to_vec();
+        expected.extend_from_slice(public.as_bytes());
+        assert_eq!(public.to_cose_key(), expected);
+        assert_eq!(x448::PublicKey::from_cose_key(&expected).unwrap(), public);
+
+        let encoded = secret.to_cose_key();
+        let decoded = x448::StaticSecret::from_cose_key(&encoded).unwrap();
+        assert_eq!(decoded.as_bytes(), secret.as_bytes());
+        assert_eq!(
+            VerifyingKey::from_cose_key(&expected),
+            Err(CoseKeyError::UnsupportedCurve)
+        );
+    }
+
+    #[test]
+    fn decodes_other_layouts() {
+        // No algorithm, a kid, and the labels out of order
+        let mut bytes = hex!("a4 21 5839").to_vec();
+        bytes.extend_from_slice(&PUBLIC);
+        bytes.extend_from_slice(&hex!("02 43 6b6964 01 01 20 07"));
+        assert_eq!(
+            VerifyingKey::from_cose_key(&bytes).unwrap().to_bytes(),
+            PUBLIC
+        );
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let public = SigningKey::from_bytes(&SEED).verifying_key();
+        let encoded = public.to_cose_key();
+
+        let mut bad = encoded.clone();
+        bad[2] = 0x02;
+        assert_eq!(
+            VerifyingKey::from_cose_key(&bad),
+            Err(CoseKeyError::UnsupportedKeyType)
+        );
+        let mut bad = encoded.clone();
+        bad[4] = 0x26;
+        assert_eq!(
+            VerifyingKey::from_cose_key(&bad),
+            Err(CoseKeyError::UnsupportedAlgorithm)
+        );
+        // Ed25519
+        let mut bad = encoded.clone();
+        bad[6] = 0x06;
+        assert_eq!(
+            VerifyingKey::from_cose_key(&bad),
+            Err(CoseKeyError::UnsupportedCurve)
+        );
+        // Duplicate label, trailing bytes and truncation
+        let mut bad = encoded.clone();
+        bad[3] = 0x01;
+        assert_eq!(
+            VerifyingKey::from_cose_key(&bad),
+            Err(CoseKeyError::InvalidEncoding)
+        );
+        let mut bad = encoded.clone();
+        bad.push(0);
+        assert_eq!(
+            VerifyingKey::from_cose_key(&bad),
+            Err(CoseKeyError::InvalidEncoding)
+        );
+        assert_eq!(
+            VerifyingKey::from_cose_key(&encoded[..encoded.len() - 1]),
+            Err(CoseKeyError::InvalidEncoding)
+        );
+
+        // x of another key with the seed of this one
+        let mut bad = hex!("a5").to_vec();
+        bad.extend_from_slice(&encoded[1..]);
+        bad.extend_from_slice(&hex!("23 5839"));
+        bad.extend_from_slice(&[1u8; 57]);
+        assert_eq!(
+            SigningKey::from_cose_key(&bad),
+            Err(CoseKeyError::InvalidKey)
+        );
+    }
+}
@@ -1,8 +1,19 @@
-use crate::curve::edwards::EdwardsPoint;
-use crate::field::FieldElement;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use std::ops::{Mul, Neg};
 
-// Affine point on untwisted curve
+use crate::curve::edwards::{CompressedEdwardsY, EdwardsPoint, SignConvention};
+use crate::field::{FieldElement, Scalar};
+use elliptic_curve::{
+    generic_array::typenum::U112,
+    group::{GroupEncoding, UncompressedEncoding},
+};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// The uncompressed encoding of a point, `x || y` with each coordinate in
+/// 56 little-endian bytes
+#[allow(deprecated)]
+pub type UncompressedEdwardsBytes = elliptic_curve::generic_array::GenericArray<u8, U112>;
+
+/// A point on the untwisted Edwards curve in affine coordinates `(x, y)`
 #[derive(Copy, Clone, Debug)]
 pub struct AffinePoint {
     pub(crate) x: FieldElement,
@@ -32,6 +43,140 @@ impl PartialEq for AffinePoint {
 
 impl Eq for AffinePoint {}
 
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<AffinePoint> for EdwardsPoint {
+    fn from(point: AffinePoint) -> Self {
+        point.to_edwards()
+    }
+}
+
+impl From<&AffinePoint> for EdwardsPoint {
+    fn from(point: &AffinePoint) -> Self {
+        point.to_edwards()
+    }
+}
+
+impl From<EdwardsPoint> for AffinePoint {
+    fn from(point: EdwardsPoint) -> Self {
+        point.to_affine()
+    }
+}
+
+impl From<&EdwardsPoint> for AffinePoint {
+    fn from(point: &EdwardsPoint) -> Self {
+        point.to_affine()
+    }
+}
+
+impl GroupEncoding for AffinePoint {
+    type Repr = <EdwardsPoint as GroupEncoding>::Repr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EdwardsPoint::from_bytes(bytes).map(|point| point.to_affine())
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.compress().0.into()
+    }
+}
+
+/// Serializes the compressed encoding, like [`EdwardsPoint`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for AffinePoint {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.compress().serialize(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AffinePoint {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let compressed = CompressedEdwardsY::deserialize(d)?;
+        Option::<AffinePoint>::from(compressed.decompress_to_affine())
+            .ok_or_else(|| serde::de::Error::custom("invalid point"))
+    }
+}
+
+/// Decoding rejects non-canonical coordinates, and unless unchecked, points off the curve
+impl UncompressedEncoding for AffinePoint {
+    type Uncompressed = UncompressedEdwardsBytes;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed_unchecked(bytes).and_then(|point| {
+            let is_on_curve = point.is_on_curve();
+            CtOption::new(point, is_on_curve)
+        })
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        let mut x_bytes = [0u8; 56];
+        let mut y_bytes = [0u8; 56];
+        x_bytes.copy_from_slice(&bytes[..56]);
+        y_bytes.copy_from_slice(&bytes[56..]);
+        let x = FieldElement::from_bytes(&x_bytes);
+        let y = FieldElement::from_bytes(&y_bytes);
+        let is_canonical = x.to_bytes().ct_eq(&x_bytes) & y.to_bytes().ct_eq(&y_bytes);
+        CtOption::new(AffinePoint { x, y }, is_canonical)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        let mut bytes = UncompressedEdwardsBytes::default();
+        bytes[..56].copy_from_slice(&self.x.to_bytes());
+        bytes[56..].copy_from_slice(&self.y.to_bytes());
+        bytes
+    }
+}
+
+impl Neg for &AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        AffinePoint {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl Neg for AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        -&self
+    }
+}
+
+impl<'b> Mul<&'b Scalar> for &AffinePoint {
+    type Output = EdwardsPoint;
+
+    fn mul(self, scalar: &'b Scalar) -> EdwardsPoint {
+        self.to_edwards() * scalar
+    }
+}
+
+define_mul_variants!(LHS = AffinePoint, RHS = Scalar, Output = EdwardsPoint);
+define_mul_variants!(LHS = Scalar, RHS = AffinePoint, Output = EdwardsPoint);
+
+impl<'b> Mul<&'b AffinePoint> for &Scalar {
+    type Output = EdwardsPoint;
+
+    fn mul(self, point: &'b AffinePoint) -> EdwardsPoint {
+        point * self
+    }
+}
+
 impl AffinePoint {
     /// The identity point
     pub const IDENTITY: AffinePoint = AffinePoint {
@@ -39,6 +184,17 @@ impl AffinePoint {
         y: FieldElement::ONE,
     };
 
+    /// The generator, [`EdwardsPoint::GENERATOR`] in affine coordinates
+    pub const GENERATOR: AffinePoint = AffinePoint {
+        x: crate::GOLDILOCKS_BASE_POINT.X,
+        y: crate::GOLDILOCKS_BASE_POINT.Y,
+    };
+
+    /// Whether this is the identity point
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::IDENTITY)
+    }
+
     pub(crate) fn isogeny(&self) -> Self {
         let x = self.x;
         let y = self.y;
@@ -70,6 +226,32 @@ impl AffinePoint {
         }
     }
 
+    /// Check that `x^2 + y^2 = 1 + d·x^2·y^2` in constant time.
+    pub fn is_on_curve(&self) -> Choice {
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let lhs = xx + yy;
+        let rhs = FieldElement::ONE + FieldElement::EDWARDS_D * xx * yy;
+        lhs.ct_eq(&rhs)
+    }
+
+    /// Compress this point, storing y and the sign of x.
+    pub fn compress(&self) -> CompressedEdwardsY {
+        self.compress_with_sign(SignConvention::default())
+    }
+
+    /// Compress this point, encoding the sign of \\(x\\) with the given convention.
+    pub fn compress_with_sign(&self, convention: SignConvention) -> CompressedEdwardsY {
+        let mut compressed_bytes = [0u8; 57];
+
+        let sign = convention.is_negative(&self.x).unwrap_u8();
+
+        let y_bytes = self.y.to_bytes();
+        compressed_bytes[..y_bytes.len()].copy_from_slice(&y_bytes[..]);
+        *compressed_bytes.last_mut().unwrap() = sign << 7;
+        CompressedEdwardsY(compressed_bytes)
+    }
+
     /// Convert to edwards extended point
     pub fn to_edwards(&self) -> EdwardsPoint {
         EdwardsPoint {
@@ -90,3 +272,73 @@ impl AffinePoint {
         self.y.to_bytes()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn constants() {
+        assert_eq!(AffinePoint::GENERATOR, EdwardsPoint::GENERATOR.to_affine());
+        assert_eq!(AffinePoint::GENERATOR.to_edwards(), EdwardsPoint::GENERATOR);
+        assert!(bool::from(AffinePoint::GENERATOR.is_on_curve()));
+        assert!(bool::from(AffinePoint::IDENTITY.is_identity()));
+        assert!(bool::from(!AffinePoint::GENERATOR.is_identity()));
+
+        let s = Scalar::random(&mut OsRng);
+        assert_eq!(s * AffinePoint::GENERATOR, EdwardsPoint::GENERATOR * s);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let point = (EdwardsPoint::GENERATOR * Scalar::random(&mut OsRng)).to_affine();
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, serde_json::to_string(&point.compress()).unwrap());
+        assert_eq!(serde_json::from_str::<AffinePoint>(&json).unwrap(), point);
+        let bare = serde_bare::to_vec(&point).unwrap();
+        assert_eq!(bare.len(), 57);
+        assert_eq!(serde_bare::from_slice::<AffinePoint>(&bare).unwrap(), point);
+
+        // The identity with the sign bit set decompresses, but is not canonical
+        let mut bytes = EdwardsPoint::IDENTITY.compress().0;
+        bytes[56] = 0x80;
+        assert!(bool::from(CompressedEdwardsY(bytes).decompress().is_some()));
+        let json = serde_json::to_string(&hex::encode(bytes)).unwrap();
+        assert!(serde_json::from_str::<AffinePoint>(&json).is_err());
+        assert!(serde_json::from_str::<EdwardsPoint>(&json).is_err());
+        assert!(serde_json::from_str::<CompressedEdwardsY>(&json).is_err());
+    }
+
+    #[test]
+    fn uncompressed_encoding() {
+        let point = (EdwardsPoint::GENERATOR * Scalar::random(&mut OsRng)).to_affine();
+        let bytes = point.to_uncompressed();
+        assert_eq!(bytes[..56], point.x()[..]);
+        assert_eq!(bytes[56..], point.y()[..]);
+        assert_eq!(AffinePoint::from_uncompressed(&bytes).unwrap(), point);
+        assert_eq!(
+            AffinePoint::from_uncompressed(&AffinePoint::IDENTITY.to_uncompressed()).unwrap(),
+            AffinePoint::IDENTITY
+        );
+
+        // Off the curve
+        let mut off_curve = bytes;
+        off_curve[0] ^= 1;
+        assert!(bool::from(
+            AffinePoint::from_uncompressed(&off_curve).is_none()
+        ));
+        assert!(bool::from(
+            AffinePoint::from_uncompressed_unchecked(&off_curve).is_some()
+        ));
+
+        // p is not a canonical encoding of 0
+        let mut non_canonical = AffinePoint::IDENTITY.to_uncompressed();
+        non_canonical[56..].copy_from_slice(&FieldElement::MINUS_ONE.to_bytes());
+        non_canonical[56] += 1;
+        assert!(bool::from(
+            AffinePoint::from_uncompressed_unchecked(&non_canonical).is_none()
+        ));
+    }
+}
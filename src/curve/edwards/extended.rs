@@ -5,8 +5,10 @@ use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use crate::constants::BASEPOINT_ORDER;
 use crate::curve::edwards::affine::AffinePoint;
-use crate::curve::montgomery::montgomery::MontgomeryPoint; // XXX: need to fix this path
-use crate::curve::scalar_mul::variable_base;
+use crate::curve::edwards::table::EdwardsBasepointTable;
+use crate::curve::model::{sealed::ExtendedCoordinates, CurveModel};
+use crate::curve::montgomery::montgomery::{MontgomeryPoint, MontgomeryPointFull}; // XXX: need to fix this path
+use crate::curve::scalar_mul::{multiscalar_mul, variable_base};
 use crate::curve::twedwards::extended::ExtendedPoint as TwistedExtendedPoint;
 use crate::field::{FieldElement, Scalar};
 use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXof, Expander, FromOkm};
@@ -15,7 +17,7 @@ use elliptic_curve::{
         typenum::{U57, U84},
         GenericArray,
     },
-    group::{Group, GroupEncoding},
+    group::{Curve, Group, GroupEncoding, WnafGroup},
 };
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
@@ -25,6 +27,26 @@ pub const DEFAULT_HASH_TO_CURVE_SUITE: &[u8] = b"edwards448_XOF:SHAKE256_ELL2_RO
 /// The default encode to curve domain separation tag
 pub const DEFAULT_ENCODE_TO_CURVE_SUITE: &[u8] = b"edwards448_XOF:SHAKE256_ELL2_NU_";
 
+/// Which values of \\(x\\) count as negative in the sign bit of a compressed point
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum SignConvention {
+    /// \\(x\\) is negative when its canonical encoding is odd, as in RFC 8032
+    #[default]
+    LeastSignificantBit,
+    /// \\(x\\) is negative when it is larger than \\((p-1)/2\\), as some older
+    /// Ed448 implementations do
+    UpperHalf,
+}
+
+impl SignConvention {
+    pub(crate) fn is_negative(&self, x: &FieldElement) -> Choice {
+        match self {
+            SignConvention::LeastSignificantBit => x.is_negative(),
+            SignConvention::UpperHalf => x.is_upper_half(),
+        }
+    }
+}
+
 #[allow(non_snake_case)]
 
 /// The compressed internal representation of a point on the Twisted Edwards Curve
@@ -34,8 +56,11 @@ pub type PointBytes = [u8; 57];
 /// in little endian format where the most significant bit is the sign bit
 /// and the remaining 448 bits represent the y-coordinate
 #[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
 pub struct CompressedEdwardsY(pub PointBytes);
 
+define_byte_slice_casts!(TYPE = CompressedEdwardsY, LEN = 57);
+
 #[cfg(feature = "zeroize")]
 impl zeroize::Zeroize for CompressedEdwardsY {
     fn zeroize(&mut self) {
@@ -70,6 +95,16 @@ impl UpperHex for CompressedEdwardsY {
     }
 }
 
+/// Parses the hex printed by [`Display`], rejecting encodings that are not
+/// the canonical compression of a valid point
+impl std::str::FromStr for CompressedEdwardsY {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(crate::encoding::hex_decode(s)?)
+    }
+}
+
 impl Default for CompressedEdwardsY {
     fn default() -> Self {
         Self([0u8; 57])
@@ -174,8 +209,12 @@ impl TryFrom<PointBytes> for CompressedEdwardsY {
 
     fn try_from(value: PointBytes) -> Result<Self, Self::Error> {
         let pt = CompressedEdwardsY(value);
-        let _ = Option::<EdwardsPoint>::from(pt.decompress())
+        let point = Option::<EdwardsPoint>::from(pt.decompress())
             .ok_or_else(|| "Invalid point".to_string())?;
+        // Recompressing gives the one canonical encoding of the point
+        if point.compress() != pt {
+            return Err("Non-canonical point encoding".to_string());
+        }
         Ok(pt)
     }
 }
@@ -257,11 +296,44 @@ impl CompressedEdwardsY {
         75, 98, 199, 201, 86, 55, 32, 118, 136, 36, 188, 182, 110, 113, 70, 63, 105, 0,
     ]);
 
+    /// Compress every point in `points`, sharing a single field inversion between them.
+    ///
+    /// Each further point costs three multiplications instead of an inversion, which
+    /// is much cheaper when serializing many points at once.
+    pub fn compress_batch(points: &[EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+        let mut affine = vec![AffinePoint::default(); points.len()];
+        EdwardsPoint::batch_normalize(points, &mut affine);
+        affine.iter().map(AffinePoint::compress).collect()
+    }
+
     /// Attempt to decompress to an `EdwardsPoint`.
     ///
     /// Returns `None` if the input is not the \\(y\\)-coordinate of a
     /// curve point.`
     pub fn decompress(&self) -> CtOption<EdwardsPoint> {
+        self.decompress_with_sign(SignConvention::default())
+    }
+
+    /// Attempt to decompress to an `EdwardsPoint`, reading the sign bit of
+    /// \\(x\\) with the given convention.
+    pub fn decompress_with_sign(&self, convention: SignConvention) -> CtOption<EdwardsPoint> {
+        let (affine, is_res) = self.decompress_affine_unchecked(convention);
+        let pt = affine.to_edwards();
+
+        CtOption::new(pt, is_res & pt.is_torsion_free())
+    }
+
+    /// Attempt to decompress to an `AffinePoint`, skipping the extended coordinates.
+    ///
+    /// This accepts the same encodings as [`CompressedEdwardsY::decompress`].
+    pub fn decompress_to_affine(&self) -> CtOption<AffinePoint> {
+        let (affine, is_res) = self.decompress_affine_unchecked(SignConvention::default());
+
+        CtOption::new(affine, is_res & affine.to_edwards().is_torsion_free())
+    }
+
+    /// Recover the affine point and whether it is on the curve, without checking the subgroup
+    fn decompress_affine_unchecked(&self, convention: SignConvention) -> (AffinePoint, Choice) {
         // Safe to unwrap here as the underlying data structure is a slice
         let (sign, b) = self.0.split_last().unwrap();
 
@@ -275,16 +347,15 @@ impl CompressedEdwardsY {
         let numerator = FieldElement::ONE - yy;
         let denominator = FieldElement::ONE - dyy;
 
-        let (mut x, is_res) = FieldElement::sqrt_ratio(&numerator, &denominator);
+        let (mut x, is_res) = FieldElement::sqrt_ratio_i(&numerator, &denominator);
 
         // Compute correct sign of x
         let compressed_sign_bit = Choice::from(sign >> 7);
-        let is_negative = x.is_negative();
+        let is_negative = convention.is_negative(&x);
         x.conditional_negate(compressed_sign_bit ^ is_negative);
 
-        let pt = AffinePoint { x, y }.to_edwards();
-
-        CtOption::new(pt, is_res & pt.is_on_curve() & pt.is_torsion_free())
+        let affine = AffinePoint { x, y };
+        (affine, is_res & affine.is_on_curve())
     }
 
     /// View this `CompressedEdwardsY` as an array of bytes.
@@ -296,6 +367,22 @@ impl CompressedEdwardsY {
     pub const fn to_bytes(&self) -> PointBytes {
         self.0
     }
+
+    /// Construct a `CompressedEdwardsY` from its big-endian encoding,
+    /// the byte reversal of the RFC 8032 encoding that some PKCS#11 tokens
+    /// and HSMs use for `CKK_EC_EDWARDS` keys.
+    pub fn from_be_bytes(bytes: &PointBytes) -> Self {
+        let mut le = *bytes;
+        le.reverse();
+        Self(le)
+    }
+
+    /// Copy this `CompressedEdwardsY` to a big-endian array of bytes.
+    pub fn to_be_bytes(&self) -> PointBytes {
+        let mut be = self.0;
+        be.reverse();
+        be
+    }
 }
 
 /// Represent points on the (untwisted) edwards curve using Extended Homogenous Projective Co-ordinates
@@ -303,6 +390,9 @@ impl CompressedEdwardsY {
 /// a = 1, d = -39081
 /// XXX: Make this more descriptive
 /// Should this be renamed to EdwardsPoint so that we are consistent with Dalek crypto? Necessary as ExtendedPoint is not regular lingo?
+///
+/// Points implement [`ConditionallySelectable`], so `EdwardsPoint::conditional_swap(&mut a, &mut b, choice)`
+/// swaps two points in constant time, as needed by ladders and other oblivious algorithms.
 #[derive(Copy, Clone, Debug)]
 pub struct EdwardsPoint {
     pub(crate) X: FieldElement,
@@ -404,6 +494,43 @@ impl Group for EdwardsPoint {
     }
 }
 
+impl WnafGroup for EdwardsPoint {
+    fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        // A window of w costs 2^(w-1) additions for the table and about 446/(w+1)
+        // additions per scalar, so w+1 pays off from 2^(w-1)(w+1)(w+2)/446 scalars on
+        const THRESHOLDS: [usize; 20] = [
+            1, 1, 1, 2, 5, 11, 26, 64, 152, 359, 836, 1929, 4409, 9993, 22483, 50255, 111676,
+            246863, 543097, 1189641,
+        ];
+        2 + THRESHOLDS
+            .iter()
+            .take_while(|&&threshold| num_scalars >= threshold)
+            .count()
+    }
+}
+
+impl Curve for EdwardsPoint {
+    type AffineRepr = AffinePoint;
+
+    fn to_affine(&self) -> AffinePoint {
+        EdwardsPoint::to_affine(self)
+    }
+
+    /// Normalize all points with a single field inversion.
+    fn batch_normalize(p: &[Self], q: &mut [AffinePoint]) {
+        assert_eq!(p.len(), q.len());
+
+        let mut z_inverses = p.iter().map(|point| point.Z).collect::<Vec<_>>();
+        FieldElement::batch_invert(&mut z_inverses);
+        for ((point, z_inv), affine) in p.iter().zip(z_inverses.iter()).zip(q.iter_mut()) {
+            *affine = AffinePoint {
+                x: point.X * z_inv,
+                y: point.Y * z_inv,
+            };
+        }
+    }
+}
+
 impl GroupEncoding for EdwardsPoint {
     type Repr = GenericArray<u8, U57>;
 
@@ -499,6 +626,23 @@ impl From<&EdwardsPoint> for PointBytes {
     }
 }
 
+impl CurveModel for EdwardsPoint {
+    const A_IS_MINUS_ONE: bool = false;
+    const D: FieldElement = FieldElement::EDWARDS_D;
+
+    type Isogenous = TwistedExtendedPoint;
+}
+
+impl ExtendedCoordinates for EdwardsPoint {
+    fn from_extended(X: FieldElement, Y: FieldElement, Z: FieldElement, T: FieldElement) -> Self {
+        Self { X, Y, Z, T }
+    }
+
+    fn extended(&self) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
+        (self.X, self.Y, self.Z, self.T)
+    }
+}
+
 impl EdwardsPoint {
     /// Identity point
     pub const IDENTITY: Self = Self {
@@ -513,122 +657,195 @@ impl EdwardsPoint {
 
     pub fn to_montgomery(&self) -> MontgomeryPoint {
         // u = y^2 * [(1-dy^2)/(1-y^2)]
+        //   = Y^2 (Z^2 - dY^2) / (Z^2 (Z^2 - Y^2)), with a single inversion
+        let YY = self.Y.square();
+        let ZZ = self.Z.square();
+        let dYY = FieldElement::EDWARDS_D * YY;
 
-        let affine = self.to_affine();
+        let u = YY * (ZZ - dYY) * (ZZ * (ZZ - YY)).invert();
 
-        let yy = affine.y.square();
-        let dyy = FieldElement::EDWARDS_D * yy;
+        MontgomeryPoint(u.to_bytes())
+    }
 
-        let u = yy * (FieldElement::ONE - dyy) * (FieldElement::ONE - yy).invert();
+    /// The image of this point under the 4-isogeny of RFC 7748 § 4.2 with both coordinates,
+    /// `(u, v) = (y^2/x^2, (2 - x^2 - y^2) y/x^3)`.
+    ///
+    /// The u-coordinate is the same as [`EdwardsPoint::to_montgomery`], and the
+    /// points with `x = 0` map to the identity.
+    pub fn to_montgomery_full(&self) -> MontgomeryPointFull {
+        // In projective coordinates u = Y^2 X / X^3 and v = (2Z^2 - X^2 - Y^2) Y / X^3
+        let XX = self.X.square();
+        let YY = self.Y.square();
+        let XXX_inv = (XX * self.X).invert();
 
-        MontgomeryPoint(u.to_bytes())
+        let mut point = MontgomeryPointFull {
+            u: YY * self.X * XXX_inv,
+            v: (self.Z.square().double() - XX - YY) * self.Y * XXX_inv,
+        };
+        point.conditional_assign(
+            &MontgomeryPointFull::IDENTITY,
+            self.X.ct_eq(&FieldElement::ZERO),
+        );
+        point
     }
 
     /// Generic scalar multiplication to compute s*P
     pub fn scalar_mul(&self, scalar: &Scalar) -> Self {
         // Compute floor(s/4)
         let mut scalar_div_four = scalar.clone();
-        scalar_div_four.div_by_four();
+        scalar_div_four.div_by_four_floor();
 
         // Use isogeny and dual isogeny to compute phi^-1((s/4) * phi(P))
         let partial_result = variable_base(&self.to_twisted(), &scalar_div_four).to_untwisted();
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut scalar_div_four);
+
         // Add partial result to (scalar mod 4) * P
         partial_result.add(&self.scalar_mod_four(scalar))
     }
 
+    /// Compute the sum of `scalars[i] * points[i]` in constant time.
+    ///
+    /// The doublings are shared between the terms, so this is faster than adding up
+    /// separate multiplications. When the scalars are public,
+    /// [`vartime::multiscalar_mul`](crate::vartime::multiscalar_mul) is faster still.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    pub fn multiscalar_mul(scalars: &[Scalar], points: &[EdwardsPoint]) -> Self {
+        assert_eq!(scalars.len(), points.len());
+
+        // As in scalar_mul, split each scalar as 4q + r
+        let mut remainder = EdwardsPoint::IDENTITY;
+        #[allow(unused_mut)]
+        let mut quotients = scalars
+            .iter()
+            .zip(points.iter())
+            .map(|(scalar, point)| {
+                remainder = remainder.add(&point.scalar_mod_four(scalar));
+                let mut scalar_div_four = *scalar;
+                scalar_div_four.div_by_four_floor();
+                scalar_div_four
+            })
+            .collect::<Vec<_>>();
+        let twisted = points.iter().map(|p| p.to_twisted()).collect::<Vec<_>>();
+        let partial_result = multiscalar_mul(&quotients, &twisted).to_untwisted();
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut quotients);
+
+        partial_result.add(&remainder)
+    }
+
+    /// Compute the sum of `scalars[i] * points[i]`, for scalars that must stay secret.
+    ///
+    /// This is [`EdwardsPoint::multiscalar_mul`]: fixed radix-16 windows and constant-time
+    /// table lookups, never NAFs, so it is safe for openings of vector commitments.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    pub fn sum_of_products(scalars: &[Scalar], points: &[EdwardsPoint]) -> Self {
+        Self::multiscalar_mul(scalars, points)
+    }
+
+    /// Multiply the generator by `scalar`, using a precomputed table.
+    ///
+    /// This is several times faster than `EdwardsPoint::GENERATOR * scalar`.
+    pub fn mul_base(scalar: &Scalar) -> Self {
+        EdwardsBasepointTable::generator() * scalar
+    }
+
+    /// Multiply by a small integer `k`.
+    ///
+    /// This is a double-and-add over the 64 bits of `k` instead of a full
+    /// 446-bit scalar multiplication. It runs in constant time with respect to `k`.
+    pub fn mul_u64(&self, k: u64) -> Self {
+        self.mul_small(k as u128, 64)
+    }
+
+    /// Multiply by a small integer `k`.
+    ///
+    /// This is a double-and-add over the 128 bits of `k` instead of a full
+    /// 446-bit scalar multiplication. It runs in constant time with respect to `k`.
+    pub fn mul_u128(&self, k: u128) -> Self {
+        self.mul_small(k, 128)
+    }
+
+    fn mul_small(&self, k: u128, bits: usize) -> Self {
+        let mut result = EdwardsPoint::IDENTITY;
+        for i in (0..bits).rev() {
+            result = result.double();
+            let sum = result.add(self);
+            result.conditional_assign(&sum, Choice::from(((k >> i) & 1) as u8));
+        }
+        result
+    }
+
     /// Returns (scalar mod 4) * P in constant time
     pub fn scalar_mod_four(&self, scalar: &Scalar) -> Self {
-        // Compute compute (scalar mod 4)
-        let s_mod_four = scalar[0] & 3;
-
-        // Compute all possible values of (scalar mod 4) * P
-        let zero_p = EdwardsPoint::IDENTITY;
-        let one_p = self;
-        let two_p = one_p.double();
-        let three_p = two_p.add(self);
-
-        // Under the reasonable assumption that `==` is constant time
-        // Then the whole function is constant time.
-        // This should be cheaper than calling double_and_add or a scalar mul operation
-        // as the number of possibilities are so small.
-        // XXX: This claim has not been tested (although it sounds intuitive to me)
-        let mut result = EdwardsPoint::IDENTITY;
-        result.conditional_assign(&zero_p, Choice::from((s_mod_four == 0) as u8));
-        result.conditional_assign(&one_p, Choice::from((s_mod_four == 1) as u8));
-        result.conditional_assign(&two_p, Choice::from((s_mod_four == 2) as u8));
-        result.conditional_assign(&three_p, Choice::from((s_mod_four == 3) as u8));
+        // (scalar mod 4) P = b0 P + b1 2P for the two low bits b0, b1,
+        // each selected without comparing or branching on the bits
+        let low = scalar.0.as_words()[0];
+        let b0 = Choice::from((low & 1) as u8);
+        let b1 = Choice::from(((low >> 1) & 1) as u8);
 
-        result
+        let one_p = EdwardsPoint::conditional_select(&EdwardsPoint::IDENTITY, self, b0);
+        let two_p = EdwardsPoint::conditional_select(&EdwardsPoint::IDENTITY, &self.double(), b1);
+
+        one_p.add(&two_p)
     }
 
     // Standard compression; store Y and sign of X
     // XXX: This needs more docs and is `compress` the conventional function name? I think to_bytes/encode is?
     pub fn compress(&self) -> CompressedEdwardsY {
-        let affine = self.to_affine();
-
-        let affine_x = affine.x;
-        let affine_y = affine.y;
-
-        let mut compressed_bytes = [0u8; 57];
+        self.compress_with_sign(SignConvention::default())
+    }
 
-        let sign = affine_x.is_negative().unwrap_u8();
+    /// Compress this point, encoding the sign of \\(x\\) with the given convention.
+    pub fn compress_with_sign(&self, convention: SignConvention) -> CompressedEdwardsY {
+        self.to_affine().compress_with_sign(convention)
+    }
 
-        let y_bytes = affine_y.to_bytes();
-        compressed_bytes[..y_bytes.len()].copy_from_slice(&y_bytes[..]);
-        *compressed_bytes.last_mut().unwrap() = sign << 7;
-        CompressedEdwardsY(compressed_bytes)
+    /// Compress `[2]P` for every point `P` in `points`, with a single field inversion.
+    ///
+    /// Doubling is part of clearing the cofactor, so this suits flows that multiply by
+    /// the cofactor and then serialize. See [`CompressedEdwardsY::compress_batch`].
+    pub fn double_and_compress_batch(points: &[EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+        let doubled = points.iter().map(EdwardsPoint::double).collect::<Vec<_>>();
+        CompressedEdwardsY::compress_batch(&doubled)
     }
 
-    //https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf (3.1)
-    // These formulas are unified, so for now we can use it for doubling. Will refactor later for speed
+    /// Add two points
     pub fn add(&self, other: &EdwardsPoint) -> Self {
-        let aXX = self.X * other.X; // aX1X2
-        let dTT = FieldElement::EDWARDS_D * self.T * other.T; // dT1T2
-        let ZZ = self.Z * other.Z; // Z1Z2
-        let YY = self.Y * other.Y;
-
-        let X = {
-            let x_1 = (self.X * other.Y) + (self.Y * other.X);
-            let x_2 = ZZ - dTT;
-            x_1 * x_2
-        };
-        let Y = {
-            let y_1 = YY - aXX;
-            let y_2 = ZZ + dTT;
-            y_1 * y_2
-        };
-
-        let T = {
-            let t_1 = YY - aXX;
-            let t_2 = (self.X * other.Y) + (self.Y * other.X);
-            t_1 * t_2
-        };
-
-        let Z = { (ZZ - dTT) * (ZZ + dTT) };
-
-        EdwardsPoint { X, Y, Z, T }
+        self.model_add(other)
     }
 
-    // XXX: See comment on addition, the formula is unified, so this will do for now
-    //https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf (3.1)
+    /// Double a point
     pub fn double(&self) -> Self {
-        self.add(&self)
+        self.model_double()
     }
 
-    pub(crate) fn is_on_curve(&self) -> Choice {
-        let XY = self.X * self.Y;
-        let ZT = self.Z * self.T;
-
-        // Y^2 + X^2 == Z^2 - T^2 * D
-
-        let YY = self.Y.square();
-        let XX = self.X.square();
-        let ZZ = self.Z.square();
-        let TT = self.T.square();
-        let lhs = YY + XX;
-        let rhs = ZZ + TT * FieldElement::EDWARDS_D;
+    /// Compare two public points in variable time.
+    ///
+    /// This exits early on the first differing coordinate and skips the
+    /// cross-multiplications when both points share the same `Z`, such as freshly
+    /// decompressed points. Only use it when both points are public.
+    pub fn vartime_eq(&self, other: &EdwardsPoint) -> bool {
+        if self.Z == other.Z {
+            return self.X == other.X && self.Y == other.Y;
+        }
+        self.X * other.Z == other.X * self.Z && self.Y * other.Z == other.Y * self.Z
+    }
 
-        XY.ct_eq(&ZT) & lhs.ct_eq(&rhs)
+    /// Check that the coordinates satisfy the curve equation.
+    ///
+    /// This runs in constant time and returns a [`Choice`], so it can validate
+    /// secret-derived points without branching on them.
+    pub fn is_on_curve(&self) -> Choice {
+        self.model_is_on_curve()
     }
 
     pub fn to_affine(&self) -> AffinePoint {
@@ -640,36 +857,8 @@ impl EdwardsPoint {
         AffinePoint { x, y }
     }
 
-    /// Edwards_Isogeny is derived from the doubling formula
-    /// XXX: There is a duplicate method in the twisted edwards module to compute the dual isogeny
-    /// XXX: Not much point trying to make it generic I think. So what we can do is optimise each respective isogeny method for a=1 or a = -1 (currently, I just made it really slow and simple)
-    fn edwards_isogeny(&self, a: FieldElement) -> TwistedExtendedPoint {
-        // Convert to affine now, then derive extended version later
-        let affine = self.to_affine();
-        let x = affine.x;
-        let y = affine.y;
-
-        // Compute x
-        let xy = x * y;
-        let x_numerator = xy + xy;
-        let x_denom = y.square() - (a * x.square());
-        let new_x = x_numerator * x_denom.invert();
-
-        // Compute y
-        let y_numerator = y.square() + (a * x.square());
-        let y_denom = (FieldElement::ONE + FieldElement::ONE) - y.square() - (a * x.square());
-        let new_y = y_numerator * y_denom.invert();
-
-        TwistedExtendedPoint {
-            X: new_x,
-            Y: new_y,
-            Z: FieldElement::ONE,
-            T: new_x * new_y,
-        }
-    }
-
     pub(crate) fn to_twisted(&self) -> TwistedExtendedPoint {
-        self.edwards_isogeny(FieldElement::ONE)
+        self.isogeny()
     }
 
     pub fn negate(&self) -> Self {
@@ -693,6 +882,8 @@ impl EdwardsPoint {
     /// Determine if this point is “torsion-free”, i.e., is contained in
     /// the prime-order subgroup.
     ///
+    /// Like [`EdwardsPoint::is_on_curve`] this runs in constant time and returns a [`Choice`].
+    ///
     /// # Return
     ///
     /// * `true` if `self` has zero torsion component and is in the
@@ -714,19 +905,7 @@ impl EdwardsPoint {
     where
         X: for<'a> ExpandMsg<'a>,
     {
-        let mut random_bytes = GenericArray::<u8, U84>::default();
-        let dst = [dst];
-        let mut expander = X::expand_message(&[msg], &dst, random_bytes.len() * 2).unwrap();
-        expander.fill_bytes(&mut random_bytes);
-        let u0 = FieldElement::from_okm(&random_bytes);
-        expander.fill_bytes(&mut random_bytes);
-        let u1 = FieldElement::from_okm(&random_bytes);
-        let mut q0 = u0.map_to_curve_elligator2();
-        let mut q1 = u1.map_to_curve_elligator2();
-        q0 = q0.isogeny();
-        q1 = q1.isogeny();
-
-        (q0.to_edwards() + q1.to_edwards()).double().double()
+        Self::map_to_isogenous::<X>(msg, dst, 2).double().double()
     }
 
     /// Encode using the default domain separation tag and hash function
@@ -737,18 +916,29 @@ impl EdwardsPoint {
     /// Implements encode to curve according
     /// see <https://datatracker.ietf.org/doc/rfc9380/>
     pub fn encode<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        Self::map_to_isogenous::<X>(msg, dst, 1).double().double()
+    }
+
+    /// Hash `msg` to `count` field elements, map each to curve448 with Elligator 2
+    /// and move them here with the 4-isogeny, and add them up.
+    /// The cofactor is not cleared.
+    pub(crate) fn map_to_isogenous<X>(msg: &[u8], dst: &[u8], count: usize) -> Self
     where
         X: for<'a> ExpandMsg<'a>,
     {
         let mut random_bytes = GenericArray::<u8, U84>::default();
         let dst = [dst];
-        let mut expander = X::expand_message(&[msg], &dst, random_bytes.len()).unwrap();
-        expander.fill_bytes(&mut random_bytes);
-        let u0 = FieldElement::from_okm(&random_bytes);
-        let mut q0 = u0.map_to_curve_elligator2();
-        q0 = q0.isogeny();
-
-        q0.to_edwards().double().double()
+        let mut expander = X::expand_message(&[msg], &dst, random_bytes.len() * count).unwrap();
+        let mut sum = Self::IDENTITY;
+        for _ in 0..count {
+            expander.fill_bytes(&mut random_bytes);
+            let u = FieldElement::from_okm(&random_bytes);
+            sum += u.map_to_curve_elligator2().isogeny().to_edwards();
+        }
+        sum
     }
 
     /// Compute pippenger multi-exponentiation.
@@ -846,6 +1036,40 @@ impl<'b> SubAssign<&'b EdwardsPoint> for EdwardsPoint {
 
 define_sub_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint);
 
+impl<'b> Add<&'b AffinePoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: &'b AffinePoint) -> EdwardsPoint {
+        self.add(&other.to_edwards())
+    }
+}
+
+define_add_variants!(LHS = EdwardsPoint, RHS = AffinePoint, Output = EdwardsPoint);
+
+impl<'b> AddAssign<&'b AffinePoint> for EdwardsPoint {
+    fn add_assign(&mut self, rhs: &'b AffinePoint) {
+        *self = (self as &EdwardsPoint) + rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = EdwardsPoint, RHS = AffinePoint);
+
+impl<'b> Sub<&'b AffinePoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn sub(self, other: &'b AffinePoint) -> EdwardsPoint {
+        self.add(&other.to_edwards().negate())
+    }
+}
+
+define_sub_variants!(LHS = EdwardsPoint, RHS = AffinePoint, Output = EdwardsPoint);
+
+impl<'b> SubAssign<&'b AffinePoint> for EdwardsPoint {
+    fn sub_assign(&mut self, rhs: &'b AffinePoint) {
+        *self = (self as &EdwardsPoint) - rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = EdwardsPoint, RHS = AffinePoint);
+
 impl<T> Sum<T> for EdwardsPoint
 where
     T: Borrow<EdwardsPoint>,
@@ -987,6 +1211,12 @@ mod tests {
         let y = hex_to_field("ae05e9634ad7048db359d6205086c2b0036ed7a035884dd7b7e36d728ad8c4b80d6565833a2a3098bbbcb2bed1cda06bdaeafbcdea9386ed");
         let gen = AffinePoint { x, y }.to_edwards();
         assert_eq!(gen.is_on_curve().unwrap_u8(), 1u8);
+        assert_eq!(gen.to_affine().is_on_curve().unwrap_u8(), 1u8);
+        assert_eq!(AffinePoint::IDENTITY.is_on_curve().unwrap_u8(), 1u8);
+
+        let off_curve = AffinePoint { x, y: y.double() };
+        assert_eq!(off_curve.is_on_curve().unwrap_u8(), 0u8);
+        assert_eq!(off_curve.to_edwards().is_on_curve().unwrap_u8(), 0u8);
     }
     #[test]
     fn test_compress_decompress() {
@@ -1009,6 +1239,207 @@ mod tests {
 
         assert_eq!(bytes, recompressed.0);
     }
+
+    #[test]
+    fn test_vartime_eq() {
+        let p = EdwardsPoint::GENERATOR.mul_u64(5);
+        let q = p.to_affine().to_edwards();
+        assert!(p.vartime_eq(&q));
+        assert!(q.vartime_eq(&p));
+        assert!(q.vartime_eq(&q));
+        assert!(!p.vartime_eq(&p.double()));
+        assert!(!q.vartime_eq(&(-q)));
+        assert!(!q.vartime_eq(&EdwardsPoint::GENERATOR));
+    }
+
+    #[test]
+    fn test_affine_conversions() {
+        let p = EdwardsPoint::GENERATOR.mul_u64(3);
+        let affine = AffinePoint::from(p);
+        assert_eq!(affine, p.to_affine());
+        assert_eq!(AffinePoint::from(&p), affine);
+        assert_eq!(EdwardsPoint::from(affine), p);
+        assert_eq!(EdwardsPoint::from(&affine), p);
+        assert_eq!(
+            EdwardsPoint::from(AffinePoint::default()),
+            EdwardsPoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_affine_compression() {
+        for k in 1u64..8 {
+            let p = EdwardsPoint::GENERATOR.mul_u64(k);
+            let affine = p.to_affine();
+            let compressed = affine.compress();
+            assert_eq!(compressed, p.compress());
+            assert_eq!(compressed.decompress_to_affine().unwrap(), affine);
+        }
+
+        let mut invalid = [0u8; 57];
+        invalid[0] = 2;
+        assert!(bool::from(
+            CompressedEdwardsY(invalid).decompress_to_affine().is_none()
+        ));
+    }
+
+    #[test]
+    fn test_sign_conventions() {
+        let mut differ = 0;
+        for k in 1u64..16 {
+            let p = EdwardsPoint::GENERATOR.mul_u64(k);
+            let x = p.to_affine().x;
+            for convention in [
+                SignConvention::LeastSignificantBit,
+                SignConvention::UpperHalf,
+            ] {
+                let compressed = p.compress_with_sign(convention);
+                assert_eq!(compressed.decompress_with_sign(convention).unwrap(), p);
+            }
+
+            let lsb = p.compress_with_sign(SignConvention::LeastSignificantBit);
+            let upper = p.compress_with_sign(SignConvention::UpperHalf);
+            assert_eq!(lsb, p.compress());
+            assert_eq!(&lsb.0[..56], &upper.0[..56]);
+            // x is in the upper half when it is larger than -x
+            let (mut x_be, mut neg_x_be) = (x.to_bytes(), (-x).to_bytes());
+            x_be.reverse();
+            neg_x_be.reverse();
+            assert_eq!(upper.0[56] >> 7 == 1, x_be > neg_x_be);
+            if lsb != upper {
+                differ += 1;
+                assert_eq!(upper.decompress().unwrap(), -p);
+            }
+        }
+        assert!(differ > 0);
+    }
+
+    #[test]
+    fn test_scalar_mod_four() {
+        let torsion = AffinePoint {
+            x: FieldElement::ONE,
+            y: FieldElement::ZERO,
+        }
+        .to_edwards();
+        let p = EdwardsPoint::GENERATOR.mul_u64(7);
+        for point in [p, p + torsion, torsion, EdwardsPoint::IDENTITY] {
+            for k in 0..8u64 {
+                let expected = point.mul_u64(k % 4);
+                assert_eq!(point.scalar_mod_four(&Scalar::from(k)), expected);
+                // Only the two low bits are read
+                let s = Scalar::from(k) + Scalar::from(1u64 << 40);
+                assert_eq!(point.scalar_mod_four(&s), expected);
+            }
+        }
+    }
+
+    /// A dudect-style leakage check: Welch's t-test between the timings of a fixed scalar and
+    /// of random ones, measured in random order. Timings are noisy, so it is ignored by default,
+    /// run it on a quiet machine with `cargo test --release -- --ignored scalar_mod_four_timing`.
+    #[test]
+    #[ignore]
+    fn test_scalar_mod_four_timing() {
+        use rand_core::OsRng;
+        use std::hint::black_box;
+        use std::time::Instant;
+
+        let point = EdwardsPoint::GENERATOR.mul_u64(7);
+        // Count, mean and sum of squared deviations of each class, updated with Welford's method
+        let mut stats = [(0f64, 0f64, 0f64); 2];
+        for _ in 0..200_000 {
+            let class = (OsRng.next_u32() & 1) as usize;
+            let scalar = if class == 0 {
+                Scalar::ZERO
+            } else {
+                Scalar::random(&mut OsRng)
+            };
+
+            let start = Instant::now();
+            black_box(point.scalar_mod_four(black_box(&scalar)));
+            let time = start.elapsed().as_nanos() as f64;
+
+            let (n, mean, m2) = &mut stats[class];
+            *n += 1.0;
+            let delta = time - *mean;
+            *mean += delta / *n;
+            *m2 += delta * (time - *mean);
+        }
+
+        let [(n0, mean0, m2_0), (n1, mean1, m2_1)] = stats;
+        let t = (mean0 - mean1) / (m2_0 / (n0 - 1.0) / n0 + m2_1 / (n1 - 1.0) / n1).sqrt();
+        // dudect's threshold for evidence of a leak
+        assert!(t.abs() < 4.5, "t = {t}");
+    }
+
+    #[test]
+    fn test_to_montgomery_projective() {
+        // Z != 1 here, and the normalised copy must map to the same point
+        let p = EdwardsPoint::GENERATOR * Scalar::from(1234u32);
+        let q = p.to_affine().to_edwards();
+        assert_eq!(p.to_montgomery(), q.to_montgomery());
+        assert_eq!(p.to_montgomery_full(), q.to_montgomery_full());
+        assert_eq!(
+            MontgomeryPoint(p.to_montgomery_full().u.to_bytes()),
+            p.to_montgomery()
+        );
+
+        assert_eq!(
+            EdwardsPoint::IDENTITY.to_montgomery(),
+            MontgomeryPoint([0u8; 56])
+        );
+        assert_eq!(
+            EdwardsPoint::IDENTITY.to_montgomery_full(),
+            MontgomeryPointFull::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_multiscalar_mul() {
+        let points = (1..5u8)
+            .map(|i| EdwardsPoint::GENERATOR * Scalar::from(i) + EdwardsPoint::GENERATOR.torque())
+            .collect::<Vec<_>>();
+        let scalars = (0..4)
+            .map(|_| Scalar::random(&mut rand_core::OsRng))
+            .collect::<Vec<_>>();
+
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(EdwardsPoint::IDENTITY, |acc, (s, p)| acc + p * s);
+        assert_eq!(EdwardsPoint::multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(EdwardsPoint::sum_of_products(&scalars, &points), expected);
+        assert_eq!(
+            EdwardsPoint::multiscalar_mul(&scalars, &points),
+            crate::vartime::multiscalar_mul(&scalars, &points)
+        );
+        assert_eq!(
+            EdwardsPoint::multiscalar_mul(&[], &[]),
+            EdwardsPoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_mul_small() {
+        let p = EdwardsPoint::GENERATOR * Scalar::from(42u8);
+        for k in [0u64, 1, 2, 3, 4, 0xdead_beef, u64::MAX] {
+            assert_eq!(p.mul_u64(k), p * Scalar::from(k));
+            assert_eq!(p.mul_u128(k as u128), p * Scalar::from(k));
+        }
+        let k = u128::MAX - 12345;
+        assert_eq!(p.mul_u128(k), p * Scalar::from(k));
+    }
+
+    #[test]
+    fn test_compressed_be_bytes() {
+        let compressed = EdwardsPoint::GENERATOR.compress();
+        let be = compressed.to_be_bytes();
+        assert_eq!(be[0], compressed.0[56]);
+        assert_eq!(be[56], compressed.0[0]);
+        let decoded = CompressedEdwardsY::from_be_bytes(&be);
+        assert_eq!(decoded, compressed);
+        assert_eq!(decoded.decompress().unwrap(), EdwardsPoint::GENERATOR);
+    }
+
     #[test]
     fn test_just_decompress() {
         let bytes = hex!("649c6a53b109897d962d033f23d01fd4e1053dddf3746d2ddce9bd66aea38ccfc3df061df03ca399eb806312ab3037c0c31523142956ada780");
@@ -1120,4 +1551,162 @@ mod tests {
         let result = EdwardsPoint::sum_of_products_pippenger(&points, &scalars);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_conditional_negate_and_swap() {
+        let a = EdwardsPoint::GENERATOR;
+        let b = a.double();
+
+        let mut p = a;
+        p.conditional_negate(Choice::from(0));
+        assert_eq!(p, a);
+        p.conditional_negate(Choice::from(1));
+        assert_eq!(p, -a);
+
+        let mut q = a.to_affine();
+        q.conditional_negate(Choice::from(0));
+        assert_eq!(q, a.to_affine());
+        q.conditional_negate(Choice::from(1));
+        assert_eq!(q, (-a).to_affine());
+
+        let mut t = a.to_twisted();
+        t.conditional_negate(Choice::from(1));
+        assert_eq!(t, a.to_twisted().negate());
+
+        let (mut x, mut y) = (a, b);
+        EdwardsPoint::conditional_swap(&mut x, &mut y, Choice::from(0));
+        assert_eq!((x, y), (a, b));
+        EdwardsPoint::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((x, y), (b, a));
+
+        let (mut x, mut y) = (a.to_affine(), b.to_affine());
+        AffinePoint::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((x, y), (b.to_affine(), a.to_affine()));
+    }
+
+    #[test]
+    fn test_compress_batch() {
+        let points = (1..6u8)
+            .map(|i| EdwardsPoint::GENERATOR * Scalar::from(i))
+            .chain([EdwardsPoint::IDENTITY])
+            .collect::<Vec<_>>();
+        let compressed = CompressedEdwardsY::compress_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        for (p, c) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*c, p.compress());
+        }
+        assert!(CompressedEdwardsY::compress_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_double_and_compress_batch() {
+        let torsion = AffinePoint {
+            x: FieldElement::ONE,
+            y: FieldElement::ZERO,
+        }
+        .to_edwards();
+        let points = [
+            EdwardsPoint::GENERATOR,
+            EdwardsPoint::GENERATOR * Scalar::from(77u8) + torsion,
+            torsion,
+            EdwardsPoint::IDENTITY,
+        ];
+        let compressed = EdwardsPoint::double_and_compress_batch(&points);
+        for (p, c) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*c, p.double().compress());
+        }
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        let points = (1..6u8)
+            .map(|i| EdwardsPoint::GENERATOR * Scalar::from(i))
+            .chain([EdwardsPoint::IDENTITY])
+            .collect::<Vec<_>>();
+        let mut affine = vec![AffinePoint::default(); points.len()];
+        EdwardsPoint::batch_normalize(&points, &mut affine);
+        for (p, a) in points.iter().zip(affine.iter()) {
+            assert_eq!(*a, p.to_affine());
+            assert_eq!(*a, Curve::to_affine(p));
+        }
+
+        // Mixed addition with affine points
+        let g = EdwardsPoint::GENERATOR;
+        assert_eq!(g + affine[1], g * Scalar::from(3u8));
+        assert_eq!(g - affine[1], -g);
+        let mut p = g;
+        p += &affine[0];
+        p -= affine[2];
+        assert_eq!(p, -g);
+    }
+
+    #[test]
+    fn test_wnaf() {
+        use elliptic_curve::group::{Wnaf, WnafBase, WnafScalar};
+        use rand_core::OsRng;
+
+        assert_eq!(EdwardsPoint::recommended_wnaf_for_num_scalars(0), 2);
+        assert_eq!(EdwardsPoint::recommended_wnaf_for_num_scalars(1), 5);
+        assert_eq!(
+            EdwardsPoint::recommended_wnaf_for_num_scalars(usize::MAX),
+            22
+        );
+
+        let g = EdwardsPoint::GENERATOR;
+        let scalars = (0..4)
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let mut wnaf = Wnaf::new();
+        let mut base = wnaf.base(g, scalars.len());
+        for s in &scalars {
+            assert_eq!(base.scalar(s), g * s);
+        }
+
+        let point = g * scalars[0];
+        let mut wnaf = Wnaf::new();
+        let mut scalar = wnaf.scalar(&scalars[1]);
+        assert_eq!(scalar.base(point), point * scalars[1]);
+        assert_eq!(scalar.base(g), g * scalars[1]);
+
+        let base = WnafBase::<EdwardsPoint, 5>::new(point);
+        assert_eq!(&base * &WnafScalar::new(&scalars[2]), point * scalars[2]);
+    }
+
+    #[test]
+    fn test_byte_slice_casts() {
+        let points = (1..4u8)
+            .map(|i| EdwardsPoint::GENERATOR * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let compressed = EdwardsPoint::double_and_compress_batch(&points);
+        let mut bytes = CompressedEdwardsY::slice_as_bytes(&compressed).to_vec();
+        assert_eq!(bytes.len(), 3 * 57);
+        assert_eq!(bytes[57..114], compressed[1].0);
+
+        let view = CompressedEdwardsY::slice_from_bytes(&bytes).unwrap();
+        assert_eq!(view, &compressed[..]);
+        assert!(CompressedEdwardsY::slice_from_bytes(&bytes[1..]).is_none());
+        assert!(CompressedEdwardsY::slice_from_bytes(&[])
+            .unwrap()
+            .is_empty());
+
+        let view = CompressedEdwardsY::slice_from_bytes_mut(&mut bytes).unwrap();
+        view[0] = view[2];
+        assert_eq!(bytes[..57], compressed[2].0);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let compressed = (EdwardsPoint::GENERATOR * Scalar::from(5u8)).compress();
+        let hex = compressed.to_string();
+        assert_eq!(hex, format!("{:x}", compressed));
+        assert_eq!(hex.parse::<CompressedEdwardsY>().unwrap(), compressed);
+        assert_eq!(
+            format!("{:X}", compressed)
+                .parse::<CompressedEdwardsY>()
+                .unwrap(),
+            compressed
+        );
+        assert!(hex[2..].parse::<CompressedEdwardsY>().is_err());
+        assert!("zz".repeat(57).parse::<CompressedEdwardsY>().is_err());
+    }
 }
@@ -11,5 +11,9 @@
 /// If this is a problem, one can use a different isogeny strategy (Decaf/Ristretto)
 pub(crate) mod affine;
 pub(crate) mod extended;
-pub use affine::AffinePoint;
-pub use extended::{CompressedEdwardsY, EdwardsPoint};
+pub(crate) mod subgroup;
+pub(crate) mod table;
+pub use affine::{AffinePoint, UncompressedEdwardsBytes};
+pub use extended::{CompressedEdwardsY, EdwardsPoint, SignConvention};
+pub use subgroup::SubgroupPoint;
+pub use table::{EdwardsBasepointTable, EdwardsCombTable};
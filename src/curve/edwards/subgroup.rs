@@ -0,0 +1,354 @@
+use std::borrow::Borrow;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::curve::edwards::{AffinePoint, EdwardsPoint};
+use crate::field::Scalar;
+use elliptic_curve::group::{
+    cofactor::{CofactorCurve, CofactorCurveAffine, CofactorGroup},
+    prime::PrimeGroup,
+    Group, GroupEncoding,
+};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// A point in the prime-order subgroup of Edwards448, the image of
+/// [`CofactorGroup::clear_cofactor`] on an [`EdwardsPoint`].
+///
+/// Decoding one checks that the point is torsion-free.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubgroupPoint(pub(crate) EdwardsPoint);
+
+impl From<SubgroupPoint> for EdwardsPoint {
+    fn from(point: SubgroupPoint) -> EdwardsPoint {
+        point.0
+    }
+}
+
+impl From<&SubgroupPoint> for EdwardsPoint {
+    fn from(point: &SubgroupPoint) -> EdwardsPoint {
+        point.0
+    }
+}
+
+impl ConstantTimeEq for SubgroupPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for SubgroupPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        SubgroupPoint(EdwardsPoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for SubgroupPoint {}
+
+impl Group for SubgroupPoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        // Hashing to the curve already clears the cofactor
+        SubgroupPoint(EdwardsPoint::random(rng))
+    }
+
+    fn identity() -> Self {
+        SubgroupPoint(EdwardsPoint::IDENTITY)
+    }
+
+    fn generator() -> Self {
+        SubgroupPoint(EdwardsPoint::GENERATOR)
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        SubgroupPoint(self.0.double())
+    }
+}
+
+impl GroupEncoding for SubgroupPoint {
+    type Repr = <EdwardsPoint as GroupEncoding>::Repr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EdwardsPoint::from_bytes(bytes).and_then(|point| point.into_subgroup())
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.0.to_bytes()
+    }
+}
+
+impl PrimeGroup for SubgroupPoint {}
+
+impl CofactorGroup for EdwardsPoint {
+    type Subgroup = SubgroupPoint;
+
+    fn clear_cofactor(&self) -> SubgroupPoint {
+        SubgroupPoint(self.double().double())
+    }
+
+    fn into_subgroup(self) -> CtOption<SubgroupPoint> {
+        CtOption::new(SubgroupPoint(self), self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        self.is_torsion_free()
+    }
+}
+
+impl CofactorCurve for EdwardsPoint {
+    type Affine = AffinePoint;
+}
+
+impl CofactorCurveAffine for AffinePoint {
+    type Scalar = Scalar;
+    type Curve = EdwardsPoint;
+
+    fn identity() -> Self {
+        AffinePoint::IDENTITY
+    }
+
+    fn generator() -> Self {
+        AffinePoint::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.is_identity()
+    }
+
+    fn to_curve(&self) -> EdwardsPoint {
+        self.to_edwards()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Addition and Subtraction
+// ------------------------------------------------------------------------
+
+impl<'b> Add<&'b SubgroupPoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+    fn add(self, other: &'b SubgroupPoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 + other.0)
+    }
+}
+
+define_add_variants!(
+    LHS = SubgroupPoint,
+    RHS = SubgroupPoint,
+    Output = SubgroupPoint
+);
+
+impl<'b> AddAssign<&'b SubgroupPoint> for SubgroupPoint {
+    fn add_assign(&mut self, rhs: &'b SubgroupPoint) {
+        *self = (self as &SubgroupPoint) + rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = SubgroupPoint, RHS = SubgroupPoint);
+
+impl<'b> Sub<&'b SubgroupPoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+    fn sub(self, other: &'b SubgroupPoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 - other.0)
+    }
+}
+
+define_sub_variants!(
+    LHS = SubgroupPoint,
+    RHS = SubgroupPoint,
+    Output = SubgroupPoint
+);
+
+impl<'b> SubAssign<&'b SubgroupPoint> for SubgroupPoint {
+    fn sub_assign(&mut self, rhs: &'b SubgroupPoint) {
+        *self = (self as &SubgroupPoint) - rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = SubgroupPoint, RHS = SubgroupPoint);
+
+impl<'b> Add<&'b SubgroupPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: &'b SubgroupPoint) -> EdwardsPoint {
+        self + other.0
+    }
+}
+
+define_add_variants!(
+    LHS = EdwardsPoint,
+    RHS = SubgroupPoint,
+    Output = EdwardsPoint
+);
+
+impl<'b> AddAssign<&'b SubgroupPoint> for EdwardsPoint {
+    fn add_assign(&mut self, rhs: &'b SubgroupPoint) {
+        *self = (self as &EdwardsPoint) + rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = EdwardsPoint, RHS = SubgroupPoint);
+
+impl<'b> Sub<&'b SubgroupPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn sub(self, other: &'b SubgroupPoint) -> EdwardsPoint {
+        self - other.0
+    }
+}
+
+define_sub_variants!(
+    LHS = EdwardsPoint,
+    RHS = SubgroupPoint,
+    Output = EdwardsPoint
+);
+
+impl<'b> SubAssign<&'b SubgroupPoint> for EdwardsPoint {
+    fn sub_assign(&mut self, rhs: &'b SubgroupPoint) {
+        *self = (self as &EdwardsPoint) - rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = EdwardsPoint, RHS = SubgroupPoint);
+
+impl<T> Sum<T> for SubgroupPoint
+where
+    T: Borrow<SubgroupPoint>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        SubgroupPoint(iter.map(|item| item.borrow().0).sum())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Negation
+// ------------------------------------------------------------------------
+
+impl Neg for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    fn neg(self) -> SubgroupPoint {
+        SubgroupPoint(-self.0)
+    }
+}
+
+impl Neg for SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    fn neg(self) -> SubgroupPoint {
+        -&self
+    }
+}
+
+// ------------------------------------------------------------------------
+// Scalar multiplication
+// ------------------------------------------------------------------------
+
+impl<'b> MulAssign<&'b Scalar> for SubgroupPoint {
+    fn mul_assign(&mut self, scalar: &'b Scalar) {
+        *self = (self as &SubgroupPoint) * scalar;
+    }
+}
+
+define_mul_assign_variants!(LHS = SubgroupPoint, RHS = Scalar);
+
+define_mul_variants!(LHS = SubgroupPoint, RHS = Scalar, Output = SubgroupPoint);
+define_mul_variants!(LHS = Scalar, RHS = SubgroupPoint, Output = SubgroupPoint);
+
+impl<'b> Mul<&'b Scalar> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    fn mul(self, scalar: &'b Scalar) -> SubgroupPoint {
+        SubgroupPoint(self.0 * scalar)
+    }
+}
+
+impl<'b> Mul<&'b SubgroupPoint> for &Scalar {
+    type Output = SubgroupPoint;
+
+    fn mul(self, point: &'b SubgroupPoint) -> SubgroupPoint {
+        point * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn cofactor() {
+        let point = EdwardsPoint::GENERATOR * Scalar::random(&mut OsRng);
+        // A point of order 2, (0, -1)
+        let torsion = EdwardsPoint {
+            Y: -EdwardsPoint::IDENTITY.Y,
+            ..EdwardsPoint::IDENTITY
+        };
+        let mixed = point + torsion;
+
+        assert!(bool::from(CofactorGroup::is_torsion_free(&point)));
+        assert!(bool::from(!CofactorGroup::is_torsion_free(&mixed)));
+        assert!(bool::from(torsion.is_small_order()));
+        assert!(bool::from(!point.is_small_order()));
+        assert!(bool::from(mixed.into_subgroup().is_none()));
+        assert_eq!(point.into_subgroup().unwrap(), SubgroupPoint(point));
+        assert_eq!(
+            EdwardsPoint::from(mixed.clear_cofactor()),
+            point.double().double()
+        );
+
+        // Only torsion-free points decode
+        assert_eq!(
+            SubgroupPoint::from_bytes(&point.to_bytes()).unwrap(),
+            SubgroupPoint(point)
+        );
+        assert!(bool::from(
+            SubgroupPoint::from_bytes(&mixed.to_bytes()).is_none()
+        ));
+    }
+
+    #[test]
+    fn group_ops() {
+        let s = Scalar::random(&mut OsRng);
+        let p = SubgroupPoint::generator() * s;
+        let q = SubgroupPoint::random(&mut OsRng);
+        assert!(bool::from(CofactorGroup::is_torsion_free(&q.0)));
+        assert_eq!(EdwardsPoint::from(p + q), p.0 + q.0);
+        assert_eq!(EdwardsPoint::from(p - q), p.0 - q.0);
+        assert_eq!(p.0 + q, p.0 + q.0);
+        assert_eq!(EdwardsPoint::from(-p), -p.0);
+        assert_eq!([p, q].iter().sum::<SubgroupPoint>(), p + q);
+        assert_eq!(p - p, SubgroupPoint::identity());
+    }
+
+    #[test]
+    fn cofactor_curve_affine() {
+        let s = Scalar::random(&mut OsRng);
+        let point = EdwardsPoint::GENERATOR * s;
+        let affine = point.to_affine();
+
+        assert_eq!(
+            <AffinePoint as CofactorCurveAffine>::generator().to_curve(),
+            EdwardsPoint::GENERATOR
+        );
+        assert!(bool::from(
+            <AffinePoint as CofactorCurveAffine>::identity().is_identity()
+        ));
+        assert!(bool::from(!affine.is_identity()));
+        assert_eq!(affine.to_curve(), point);
+        assert_eq!(EdwardsPoint::from(-affine), -point);
+        assert_eq!(<AffinePoint as CofactorCurveAffine>::generator() * s, point);
+        assert_eq!(AffinePoint::from_bytes(&affine.to_bytes()).unwrap(), affine);
+        assert_eq!(affine.to_bytes(), point.to_bytes());
+    }
+}
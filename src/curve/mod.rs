@@ -1,7 +1,12 @@
 pub mod edwards;
+pub(crate) mod model;
 pub mod montgomery;
 pub(crate) mod scalar_mul;
 pub(crate) mod twedwards;
 
-pub use edwards::{AffinePoint, CompressedEdwardsY, EdwardsPoint};
-pub use montgomery::{MontgomeryPoint, ProjectiveMontgomeryPoint};
+pub use edwards::{
+    AffinePoint, CompressedEdwardsY, EdwardsBasepointTable, EdwardsCombTable, EdwardsPoint,
+    SignConvention, SubgroupPoint, UncompressedEdwardsBytes,
+};
+pub use model::CurveModel;
+pub use montgomery::{MontgomeryPoint, MontgomeryPointFull, ProjectiveMontgomeryPoint};
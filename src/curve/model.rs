@@ -0,0 +1,270 @@
+//! The arithmetic shared by the two Edwards models of Goldilocks.
+//!
+//! Both curves have the affine equation `a·x^2 + y^2 = 1 + d·x^2·y^2`:
+//! the untwisted Ed448-Goldilocks curve has `a = 1, d = -39081`
+//! and its twist has `a = -1, d = -39082`.
+//! The formulas below are written once in terms of `a` and `d` and every point type
+//! on either curve gets them by implementing this trait, so an improvement to one formula
+//! applies to both curves.
+//!
+//! `a` is only ever ±1, so multiplying by it is a negation that is resolved at compile time.
+#![allow(non_snake_case)]
+
+use crate::field::FieldElement;
+use subtle::{Choice, ConstantTimeEq};
+
+pub(crate) mod sealed {
+    use crate::field::FieldElement;
+
+    /// Raw access to the coordinates, kept out of the public trait so that code outside
+    /// the crate cannot build a point that is not on the curve.
+    pub trait ExtendedCoordinates {
+        /// Build a point from extended coordinates `(X : Y : Z : T)` with `X·Y = Z·T`
+        fn from_extended(
+            X: FieldElement,
+            Y: FieldElement,
+            Z: FieldElement,
+            T: FieldElement,
+        ) -> Self;
+
+        /// The extended coordinates `(X, Y, Z, T)` of this point
+        fn extended(&self) -> (FieldElement, FieldElement, FieldElement, FieldElement);
+    }
+}
+
+use sealed::ExtendedCoordinates;
+
+/// The Edwards formulas shared by [`EdwardsPoint`](crate::EdwardsPoint) on `a = 1` and the
+/// points of its twist on `a = -1`, written once in terms of the curve constants.
+///
+/// The trait is sealed, it is only implemented by the point types of this crate.
+///
+/// ```
+/// use ed448_goldilocks_plus::{CurveModel, EdwardsPoint};
+///
+/// fn quadruple<P: CurveModel>(p: P) -> P {
+///     p.model_double().model_double()
+/// }
+///
+/// let p = EdwardsPoint::GENERATOR;
+/// assert_eq!(quadruple(p), p.double().double());
+/// ```
+///
+/// The raw coordinates stay private, so points off the curve cannot be built:
+///
+/// ```compile_fail
+/// use ed448_goldilocks_plus::{CurveModel, EdwardsPoint, FieldElement};
+///
+/// let p = EdwardsPoint::from_extended(
+///     FieldElement::ONE,
+///     FieldElement::ONE,
+///     FieldElement::ONE,
+///     FieldElement::ONE,
+/// );
+/// ```
+pub trait CurveModel: ExtendedCoordinates + Copy {
+    /// Whether `a = -1`, otherwise `a = 1`
+    const A_IS_MINUS_ONE: bool;
+    /// The `d` coefficient
+    const D: FieldElement;
+
+    /// The point type on the curve reached by [`CurveModel::isogeny`]
+    type Isogenous: CurveModel;
+
+    /// Compute `a·x`
+    #[inline(always)]
+    fn mul_by_a(x: FieldElement) -> FieldElement {
+        if Self::A_IS_MINUS_ONE {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Unified addition in extended coordinates
+    // https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf (3.1)
+    fn model_add(&self, other: &Self) -> Self {
+        let (X1, Y1, Z1, T1) = self.extended();
+        let (X2, Y2, Z2, T2) = other.extended();
+
+        let XX = X1 * X2; // X1X2
+        let YY = Y1 * Y2; // Y1Y2
+        let dTT = Self::D * T1 * T2; // dT1T2
+        let ZZ = Z1 * Z2; // Z1Z2
+        let XY = (X1 + Y1) * (X2 + Y2) - XX - YY; // X1Y2 + Y1X2
+
+        let E = ZZ - dTT;
+        let F = ZZ + dTT;
+        let G = YY - Self::mul_by_a(XX);
+
+        Self::from_extended(XY * E, G * F, E * F, G * XY)
+    }
+
+    /// Dedicated addition in extended coordinates, which does not need `d`.
+    ///
+    /// This is one multiplication cheaper than [`CurveModel::model_add`] when `a = -1`,
+    /// but it returns `(0 : 0 : 0 : 0)` for `self == other`, so it is only for sums
+    /// whose terms are known to be distinct.
+    // https://hyperelliptic.org/EFD/g1p/auto-twisted-extended-1.html#addition-add-2008-hwcd-4
+    // https://hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-add-2008-hwcd-2
+    fn model_add_dedicated(&self, other: &Self) -> Self {
+        let (X1, Y1, Z1, T1) = self.extended();
+        let (X2, Y2, Z2, T2) = other.extended();
+
+        let C = Z1 * T2;
+        let D = T1 * Z2;
+        if Self::A_IS_MINUS_ONE {
+            // Everything is scaled by 2 here, which cancels in the projective result
+            let A = (Y1 - X1) * (Y2 + X2);
+            let B = (Y1 + X1) * (Y2 - X2);
+            let E = (D + C).double();
+            let F = B - A;
+            let G = B + A;
+            let H = (D - C).double();
+            Self::from_extended(E * F, G * H, F * G, E * H)
+        } else {
+            let A = X1 * X2;
+            let B = Y1 * Y2;
+            let E = D + C;
+            let F = (X1 - Y1) * (X2 + Y2) + B - A;
+            let G = B + A;
+            let H = D - C;
+            Self::from_extended(E * F, G * H, F * G, E * H)
+        }
+    }
+
+    /// As [`CurveModel::model_add_dedicated`], for an `other` with `Z = 1`, such as a point
+    /// from a table normalised to affine, which saves another multiplication.
+    fn model_add_mixed(&self, other: &Self) -> Self {
+        let (X1, Y1, Z1, T1) = self.extended();
+        let (X2, Y2, Z2, T2) = other.extended();
+        debug_assert!(bool::from(Z2.ct_eq(&FieldElement::ONE)));
+
+        let C = Z1 * T2;
+        if Self::A_IS_MINUS_ONE {
+            let A = (Y1 - X1) * (Y2 + X2);
+            let B = (Y1 + X1) * (Y2 - X2);
+            let E = (T1 + C).double();
+            let F = B - A;
+            let G = B + A;
+            let H = (T1 - C).double();
+            Self::from_extended(E * F, G * H, F * G, E * H)
+        } else {
+            let A = X1 * X2;
+            let B = Y1 * Y2;
+            let E = T1 + C;
+            let F = (X1 - Y1) * (X2 + Y2) + B - A;
+            let G = B + A;
+            let H = T1 - C;
+            Self::from_extended(E * F, G * H, F * G, E * H)
+        }
+    }
+
+    /// Dedicated doubling in extended coordinates, which does not need `T`
+    // https://hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#doubling-dbl-2008-hwcd
+    fn model_double(&self) -> Self {
+        let (X1, Y1, Z1, _) = self.extended();
+
+        let A = X1.square();
+        let B = Y1.square();
+        let C = Z1.square().double();
+        let D = Self::mul_by_a(A);
+        let E = (X1 + Y1).square() - A - B;
+        let G = D + B;
+        let F = G - C;
+        let H = D - B;
+
+        Self::from_extended(E * F, G * H, F * G, E * H)
+    }
+
+    /// Check `a·X^2 + Y^2 = Z^2 + d·T^2` and `X·Y = Z·T`
+    fn model_is_on_curve(&self) -> Choice {
+        let (X, Y, Z, T) = self.extended();
+
+        let XY = X * Y;
+        let ZT = Z * T;
+
+        let lhs = Self::mul_by_a(X.square()) + Y.square();
+        let rhs = Z.square() + Self::D * T.square();
+
+        XY.ct_eq(&ZT) & lhs.ct_eq(&rhs)
+    }
+
+    /// The isogeny derived from the doubling formula, it maps the curve with
+    /// coefficient `a` to the one with `-a` (see <https://www.shiftleft.org/papers/isogeny/isogeny.pdf>)
+    ///
+    /// Applying it in both directions multiplies a point by 4.
+    fn isogeny(&self) -> Self::Isogenous {
+        // In affine coordinates x' = 2xy / (y^2 - a·x^2) and y' = (y^2 + a·x^2) / (2 - y^2 - a·x^2),
+        // projectively the common factors of Z cancel so no inversion is needed
+        let (X, Y, Z, _) = self.extended();
+
+        let XX = Self::mul_by_a(X.square()); // a·X^2
+        let YY = Y.square();
+        let XY = (X * Y).double();
+
+        let x_denom = YY - XX;
+        let y_numerator = YY + XX;
+        let y_denom = Z.square().double() - YY - XX;
+
+        Self::Isogenous::from_extended(
+            XY * y_denom,
+            y_numerator * x_denom,
+            x_denom * y_denom,
+            XY * y_numerator,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EdwardsPoint, TWISTED_EDWARDS_BASE_POINT};
+
+    fn check_model<P: CurveModel + ConstantTimeEq>(p: P) {
+        let q = p.model_double();
+        assert!(bool::from(q.ct_eq(&p.model_add(&p))));
+        assert!(bool::from(q.model_is_on_curve()));
+        assert!(bool::from(p.model_add(&q).model_is_on_curve()));
+    }
+
+    fn check_dedicated<P: CurveModel + ConstantTimeEq>(p: P, q: P, q_affine: P) {
+        let sum = p.model_add(&q);
+        assert!(bool::from(sum.ct_eq(&p.model_add_dedicated(&q))));
+        assert!(bool::from(sum.ct_eq(&p.model_add_mixed(&q_affine))));
+        assert!(bool::from(p.model_add_dedicated(&q).model_is_on_curve()));
+        assert!(bool::from(p.model_add_mixed(&q_affine).model_is_on_curve()));
+        // Doubling is the exceptional case
+        let (_, _, Z, _) = p.model_add_dedicated(&p).extended();
+        assert!(bool::from(Z.ct_eq(&FieldElement::ZERO)));
+    }
+
+    #[test]
+    fn test_dedicated_addition() {
+        let p = EdwardsPoint::GENERATOR.mul_u64(5);
+        let q = EdwardsPoint::GENERATOR.mul_u64(7).double();
+        check_dedicated(p, q, q.to_affine().to_edwards());
+        check_dedicated(
+            p.to_twisted(),
+            q.to_twisted(),
+            q.to_twisted().to_affine().to_extended(),
+        );
+        // The identity is distinct from every other point
+        check_dedicated(p, EdwardsPoint::IDENTITY, EdwardsPoint::IDENTITY);
+    }
+
+    #[test]
+    fn test_models_agree() {
+        let p = EdwardsPoint::GENERATOR.mul_u64(5);
+        check_model(p);
+        check_model(TWISTED_EDWARDS_BASE_POINT);
+        check_model(p.to_twisted());
+
+        // Going there and back again multiplies by 4
+        let four_p = p.double().double();
+        assert_eq!(p.isogeny().isogeny(), four_p);
+        assert_eq!(p.to_twisted().isogeny().isogeny(), four_p.to_twisted());
+        // The result does not depend on the projective representative
+        assert_eq!(p.isogeny(), p.to_affine().to_edwards().isogeny());
+    }
+}
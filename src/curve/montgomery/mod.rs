@@ -1,4 +1,4 @@
 pub(crate) mod montgomery;
 
-pub use montgomery::{MontgomeryPoint, ProjectiveMontgomeryPoint};
+pub use montgomery::{MontgomeryPoint, MontgomeryPointFull, ProjectiveMontgomeryPoint};
 // This is incomplete. Missing the 4-isogenous maps from Goldilocks and test
@@ -11,11 +11,16 @@
 #![allow(non_snake_case)]
 
 // use crate::constants::A_PLUS_TWO_OVER_FOUR;
-use crate::curve::edwards::extended::EdwardsPoint;
+use crate::curve::edwards::{affine::AffinePoint, extended::EdwardsPoint};
 use crate::field::{FieldElement, Scalar};
+use elliptic_curve::{
+    generic_array::typenum::U56,
+    group::GroupEncoding,
+    hash2curve::{ExpandMsg, ExpandMsgXof},
+};
 use std::fmt;
-use std::ops::Mul;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use std::ops::{Mul, MulAssign, Neg};
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
@@ -39,10 +44,18 @@ const LOW_C: MontgomeryPoint = MontgomeryPoint([
     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
 ]);
 
+/// The default hash to curve domain separation tag
+pub const DEFAULT_HASH_TO_CURVE_SUITE: &[u8] = b"curve448_XOF:SHAKE256_ELL2_RO_";
+/// The default encode to curve domain separation tag
+pub const DEFAULT_ENCODE_TO_CURVE_SUITE: &[u8] = b"curve448_XOF:SHAKE256_ELL2_NU_";
+
 #[derive(Copy, Clone, Hash)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[repr(transparent)]
 pub struct MontgomeryPoint(pub [u8; 56]);
 
+define_byte_slice_casts!(TYPE = MontgomeryPoint, LEN = 56);
+
 impl fmt::Debug for MontgomeryPoint {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         self.0[..].fmt(formatter)
@@ -55,6 +68,16 @@ impl ConstantTimeEq for MontgomeryPoint {
     }
 }
 
+impl ConditionallySelectable for MontgomeryPoint {
+    fn conditional_select(a: &MontgomeryPoint, b: &MontgomeryPoint, choice: Choice) -> Self {
+        let mut bytes = [0u8; 56];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        MontgomeryPoint(bytes)
+    }
+}
+
 impl PartialEq for MontgomeryPoint {
     fn eq(&self, other: &MontgomeryPoint) -> bool {
         self.ct_eq(other).into()
@@ -62,6 +85,11 @@ impl PartialEq for MontgomeryPoint {
 }
 impl Eq for MontgomeryPoint {}
 
+/// A point on Curve448 in projective `(U : W)` coordinates, the state of a Montgomery ladder.
+///
+/// The two rungs of a ladder are swapped in constant time with
+/// `ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, choice)`
+/// from [`ConditionallySelectable`].
 #[derive(Copy, Clone)]
 pub struct ProjectiveMontgomeryPoint {
     U: FieldElement,
@@ -71,27 +99,12 @@ pub struct ProjectiveMontgomeryPoint {
 impl Mul<&Scalar> for &MontgomeryPoint {
     type Output = MontgomeryPoint;
     fn mul(self, scalar: &Scalar) -> MontgomeryPoint {
-        // Algorithm 8 of Costello-Smith 2017
-        let affine_u = FieldElement::from_bytes(&self.0);
-        let mut x0 = ProjectiveMontgomeryPoint::identity();
-        let mut x1 = ProjectiveMontgomeryPoint {
-            U: affine_u,
-            W: FieldElement::ONE,
-        };
-
-        let bits = scalar.bits();
-        let mut swap = 0;
-        for s in (0..448).rev() {
-            let bit = bits[s] as u8;
-            let choice: u8 = swap ^ bit;
-
-            ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(choice));
-            differential_add_and_double(&mut x0, &mut x1, &affine_u);
-
-            swap = bit;
-        }
-
-        x0.to_affine()
+        #[allow(unused_mut)]
+        let mut bytes = scalar.to_bytes();
+        let result = self.ladder(&bytes);
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+        result
     }
 }
 
@@ -102,11 +115,214 @@ impl Mul<&MontgomeryPoint> for &Scalar {
     }
 }
 
+impl MulAssign<&Scalar> for MontgomeryPoint {
+    fn mul_assign(&mut self, scalar: &Scalar) {
+        *self = *self * scalar;
+    }
+}
+
+define_mul_assign_variants!(LHS = MontgomeryPoint, RHS = Scalar);
+
+define_mul_variants!(
+    LHS = MontgomeryPoint,
+    RHS = Scalar,
+    Output = MontgomeryPoint
+);
+define_mul_variants!(
+    LHS = Scalar,
+    RHS = MontgomeryPoint,
+    Output = MontgomeryPoint
+);
+
+/// The encoding is the 56-byte u-coordinate, which must be canonical.
+///
+/// Points on the twist are accepted, as in X448, since `u` alone can't tell them apart
+/// without a square root.
+#[allow(deprecated)]
+impl GroupEncoding for MontgomeryPoint {
+    type Repr = elliptic_curve::generic_array::GenericArray<u8, U56>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        let mut value = [0u8; 56];
+        value.copy_from_slice(bytes);
+        let is_canonical = FieldElement::from_bytes(&value).to_bytes().ct_eq(&value);
+        CtOption::new(MontgomeryPoint(value), is_canonical)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        let mut value = [0u8; 56];
+        value.copy_from_slice(bytes);
+        CtOption::new(MontgomeryPoint(value), Choice::from(1))
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        Self::Repr::from(self.0)
+    }
+}
+
+/// Serializes the canonical encoding of the u-coordinate,
+/// deserializing rejects values of `p` and above
+#[cfg(feature = "serde")]
+impl serde::Serialize for MontgomeryPoint {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let bytes = FieldElement::from_bytes(&self.0).to_bytes();
+        if s.is_human_readable() {
+            hex::encode(bytes).serialize(s)
+        } else {
+            use serde::ser::SerializeTuple;
+
+            let mut tup = s.serialize_tuple(bytes.len())?;
+            for b in bytes.iter() {
+                tup.serialize_element(b)?;
+            }
+            tup.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MontgomeryPoint {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes = if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            let bytes = hex::decode(s).map_err(serde::de::Error::custom)?;
+            <[u8; 56]>::try_from(bytes)
+                .map_err(|_| serde::de::Error::custom("invalid byte length"))?
+        } else {
+            use serde::de::SeqAccess;
+
+            struct MontgomeryPointVisitor;
+
+            impl<'de> serde::de::Visitor<'de> for MontgomeryPointVisitor {
+                type Value = [u8; 56];
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "a sequence of 56 bytes")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut bytes = [0u8; 56];
+                    for (i, b) in bytes.iter_mut().enumerate() {
+                        *b = seq
+                            .next_element()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(i, &"56"))?;
+                    }
+                    Ok(bytes)
+                }
+            }
+
+            d.deserialize_tuple(56, MontgomeryPointVisitor)?
+        };
+        Option::from(MontgomeryPoint::from_bytes(&bytes.into()))
+            .ok_or_else(|| serde::de::Error::custom("non-canonical u-coordinate"))
+    }
+}
+
 impl MontgomeryPoint {
-    pub fn to_edwards(&self, _sign: u8) -> Option<EdwardsPoint> {
+    /// Compute the X448 function of [RFC 7748 § 5]: clamp `bytes` to a multiple of the
+    /// cofactor with the top bit set, and multiply this point by it.
+    ///
+    /// The u-coordinate is used as is, so points on the twist give the result on the twist
+    /// as the RFC requires, and the result is all zeros for the low order points.
+    ///
+    /// [RFC 7748 § 5]: https://www.rfc-editor.org/rfc/rfc7748#section-5
+    pub fn mul_clamped(&self, bytes: [u8; 56]) -> MontgomeryPoint {
+        #[allow(unused_mut)]
+        let mut bytes = crate::x448::clamp_integer(bytes);
+        let result = self.ladder(&bytes);
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+        result
+    }
+
+    /// The constant-time Montgomery ladder of RFC 7748 on the u-coordinate,
+    /// with the little-endian scalar `k`.
+    fn ladder(&self, k: &[u8; 56]) -> MontgomeryPoint {
+        #[allow(unused_mut, unused_variables)]
+        let (mut x0, mut x1) = ladder(&FieldElement::from_bytes(&self.0), k);
+        let result = x0.to_affine();
+
+        // The ladder state reveals the scalar bits
+        #[cfg(feature = "zeroize")]
+        {
+            x0.zeroize();
+            x1.zeroize();
+        }
+
+        result
+    }
+
+    /// Recover an Edwards point from this u-coordinate, the inverse of
+    /// [`EdwardsPoint::to_montgomery`].
+    ///
+    /// `to_montgomery` is the 4-isogeny of RFC 7748, which forgets the sign of `x` and
+    /// the torsion component. This returns the point `P` in the prime-order subgroup
+    /// with `P.to_montgomery() == self` whose `x` is negative if the low bit of `sign` is set.
+    /// It is `None` for u-coordinates on the twist, for the low order points other than
+    /// `0`, the image of the identity, and for non-canonical encodings.
+    pub fn to_edwards(&self, sign: u8) -> Option<EdwardsPoint> {
         // We use the 4-isogeny to map to the Ed448.
         // This is different to Curve25519, where we use a birational map.
-        todo!()
+        // The dual isogeny of RFC 7748 § 4.2 maps (u, v) to [-4]P, so divide by 4 afterwards
+        // and fix the sign below
+        let u = FieldElement::from_bytes(&self.0);
+        let uu = u.square();
+        let vv = u * (uu + FieldElement::J * u + FieldElement::ONE);
+        let is_on_curve = vv.is_square();
+        let v = vv.sqrt();
+
+        let u4 = uu.square();
+        let u5 = u4 * u;
+        let uuu = uu * u;
+        let x = v.double().double()
+            * (uu - FieldElement::ONE)
+            * (u4 - uu.double() + vv.double().double() + FieldElement::ONE).invert();
+        let y = -(u5 - uuu.double() - (u * vv).double().double() + u)
+            * (u5 - (uu * vv).double() - uuu.double() - vv.double() + u).invert();
+        let four_p = AffinePoint { x, y }.to_edwards();
+        let mut point = four_p * Scalar::from(4u8).invert();
+
+        let is_identity = self.ct_eq(&LOW_A);
+        point.conditional_assign(&EdwardsPoint::IDENTITY, is_identity);
+        let flip = point.to_affine().x.is_negative() ^ Choice::from(sign & 1);
+        point.conditional_negate(flip);
+
+        let is_preimage = point.to_montgomery().ct_eq(self);
+        CtOption::new(point, (is_on_curve & is_preimage) | is_identity).into()
+    }
+
+    /// Hash using the default domain separation tag and hash function
+    pub fn hash_with_defaults(msg: &[u8]) -> Self {
+        Self::hash::<ExpandMsgXof<sha3::Shake256>>(msg, DEFAULT_HASH_TO_CURVE_SUITE)
+    }
+
+    /// Implements the curve448 hash to curve suites of RFC 9380,
+    /// see [`MontgomeryPointFull::hash`]
+    pub fn hash<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        EdwardsPoint::map_to_isogenous::<X>(msg, dst, 2).to_montgomery()
+    }
+
+    /// Encode using the default domain separation tag and hash function
+    pub fn encode_with_defaults(msg: &[u8]) -> Self {
+        Self::encode::<ExpandMsgXof<sha3::Shake256>>(msg, DEFAULT_ENCODE_TO_CURVE_SUITE)
+    }
+
+    /// Implements the curve448 encode to curve suites of RFC 9380,
+    /// see [`MontgomeryPointFull::encode`]
+    pub fn encode<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        EdwardsPoint::map_to_isogenous::<X>(msg, dst, 1).to_montgomery()
     }
 
     /// Returns true if the point is one of the low order points
@@ -118,6 +334,22 @@ impl MontgomeryPoint {
         &self.0
     }
 
+    /// Construct a `MontgomeryPoint` from the big-endian encoding of its
+    /// u-coordinate, as used by some PKCS#11 tokens and HSMs for
+    /// `CKK_EC_MONTGOMERY` keys.
+    pub fn from_be_bytes(bytes: &[u8; 56]) -> MontgomeryPoint {
+        let mut le = *bytes;
+        le.reverse();
+        MontgomeryPoint(le)
+    }
+
+    /// Copy the u-coordinate to a big-endian array of bytes.
+    pub fn to_be_bytes(&self) -> [u8; 56] {
+        let mut be = self.0;
+        be.reverse();
+        be
+    }
+
     /// Returns the generator specified in RFC7748
     pub const fn generator() -> MontgomeryPoint {
         MontgomeryPoint([
@@ -136,6 +368,14 @@ impl MontgomeryPoint {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Zeroize for ProjectiveMontgomeryPoint {
+    fn zeroize(&mut self) {
+        self.U.zeroize();
+        self.W.zeroize();
+    }
+}
+
 impl ConditionallySelectable for ProjectiveMontgomeryPoint {
     fn conditional_select(
         a: &ProjectiveMontgomeryPoint,
@@ -149,6 +389,38 @@ impl ConditionallySelectable for ProjectiveMontgomeryPoint {
     }
 }
 
+/// The Montgomery ladder on the affine u-coordinate `affine_u` with the little-endian
+/// scalar `k`, returning both rungs `([k]P, [k+1]P)`.
+fn ladder(
+    affine_u: &FieldElement,
+    k: &[u8; 56],
+) -> (ProjectiveMontgomeryPoint, ProjectiveMontgomeryPoint) {
+    // Algorithm 8 of Costello-Smith 2017
+    let mut x0 = ProjectiveMontgomeryPoint::identity();
+    let mut x1 = ProjectiveMontgomeryPoint {
+        U: *affine_u,
+        W: FieldElement::ONE,
+    };
+
+    let mut swap = 0;
+    for s in (0..448).rev() {
+        let bit = (k[s >> 3] >> (s & 7)) & 1;
+        let choice: u8 = swap ^ bit;
+
+        ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(choice));
+        differential_add_and_double(&mut x0, &mut x1, affine_u);
+
+        swap = bit;
+    }
+    // Undo the swap of the last step, otherwise odd scalars return the wrong rung
+    ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(swap));
+
+    #[cfg(feature = "zeroize")]
+    swap.zeroize();
+
+    (x0, x1)
+}
+
 fn differential_add_and_double(
     P: &mut ProjectiveMontgomeryPoint,
     Q: &mut ProjectiveMontgomeryPoint,
@@ -201,11 +473,506 @@ impl ProjectiveMontgomeryPoint {
     }
 }
 
+/// A point on Curve448 with both affine coordinates `(u, v)`, where `v^2 = u^3 + A u^2 + u`.
+///
+/// [`MontgomeryPoint`] is only the u-coordinate, which is all X448 needs but
+/// determines a point only up to sign. Protocols that need the whole point can use
+/// this instead: scalar multiplication still runs the ladder on `u`, and recovers `v`
+/// at the end with the formula of Okeya and Sakurai, "Efficient Elliptic Curve
+/// Cryptosystems from a Scalar Multiplication Algorithm with Recovery of the
+/// y-Coordinate on a Montgomery-Form Elliptic Curve" (CHES 2001).
+#[derive(Copy, Clone, Debug)]
+pub struct MontgomeryPointFull {
+    // The point at infinity has no affine coordinates, it is stored as (0, 1),
+    // which is not on the curve
+    pub(crate) u: FieldElement,
+    pub(crate) v: FieldElement,
+}
+
+impl ConstantTimeEq for MontgomeryPointFull {
+    fn ct_eq(&self, other: &MontgomeryPointFull) -> Choice {
+        self.u.ct_eq(&other.u) & self.v.ct_eq(&other.v)
+    }
+}
+
+impl ConditionallySelectable for MontgomeryPointFull {
+    fn conditional_select(
+        a: &MontgomeryPointFull,
+        b: &MontgomeryPointFull,
+        choice: Choice,
+    ) -> MontgomeryPointFull {
+        MontgomeryPointFull {
+            u: FieldElement::conditional_select(&a.u, &b.u, choice),
+            v: FieldElement::conditional_select(&a.v, &b.v, choice),
+        }
+    }
+}
+
+impl PartialEq for MontgomeryPointFull {
+    fn eq(&self, other: &MontgomeryPointFull) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+impl Eq for MontgomeryPointFull {}
+
+impl Neg for &MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn neg(self) -> MontgomeryPointFull {
+        let mut point = *self;
+        point.v.conditional_negate(!self.is_identity());
+        point
+    }
+}
+
+impl Neg for MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn neg(self) -> MontgomeryPointFull {
+        -&self
+    }
+}
+
+impl Mul<&Scalar> for &MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn mul(self, scalar: &Scalar) -> MontgomeryPointFull {
+        #[allow(unused_mut)]
+        let mut bytes = scalar.to_bytes();
+        #[allow(unused_mut)]
+        let (mut x0, mut x1) = ladder(&self.u, &bytes);
+        let (x, y) = (self.u, self.v);
+        let (X1, Z1, X2, Z2) = (x0.U, x0.W, x1.U, x1.W);
+
+        // Okeya-Sakurai with B = 1, from P = (x, y), [k]P = (X1 : Z1) and [k+1]P = (X2 : Z2)
+        let yzz = (y * Z1 * Z2).double();
+        let A = FieldElement::J;
+        let V = Z2
+            * ((X1 + x * Z1 + (A * Z1).double()) * (x * X1 + Z1) - (A * Z1.square()).double())
+            - (X1 - x * Z1).square() * X2;
+        let W = (yzz * Z1).invert();
+        let mut result = MontgomeryPointFull {
+            u: yzz * X1 * W,
+            v: V * W,
+        };
+
+        // The formula divides by zero when [k+1]P is the identity, so [k]P = -P,
+        // and when [k]P is the identity
+        result.conditional_assign(&-self, Z2.ct_eq(&FieldElement::ZERO));
+        result.conditional_assign(
+            &MontgomeryPointFull::IDENTITY,
+            Z1.ct_eq(&FieldElement::ZERO),
+        );
+        // The ladder itself degenerates on (0, 0), which has order 2
+        let mut two_torsion = MontgomeryPointFull::IDENTITY;
+        two_torsion
+            .v
+            .conditional_assign(&FieldElement::ZERO, Choice::from(bytes[0] & 1));
+        result.conditional_assign(&two_torsion, y.ct_eq(&FieldElement::ZERO));
+        result.conditional_assign(&MontgomeryPointFull::IDENTITY, self.is_identity());
+
+        #[cfg(feature = "zeroize")]
+        {
+            bytes.zeroize();
+            x0.zeroize();
+            x1.zeroize();
+        }
+
+        result
+    }
+}
+
+impl Mul<&MontgomeryPointFull> for &Scalar {
+    type Output = MontgomeryPointFull;
+
+    fn mul(self, point: &MontgomeryPointFull) -> MontgomeryPointFull {
+        point * self
+    }
+}
+
+define_mul_variants!(
+    LHS = MontgomeryPointFull,
+    RHS = Scalar,
+    Output = MontgomeryPointFull
+);
+define_mul_variants!(
+    LHS = Scalar,
+    RHS = MontgomeryPointFull,
+    Output = MontgomeryPointFull
+);
+
+impl MontgomeryPointFull {
+    /// The point at infinity
+    pub const IDENTITY: MontgomeryPointFull = MontgomeryPointFull {
+        u: FieldElement::ZERO,
+        v: FieldElement::ONE,
+    };
+
+    /// The generator of RFC 7748 § 4.2, `u = 5` with the even `v`.
+    /// It is the image of the Ed448 generator under [`EdwardsPoint::to_montgomery_full`].
+    pub fn generator() -> MontgomeryPointFull {
+        Self::from_u(&MontgomeryPoint::generator(), 0).unwrap()
+    }
+
+    /// Lift a u-coordinate to the point whose `v` is negative, i.e. odd,
+    /// if the low bit of `sign` is set.
+    ///
+    /// Returns `None` if `u` is on the twist. Note that `u = 0` is the point `(0, 0)` of
+    /// order 2, not the identity as in X448.
+    pub fn from_u(u: &MontgomeryPoint, sign: u8) -> Option<MontgomeryPointFull> {
+        let u = FieldElement::from_bytes(&u.0);
+        let vv = u * (u.square() + FieldElement::J * u + FieldElement::ONE);
+        let mut v = vv.sqrt();
+        let is_on_curve = v.square().ct_eq(&vv);
+        let flip = v.is_negative() ^ Choice::from(sign & 1);
+        v.conditional_negate(flip);
+        CtOption::new(MontgomeryPointFull { u, v }, is_on_curve).into()
+    }
+
+    /// Construct a point from the little-endian encodings of its coordinates.
+    ///
+    /// Returns `None` if either encoding is not canonical or the point is not on the curve.
+    pub fn from_uv(u: &[u8; 56], v: &[u8; 56]) -> Option<MontgomeryPointFull> {
+        let point = MontgomeryPointFull {
+            u: FieldElement::from_bytes(u),
+            v: FieldElement::from_bytes(v),
+        };
+        let is_canonical = point.u.to_bytes().ct_eq(u) & point.v.to_bytes().ct_eq(v);
+        let uu = point.u.square();
+        let is_on_curve = point
+            .v
+            .square()
+            .ct_eq(&(point.u * (uu + FieldElement::J * point.u + FieldElement::ONE)));
+        CtOption::new(point, is_canonical & is_on_curve).into()
+    }
+
+    /// Implements hash to curve for curve448 according to
+    /// <https://datatracker.ietf.org/doc/rfc9380/>, with `X` the `expand_message` of the suite.
+    ///
+    /// The RFC adds the two Elligator 2 points on curve448 and clears the cofactor by
+    /// multiplying by 4. This adds them on edwards448 instead, where addition is complete:
+    /// the points are moved there with the 4-isogeny of RFC 7748 § 4.2 and back with its
+    /// dual, and the composition of the two is the multiplication by -4.
+    pub fn hash<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        -EdwardsPoint::map_to_isogenous::<X>(msg, dst, 2).to_montgomery_full()
+    }
+
+    /// Implements encode to curve for curve448 according to
+    /// <https://datatracker.ietf.org/doc/rfc9380/>, see [`MontgomeryPointFull::hash`]
+    pub fn encode<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        -EdwardsPoint::map_to_isogenous::<X>(msg, dst, 1).to_montgomery_full()
+    }
+
+    /// The u-coordinate, which is all zeros for the identity as in X448
+    pub fn u(&self) -> MontgomeryPoint {
+        MontgomeryPoint(self.u.to_bytes())
+    }
+
+    /// The little-endian encoding of the v-coordinate, which is one for the identity
+    pub fn v(&self) -> [u8; 56] {
+        self.v.to_bytes()
+    }
+
+    /// Whether this is the point at infinity
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&MontgomeryPointFull::IDENTITY)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let point = MontgomeryPoint::generator() * Scalar::from(7u8);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(point.0)));
+        assert_eq!(
+            serde_json::from_str::<MontgomeryPoint>(&json).unwrap(),
+            point
+        );
+        let bare = serde_bare::to_vec(&point).unwrap();
+        assert_eq!(bare.len(), 56);
+        assert_eq!(
+            serde_bare::from_slice::<MontgomeryPoint>(&bare).unwrap(),
+            point
+        );
+
+        // p encodes zero, it serializes reduced and is rejected on the way in
+        let mut p = [0xffu8; 56];
+        p[28] = 0xfe;
+        let bare = serde_bare::to_vec(&MontgomeryPoint(p)).unwrap();
+        assert_eq!(bare, [0u8; 56]);
+        let json = serde_json::to_string(&hex::encode(p)).unwrap();
+        assert!(serde_json::from_str::<MontgomeryPoint>(&json).is_err());
+    }
+
+    #[test]
+    fn test_byte_slice_casts() {
+        let points = [MontgomeryPoint::generator(), MontgomeryPoint([9u8; 56])];
+        let bytes = MontgomeryPoint::slice_as_bytes(&points);
+        assert_eq!(bytes[56..], [9u8; 56]);
+        assert_eq!(
+            MontgomeryPoint::slice_from_bytes(bytes).unwrap(),
+            &points[..]
+        );
+        assert!(MontgomeryPoint::slice_from_bytes(&bytes[1..]).is_none());
+    }
+
+    #[test]
+    fn test_be_bytes() {
+        let g = MontgomeryPoint::generator();
+        let be = g.to_be_bytes();
+        assert_eq!(be[55], 5);
+        assert!(be[..55].iter().all(|b| *b == 0));
+        assert_eq!(MontgomeryPoint::from_be_bytes(&be), g);
+    }
+
+    #[test]
+    fn test_conditional_swap() {
+        let a = MontgomeryPoint::generator();
+        let b = &a * &Scalar::from(3u8);
+        let (mut x, mut y) = (a, b);
+        MontgomeryPoint::conditional_swap(&mut x, &mut y, Choice::from(0));
+        assert_eq!((x, y), (a, b));
+        MontgomeryPoint::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((x, y), (b, a));
+    }
+
+    #[test]
+    fn test_projective_conditional_swap() {
+        let a = ProjectiveMontgomeryPoint::identity();
+        let b = ProjectiveMontgomeryPoint {
+            U: FieldElement::from_bytes(&MontgomeryPoint::generator().0),
+            W: FieldElement::ONE,
+        };
+        let (mut x0, mut x1) = (a, b);
+        ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(0));
+        assert_eq!(x0.U, a.U);
+        assert_eq!(x1.U, b.U);
+        ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(1));
+        assert_eq!((x0.U, x0.W), (b.U, b.W));
+        assert_eq!((x1.U, x1.W), (a.U, a.W));
+    }
+
+    #[test]
+    fn test_rfc7748_vectors() {
+        use hex_literal::hex;
+
+        // RFC 7748 § 5.2
+        let vectors = [
+            (
+                hex!("3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3"),
+                hex!("06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086"),
+                hex!("ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaadeb445fc66a01b0779d98223961111e21766282f73dd96b6f"),
+            ),
+            (
+                hex!("203d494428b8399352665ddca42f9de8fef600908e0d461cb021f8c538345dd77c3e4806e25f46d3315c44e0a5b4371282dd2c8d5be3095f"),
+                hex!("0fbcc2f993cd56d3305b0b7d9e55d4c1a8fb5dbb52f8e9a1e9b6201b165d015894e56c4d3570bee52fe205e28a78b91cdfbde71ce8d157db"),
+                hex!("884a02576239ff7a2f2f63b2db6a9ff37047ac13568e1e30fe63c4a7ad1b3ee3a5700df34321d62077e63633c575c1c954514e99da7c179d"),
+            ),
+        ];
+        for (k, u, expected) in vectors {
+            assert_eq!(MontgomeryPoint(u).mul_clamped(k).0, expected);
+        }
+
+        // The iterated test, without the slow million iterations
+        let mut k = MontgomeryPoint::generator().0;
+        let mut u = k;
+        for i in 1..=1000 {
+            let next = MontgomeryPoint(u).mul_clamped(k).0;
+            u = k;
+            k = next;
+            if i == 1 {
+                assert_eq!(k, hex!("3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd0db897086239492caf350b51f833868b9bc2b3bca9cf4113"));
+            }
+        }
+        assert_eq!(k, hex!("aa3b4749d55b9daf1e5b00288826c467274ce3ebbdd5c17b975e09d4af6c67cf10d087202db88286e2b79fceea3ec353ef54faa26e219f38"));
+    }
+
+    #[test]
+    fn test_odd_scalar() {
+        let g = MontgomeryPoint::generator();
+        assert_eq!(&g * &Scalar::ONE, g);
+        let goldilocks = crate::GOLDILOCKS_BASE_POINT;
+        assert_eq!(
+            &goldilocks.to_montgomery() * &Scalar::from(201u32),
+            goldilocks.scalar_mul(&Scalar::from(201u32)).to_montgomery()
+        );
+    }
+
+    #[test]
+    fn test_to_edwards() {
+        use rand_core::OsRng;
+
+        for _ in 0..8 {
+            let p = EdwardsPoint::GENERATOR * Scalar::random(&mut OsRng);
+            let u = p.to_montgomery();
+            let sign = p.to_affine().x.is_negative().unwrap_u8();
+            assert_eq!(u.to_edwards(sign), Some(p));
+            assert_eq!(u.to_edwards(sign ^ 1), Some(-p));
+        }
+        assert_eq!(
+            MontgomeryPoint::generator()
+                .to_edwards(0)
+                .map(|p| p.to_montgomery()),
+            Some(MontgomeryPoint::generator())
+        );
+
+        // The identity, and the low order points
+        assert_eq!(LOW_A.to_edwards(0), Some(EdwardsPoint::IDENTITY));
+        assert_eq!(LOW_B.to_edwards(0), None);
+        assert_eq!(LOW_C.to_edwards(0), None);
+
+        // 6 is on the twist, and 2 is on the curve but outside the image of the prime-order subgroup
+        let mut u = [0u8; 56];
+        u[0] = 6;
+        assert_eq!(MontgomeryPoint(u).to_edwards(0), None);
+        u[0] = 2;
+        assert_eq!(MontgomeryPoint(u).to_edwards(0), None);
+    }
+
+    #[test]
+    fn test_operators_and_encoding() {
+        let g = MontgomeryPoint::generator();
+        let s = Scalar::from(7u8);
+        let expected = g * s;
+        assert_eq!(s * g, expected);
+        let mut p = g;
+        p *= s;
+        assert_eq!(p, expected);
+
+        let bytes = GroupEncoding::to_bytes(&expected);
+        assert_eq!(&bytes[..], expected.as_bytes());
+        assert_eq!(MontgomeryPoint::from_bytes(&bytes).unwrap(), expected);
+
+        // p + 1 encodes 1, but not canonically
+        let mut p_plus_one = [0xffu8; 56];
+        p_plus_one[..28].fill(0);
+        let p_plus_one = <MontgomeryPoint as GroupEncoding>::Repr::from(p_plus_one);
+        assert!(bool::from(
+            MontgomeryPoint::from_bytes(&p_plus_one).is_none()
+        ));
+        assert!(bool::from(
+            MontgomeryPoint::from_bytes_unchecked(&p_plus_one).is_some()
+        ));
+    }
+
+    #[test]
+    fn test_full_point() {
+        use hex_literal::hex;
+        use rand_core::OsRng;
+
+        // The v-coordinate of the generator in RFC 7748 § 4.2
+        let g = MontgomeryPointFull::generator();
+        assert_eq!(g.u(), MontgomeryPoint::generator());
+        assert_eq!(g.v(), hex!("1a5b7b453d22d76ff77a6750b1c41213210d4346237e02b8edf6f38dc25df760d04555f5345daecbce6f32586eab986cf6b1f595125d237d"));
+        assert_eq!(EdwardsPoint::GENERATOR.to_montgomery_full(), g);
+        assert_eq!(MontgomeryPointFull::from_u(&g.u(), 1), Some(-g));
+
+        for _ in 0..4 {
+            let s = Scalar::random(&mut OsRng);
+            let p = g * s;
+            assert_eq!(p, (EdwardsPoint::GENERATOR * s).to_montgomery_full());
+            assert_eq!(p.u(), MontgomeryPoint::generator() * s);
+            assert_eq!(s * g, p);
+        }
+
+        // The exceptional cases of the recovery
+        assert_eq!(g * Scalar::ZERO, MontgomeryPointFull::IDENTITY);
+        assert_eq!(g * -Scalar::ONE, -g);
+        assert_eq!(
+            MontgomeryPointFull::IDENTITY * Scalar::from(3u8),
+            MontgomeryPointFull::IDENTITY
+        );
+        let two_torsion = MontgomeryPointFull::from_u(&LOW_A, 0).unwrap();
+        assert_eq!(two_torsion * Scalar::from(3u8), two_torsion);
+        assert!(bool::from((two_torsion * Scalar::from(2u8)).is_identity()));
+
+        // 6 is on the twist, and p + 1 is not canonical
+        let mut u = [0u8; 56];
+        u[0] = 6;
+        assert_eq!(MontgomeryPointFull::from_u(&MontgomeryPoint(u), 0), None);
+        assert_eq!(MontgomeryPointFull::from_uv(&g.u().0, &g.v()), Some(g));
+        assert_eq!(MontgomeryPointFull::from_uv(&u, &g.v()), None);
+        let mut p_plus_one = [0xffu8; 56];
+        p_plus_one[..28].fill(0);
+        assert_eq!(MontgomeryPointFull::from_uv(&p_plus_one, &[0u8; 56]), None);
+        assert_eq!(
+            MontgomeryPointFull::from_uv(&[1u8; 56][..].try_into().unwrap(), &g.v()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        use hex_literal::hex;
+
+        // RFC 9380 § J.6.1, P.x and P.y in big-endian
+        const DST: &[u8] = b"QUUX-V01-CS02-with-curve448_XOF:SHAKE256_ELL2_RO_";
+        const MSGS: &[(&[u8], [u8; 56], [u8; 56])] = &[
+            (b"", hex!("5ea5ff623d27c75e73717514134e73e419f831a875ca9e82915fdfc7069d0a9f8b532cfb32b1d8dd04ddeedbe3fa1d0d681c01e825d6a9ea"), hex!("5052272187607071cae910441cec58145c9b36c61345ff2540b312a2a9c4e718f5d7b3e8270b94a9b3b1931ee87b5c7da26beee99dded73e")),
+            (b"abc", hex!("9b2f7ce34878d7cebf34c582db14958308ea09366d1ec71f646411d3de0ae564d082b06f40cd30dfc08d9fb7cb21df390cf207806ad9d0e4"), hex!("ec75f110f5b66c15969ead128249e08224b17effa8ca6e18b9929e3e3a97135136c61c957b2d890cb3bfdad92767656916689f3b796129cc")),
+            (b"abcdef0123456789", hex!("f54ecd14b85a50eeeee0618452df3a75be7bfba11da5118774ae4ea55ac204e153f77285d780c4acee6c96abe3577a0c0b00be6e790cf194"), hex!("6cadb859b40873ef8f966bc381c133ad534d831b5cdcfbf837ca8cbd97a15dead173c256c07328825e4224a448a639185182ae92312bd7af")),
+            (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", hex!("5bd67c4f88adf6beb10f7e0d0054659776a55c97b809ec8b3101729e104fd0f684e103792f267fd87cc4afc25a073956ef4f268fb02824d5"), hex!("25e0a34e95cad8e61b34f9b30b8458d514588ad2fc1735d3a9dd60bd64b10c8787a5a50e5ac22854b2b983e06d084ec64c8ad050d6369bcd")),
+            (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", hex!("ea441c10b3636ecedd5c0dfcae96384cc40de8390a0ab648765b4508da12c586d55dc981275776507ebca0e4d1bcaa302bb69dcfa31b3451"), hex!("011fe6d2b6433f3d726ab89c3d3418c646d9a3b4141c77c7fc39b68dddf30259f46536652679326f73facb4d9f4a3035b9093d4f0c0de425")),
+        ];
+
+        for (msg, x, y) in MSGS {
+            let p = MontgomeryPointFull::hash::<ExpandMsgXof<sha3::Shake256>>(msg, DST);
+            assert_eq!(p.u().to_be_bytes(), *x);
+            let mut v = p.v();
+            v.reverse();
+            assert_eq!(v, *y);
+            assert_eq!(
+                MontgomeryPoint::hash::<ExpandMsgXof<sha3::Shake256>>(msg, DST),
+                p.u()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_to_curve() {
+        use hex_literal::hex;
+
+        // RFC 9380 § J.6.2
+        const DST: &[u8] = b"QUUX-V01-CS02-with-curve448_XOF:SHAKE256_ELL2_NU_";
+        const MSGS: &[(&[u8], [u8; 56], [u8; 56])] = &[
+            (b"", hex!("b65e8dbb279fd656f926f68d463b13ca7a982b32f5da9c7cc58afcf6199e4729863fb75ca9ae3c95c6887d95a5102637a1c5c40ff0aafadc"), hex!("15e15dee30d6135ee3fa8017db7e7ea6e5e60953ae2ba7bc59a2b44648e437c59b388e128979de75d8710e3a29df0c2d94ace9de779babac")),
+            (b"abc", hex!("51aceca4fa95854bbaba58d8a5e17a86c07acadef32e1188cafda26232131800002cc2f27c7aec454e5e0c615bddffb7df6a5f7f0f14793f"), hex!("3a6f36db914d74f72117e929f710dcc15a2891cfa23ba788b5e1e4267fc78198cbde61dfe71b55af5b6b79231f4578bff9a25c81930aded3")),
+            (b"abcdef0123456789", hex!("c6d65987f146b8d0cb5d2c44e1872ac3af1f458f6a8bd8c232ffe8b9d09496229a5a27f350eb7d97305bcc4e0f38328718352e8e3129ed71"), hex!("b2d06fe40ccc023beca46ab0df2a6df816095b1307731a50ee376d4ab08689913b133609f299646a3576bf0c64e48fbbebf53dfbf3e409a6")),
+            (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", hex!("9b8d008863beb4a02fb9e4efefd2eba867307fb1c7ce01746115d32e1db551bb254e8e3e4532d5c74a83949a69a60519ecc9178083cbe943"), hex!("cb95e035bab2e19839d7bc83d8f13f0f3bda94488b0193f1b6218ffa00926e661d32662708a8a5695503b23724e7ee45f5bbce8a7e0bec8e")),
+            (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", hex!("8746dc34799112d1f20acda9d7f722c9abb29b1fb6b7e9e566983843c20bd7c9bfad21b45c5166b808d2f5d44e188f1fdaf29cdee8a72e4c"), hex!("83ed6cb7b36d783d675e5f9ff39bcb81117acf530a9c3278fa1fa8d6d8b27327efe7ca07ffc490c4874a414d70a41e775c28431e13a5c94e")),
+        ];
+
+        for (msg, x, y) in MSGS {
+            let p = MontgomeryPointFull::encode::<ExpandMsgXof<sha3::Shake256>>(msg, DST);
+            assert_eq!(p.u().to_be_bytes(), *x);
+            let mut v = p.v();
+            v.reverse();
+            assert_eq!(v, *y);
+            assert_eq!(
+                MontgomeryPoint::encode::<ExpandMsgXof<sha3::Shake256>>(msg, DST),
+                p.u()
+            );
+        }
+
+        // The defaults are the same suites, and the points are in the prime-order subgroup
+        let p = MontgomeryPoint::hash_with_defaults(b"abc");
+        assert_eq!(p.to_edwards(0).map(|e| e.to_montgomery()), Some(p));
+        let p = MontgomeryPoint::encode_with_defaults(b"abc");
+        assert_eq!(p.to_edwards(0).map(|e| e.to_montgomery()), Some(p));
+    }
+
     #[test]
     fn test_montgomery_edwards() {
         let scalar = Scalar::from(200u32);
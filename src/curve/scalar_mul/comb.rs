@@ -0,0 +1,167 @@
+#![allow(non_snake_case)]
+
+use crate::curve::twedwards::{
+    extended::ExtendedPoint, extensible::ExtensiblePoint, projective::ProjectiveNielsPoint,
+};
+use crate::field::Scalar;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The number of bits of a reduced scalar
+const SCALAR_BITS: usize = 446;
+
+/// A Lim-Lee comb for multiplying a fixed point `P`, with signed teeth as in libdecaf.
+///
+/// The bits of the scalar are split into `combs` blocks of `teeth * spacing` bits, and
+/// tooth `k` of comb `j` reads bit `(j * teeth + k) * spacing + i` in round `i`.
+/// With every bit read as a digit `±1` a comb needs `2^(teeth - 1)` entries,
+/// the others are their negations.
+pub(crate) struct CombTable {
+    point: ExtendedPoint,
+    teeth: usize,
+    spacing: usize,
+    combs: usize,
+    /// `2^(combs * teeth * spacing) - 1`, which the signed digits subtract
+    adjustment: Scalar,
+    /// `2^(teeth - 1)` entries per comb
+    table: Vec<ProjectiveNielsPoint>,
+}
+
+impl CombTable {
+    /// Builds the table, with the number of combs needed to cover a scalar.
+    pub(crate) fn new(point: &ExtendedPoint, teeth: usize, spacing: usize) -> Self {
+        assert!((1..=16).contains(&teeth), "between 1 and 16 teeth");
+        assert!(spacing > 0, "spacing must be positive");
+        let combs = SCALAR_BITS.div_ceil(teeth * spacing);
+
+        let mut adjustment = Scalar::ONE;
+        for _ in 0..combs * teeth * spacing {
+            adjustment += adjustment;
+        }
+        adjustment -= Scalar::ONE;
+
+        // The tooth points 2^((j * teeth + k) * spacing) P
+        let mut P = *point;
+        let teeth_points = (0..combs * teeth)
+            .map(|_| {
+                let tooth = P;
+                for _ in 0..spacing {
+                    P = P.double();
+                }
+                tooth
+            })
+            .collect::<Vec<_>>();
+
+        let half = 1 << (teeth - 1);
+        let mut table = Vec::with_capacity(combs * half);
+        for comb in teeth_points.chunks(teeth) {
+            // Entry m has digit +1 for the teeth whose bit is set in m, -1 for the others,
+            // and +1 for the top tooth. Setting a bit of m adds twice its tooth.
+            let mut entries = vec![comb[teeth - 1]; half];
+            for tooth in &comb[..teeth - 1] {
+                entries[0] = entries[0].add(&tooth.negate());
+            }
+            for m in 1..half {
+                let low = m.trailing_zeros() as usize;
+                entries[m] = entries[m & (m - 1)].add(&comb[low].double());
+            }
+            table.extend(
+                entries
+                    .iter()
+                    .map(|entry| entry.to_extensible().to_projective_niels()),
+            );
+        }
+
+        CombTable {
+            point: *point,
+            teeth,
+            spacing,
+            combs,
+            adjustment,
+            table,
+        }
+    }
+
+    /// The point the table was built from
+    pub(crate) fn basepoint(&self) -> ExtendedPoint {
+        self.point
+    }
+
+    /// Computes `scalar * P` in constant time
+    pub(crate) fn mul(&self, s: &Scalar) -> ExtendedPoint {
+        // Read every bit b as the digit 2b - 1, which sums to 2s' - adjustment
+        #[allow(unused_mut)]
+        let mut scalar = (*s + self.adjustment).halve().to_bytes();
+        let bit = |i: usize| -> u32 {
+            if i < 8 * scalar.len() {
+                ((scalar[i / 8] >> (i % 8)) & 1) as u32
+            } else {
+                0
+            }
+        };
+
+        let half = 1 << (self.teeth - 1);
+        let mut result = ExtensiblePoint::IDENTITY;
+        for i in (0..self.spacing).rev() {
+            if i + 1 != self.spacing {
+                result = result.double();
+            }
+            for j in 0..self.combs {
+                let mut index = 0u32;
+                for k in 0..self.teeth {
+                    index |= bit((j * self.teeth + k) * self.spacing + i) << k;
+                }
+
+                // A clear top tooth negates all digits of the entry
+                let invert = (index >> (self.teeth - 1)) ^ 1;
+                let index = (index ^ invert.wrapping_neg()) & (half as u32 - 1);
+
+                let mut entry = self.select(j, index);
+                entry.conditional_negate(Choice::from(invert as u8));
+                result = result.add_projective_niels(&entry);
+
+                #[cfg(feature = "zeroize")]
+                entry.zeroize();
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        scalar.zeroize();
+
+        result.to_extended()
+    }
+
+    /// Selects entry `index` of comb `j` in constant time
+    fn select(&self, j: usize, index: u32) -> ProjectiveNielsPoint {
+        let half = 1 << (self.teeth - 1);
+        let mut result = ProjectiveNielsPoint::identity();
+        for (m, entry) in self.table[j * half..(j + 1) * half].iter().enumerate() {
+            result.conditional_assign(entry, index.ct_eq(&(m as u32)));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base;
+    use crate::TWISTED_EDWARDS_BASE_POINT;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_comb() {
+        let B = TWISTED_EDWARDS_BASE_POINT;
+        let s = Scalar::random(&mut OsRng);
+        let expected = variable_base(&B, &s);
+        // libdecaf's shape, a single tooth, and one comb covering more than a scalar
+        for (teeth, spacing) in [(5, 18), (1, 7), (8, 60)] {
+            let table = CombTable::new(&B, teeth, spacing);
+            assert_eq!(table.mul(&s), expected);
+            assert_eq!(table.mul(&Scalar::ZERO), ExtendedPoint::IDENTITY);
+            assert_eq!(table.mul(&-Scalar::ONE), B.negate());
+            assert_eq!(table.basepoint(), B);
+        }
+    }
+}
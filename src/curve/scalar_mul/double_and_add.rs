@@ -6,15 +6,21 @@ use subtle::{Choice, ConditionallySelectable};
 pub(crate) fn double_and_add(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
     let mut result = ExtendedPoint::IDENTITY;
 
+    #[allow(unused_mut)]
+    let mut bits = s.bits();
+
     // NB, we reverse here, so we are going from MSB to LSB
     // XXX: Would be great if subtle had a From<u32> for Choice. But maybe that is not it's purpose?
-    for bit in s.bits().into_iter().rev() {
+    for bit in bits.iter().rev() {
         result = result.double();
 
         let mut p = ExtendedPoint::IDENTITY;
-        p.conditional_assign(point, Choice::from(bit as u8));
+        p.conditional_assign(point, Choice::from(*bit as u8));
         result = result.add(&p);
     }
 
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut bits);
+
     result
 }
@@ -1,13 +1,154 @@
 #![allow(non_snake_case)]
 
-use super::double_and_add;
-use crate::curve::twedwards::extended::ExtendedPoint;
+use super::window::wnaf::{NafLookupTable, NafLookupTable5, NafLookupTable8};
+use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
 use crate::field::Scalar;
-/// XXX: Really in-efficient way to do double base scala mul
-/// Replace it with endomorphism from pornin or use naf form
-/// Computes aA + bB where B is the TwistedEdwards basepoint
-pub(crate) fn double_base_scalar_mul(a: &Scalar, A: &ExtendedPoint, b: &Scalar) -> ExtendedPoint {
-    let part_a = double_and_add(A, a);
-    let part_b = double_and_add(&ExtendedPoint::GENERATOR, b);
-    part_a.add(&part_b)
+use std::sync::OnceLock;
+
+/// The width-8 NAF table of the twisted basepoint, built on first use
+pub(crate) fn basepoint_naf_table() -> &'static NafLookupTable8 {
+    static TABLE: OnceLock<NafLookupTable8> = OnceLock::new();
+    TABLE.get_or_init(|| NafLookupTable8::from(&crate::TWISTED_EDWARDS_BASE_POINT))
+}
+
+/// Computes aA + bB in variable time, where both points are given by their NAF lookup tables.
+/// Only use this when a, b and the points are public, as in signature verification.
+pub(crate) fn vartime_double_base<const N: usize, const M: usize>(
+    a: &Scalar,
+    A: &NafLookupTable<N>,
+    b: &Scalar,
+    B: &NafLookupTable<M>,
+) -> ExtendedPoint {
+    let a_naf = a.non_adjacent_form(NafLookupTable::<N>::WIDTH);
+    let b_naf = b.non_adjacent_form(NafLookupTable::<M>::WIDTH);
+
+    // Skip the leading zero digits
+    let top = match (0..448).rev().find(|&i| a_naf[i] != 0 || b_naf[i] != 0) {
+        Some(i) => i,
+        None => return ExtendedPoint::IDENTITY,
+    };
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..=top).rev() {
+        result = result.double();
+
+        if a_naf[i] > 0 {
+            result = result.add_projective_niels(A.select(a_naf[i] as usize));
+        } else if a_naf[i] < 0 {
+            result = result.sub_projective_niels(A.select(a_naf[i].unsigned_abs() as usize));
+        }
+
+        if b_naf[i] > 0 {
+            result = result.add_projective_niels(B.select(b_naf[i] as usize));
+        } else if b_naf[i] < 0 {
+            result = result.sub_projective_niels(B.select(b_naf[i].unsigned_abs() as usize));
+        }
+    }
+
+    result.to_extended()
+}
+
+/// Computes the sum of a_i A_i plus the sum of b_j B_j in variable time with interleaved NAFs,
+/// where the A_i have width-5 tables and the B_j precomputed width-8 tables, such as the
+/// [`basepoint_naf_table`]. As with [`vartime_double_base`], every input must be public.
+pub(crate) fn vartime_straus(
+    terms: &[(Scalar, NafLookupTable5)],
+    static_terms: &[(Scalar, &NafLookupTable8)],
+) -> ExtendedPoint {
+    let nafs = terms
+        .iter()
+        .map(|(a, _)| a.non_adjacent_form(NafLookupTable5::WIDTH))
+        .collect::<Vec<_>>();
+    let static_nafs = static_terms
+        .iter()
+        .map(|(b, _)| b.non_adjacent_form(NafLookupTable8::WIDTH))
+        .collect::<Vec<_>>();
+
+    // Skip the leading zero digits
+    let nonzero = |i: usize| nafs.iter().chain(static_nafs.iter()).any(|naf| naf[i] != 0);
+    let top = match (0..448).rev().find(|&i| nonzero(i)) {
+        Some(i) => i,
+        None => return ExtendedPoint::IDENTITY,
+    };
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..=top).rev() {
+        result = result.double();
+
+        for (naf, (_, A)) in nafs.iter().zip(terms.iter()) {
+            if naf[i] > 0 {
+                result = result.add_projective_niels(A.select(naf[i] as usize));
+            } else if naf[i] < 0 {
+                result = result.sub_projective_niels(A.select(naf[i].unsigned_abs() as usize));
+            }
+        }
+
+        for (naf, (_, B)) in static_nafs.iter().zip(static_terms.iter()) {
+            if naf[i] > 0 {
+                result = result.add_projective_niels(B.select(naf[i] as usize));
+            } else if naf[i] < 0 {
+                result = result.sub_projective_niels(B.select(naf[i].unsigned_abs() as usize));
+            }
+        }
+    }
+
+    result.to_extended()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::{variable_base, window::wnaf::NafLookupTable5};
+    use crate::TWISTED_EDWARDS_BASE_POINT;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_vartime_double_base() {
+        let B = TWISTED_EDWARDS_BASE_POINT;
+        let A = variable_base(&B, &Scalar::from(1234567u32));
+        let A_table = NafLookupTable5::from(&A);
+
+        for _ in 0..8 {
+            let a = Scalar::random(&mut OsRng);
+            let b = Scalar::random(&mut OsRng);
+            let expected = variable_base(&A, &a).add(&variable_base(&B, &b));
+            let got = vartime_double_base(&a, &A_table, &b, basepoint_naf_table());
+            assert_eq!(got, expected);
+        }
+
+        let got = vartime_double_base(&Scalar::ZERO, &A_table, &Scalar::ZERO, &A_table);
+        assert_eq!(got, ExtendedPoint::IDENTITY);
+        let got = vartime_double_base(&-Scalar::ONE, &A_table, &Scalar::ONE, basepoint_naf_table());
+        assert_eq!(got, B.add(&A.negate()));
+    }
+
+    #[test]
+    fn test_vartime_straus() {
+        let B = TWISTED_EDWARDS_BASE_POINT;
+        let points = (1..4u32)
+            .map(|i| variable_base(&B, &Scalar::from(i * 1000 + 7)))
+            .collect::<Vec<_>>();
+        let scalars = (0..3)
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let b = Scalar::random(&mut OsRng);
+
+        let terms = scalars
+            .iter()
+            .zip(points.iter())
+            .map(|(a, A)| (*a, NafLookupTable5::from(A)))
+            .collect::<Vec<_>>();
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(ExtendedPoint::IDENTITY, |acc, (a, A)| {
+                acc.add(&variable_base(A, a))
+            });
+        assert_eq!(vartime_straus(&terms, &[]), expected);
+        assert_eq!(
+            vartime_straus(&terms, &[(b, basepoint_naf_table())]),
+            expected.add(&variable_base(&B, &b))
+        );
+        assert_eq!(vartime_straus(&[], &[]), ExtendedPoint::IDENTITY);
+    }
 }
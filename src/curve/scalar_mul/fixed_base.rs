@@ -0,0 +1,99 @@
+#![allow(non_snake_case)]
+
+use super::window::wnaf::LookupTable;
+use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
+use crate::field::Scalar;
+use subtle::{Choice, ConditionallyNegatable};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The multiples `[P, 2P, ..., 8P]` of `16^(2i) P` for every even radix-16 digit position `2i`.
+///
+/// A scalar multiplication of `P` then costs one table lookup and addition per digit and
+/// only four doublings, instead of four doublings per digit.
+pub(crate) struct BasepointTable([LookupTable; 57]);
+
+impl From<&ExtendedPoint> for BasepointTable {
+    fn from(point: &ExtendedPoint) -> Self {
+        let mut P = *point;
+        BasepointTable(core::array::from_fn(|_| {
+            let table = LookupTable::from(&P);
+            for _ in 0..8 {
+                P = P.double();
+            }
+            table
+        }))
+    }
+}
+
+impl BasepointTable {
+    /// The point the table was built from
+    pub(crate) fn basepoint(&self) -> ExtendedPoint {
+        self.0[0].select(1).to_extended()
+    }
+
+    /// Computes `scalar * P` in constant time
+    pub(crate) fn mul(&self, s: &Scalar) -> ExtendedPoint {
+        #[allow(unused_mut)]
+        let mut scalar = s.to_radix_16();
+
+        // Sum the odd digits, scale them by 16 and then add the even digits
+        let mut result = ExtensiblePoint::IDENTITY;
+        for i in (1..113).step_by(2) {
+            result = self.add_digit(&result, i / 2, scalar[i]);
+        }
+        result = result.double();
+        result = result.double();
+        result = result.double();
+        result = result.double();
+        for i in (0..113).step_by(2) {
+            result = self.add_digit(&result, i / 2, scalar[i]);
+        }
+
+        #[cfg(feature = "zeroize")]
+        scalar.zeroize();
+
+        result.to_extended()
+    }
+
+    /// Adds `digit * 16^(2i) P` to `point`, for a digit in `[-8, 8]`
+    fn add_digit(&self, point: &ExtensiblePoint, i: usize, digit: i8) -> ExtensiblePoint {
+        // The mask is the top bit, will be 1 for negative numbers, 0 for positive numbers
+        let mask = digit >> 7;
+        let sign = mask & 0x1;
+        // Use the mask to get the absolute value of the digit
+        let abs_value = ((digit + mask) ^ mask) as u32;
+
+        let mut neg_P = self.0[i].select(abs_value);
+        neg_P.conditional_negate(Choice::from(sign as u8));
+        let result = point.add_projective_niels(&neg_P);
+
+        #[cfg(feature = "zeroize")]
+        neg_P.zeroize();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base;
+    use crate::TWISTED_EDWARDS_BASE_POINT;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_basepoint_table() {
+        let B = TWISTED_EDWARDS_BASE_POINT;
+        let table = BasepointTable::from(&B);
+        assert_eq!(table.basepoint(), B);
+
+        for _ in 0..8 {
+            let s = Scalar::random(&mut OsRng);
+            assert_eq!(table.mul(&s), variable_base(&B, &s));
+        }
+        assert_eq!(table.mul(&Scalar::ZERO), ExtendedPoint::IDENTITY);
+        assert_eq!(table.mul(&Scalar::ONE), B);
+        assert_eq!(table.mul(&-Scalar::ONE), B.negate());
+    }
+}
@@ -1,7 +1,14 @@
+pub(crate) mod comb;
 pub(crate) mod double_and_add;
-// pub(crate) mod double_base;
+pub(crate) mod double_base;
+pub(crate) mod fixed_base;
+pub(crate) mod pippenger;
 pub(crate) mod variable_base;
 pub(crate) mod window;
 
+pub(crate) use comb::CombTable;
 pub(crate) use double_and_add::double_and_add;
-pub(crate) use variable_base::variable_base;
+pub(crate) use double_base::{basepoint_naf_table, vartime_double_base, vartime_straus};
+pub(crate) use fixed_base::BasepointTable;
+pub(crate) use pippenger::vartime_pippenger;
+pub(crate) use variable_base::{multiscalar_mul, variable_base};
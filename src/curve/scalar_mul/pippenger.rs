@@ -0,0 +1,96 @@
+#![allow(non_snake_case)]
+
+use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
+use crate::field::Scalar;
+
+/// Computes the sum of `scalars[i] * points[i]` in variable time with Pippenger's bucket method.
+///
+/// Each radix `2^w` digit position sorts the points into buckets by their digit, so the cost
+/// per point is one addition per digit rather than a table. This wins over Straus' method
+/// from about 500 points. Every input must be public.
+pub(crate) fn vartime_pippenger(scalars: &[Scalar], points: &[ExtendedPoint]) -> ExtendedPoint {
+    debug_assert_eq!(scalars.len(), points.len());
+
+    // Larger windows mean fewer digits but more buckets to sum up
+    let w = match points.len() {
+        0..=599 => 7,
+        600..=1999 => 8,
+        _ => 9,
+    };
+    let buckets_count = 1 << (w - 1);
+
+    let digits = scalars.iter().map(|s| s.as_radix_2w(w)).collect::<Vec<_>>();
+    let points = points
+        .iter()
+        .map(|p| p.to_extensible().to_projective_niels())
+        .collect::<Vec<_>>();
+    let digits_count = match digits.first() {
+        Some(digits) => digits.len(),
+        None => return ExtendedPoint::IDENTITY,
+    };
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..digits_count).rev() {
+        for _ in 0..w {
+            result = result.double();
+        }
+
+        let mut buckets = (0..buckets_count)
+            .map(|_| ExtensiblePoint::IDENTITY)
+            .collect::<Vec<_>>();
+        for (digits, P) in digits.iter().zip(points.iter()) {
+            let digit = digits[i];
+            if digit > 0 {
+                let b = (digit - 1) as usize;
+                buckets[b] = buckets[b].add_projective_niels(P);
+            } else if digit < 0 {
+                let b = (-digit - 1) as usize;
+                buckets[b] = buckets[b].sub_projective_niels(P);
+            }
+        }
+
+        // The sum of (b + 1) * buckets[b], as a sum of running sums from the top bucket down
+        let mut running_sum = ExtensiblePoint::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running_sum = running_sum.add_extensible(bucket);
+            result = result.add_extensible(&running_sum);
+        }
+    }
+
+    result.to_extended()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::{vartime_straus, window::wnaf::NafLookupTable5};
+    use crate::TWISTED_EDWARDS_BASE_POINT;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_vartime_pippenger() {
+        let B = TWISTED_EDWARDS_BASE_POINT;
+        for n in [1, 7, 200] {
+            let mut P = B;
+            let points = (0..n)
+                .map(|_| {
+                    P = P.double().add(&B);
+                    P
+                })
+                .collect::<Vec<_>>();
+            let scalars = (0..n)
+                .map(|_| Scalar::random(&mut OsRng))
+                .collect::<Vec<_>>();
+            let terms = scalars
+                .iter()
+                .zip(points.iter())
+                .map(|(a, A)| (*a, NafLookupTable5::from(A)))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                vartime_pippenger(&scalars, &points),
+                vartime_straus(&terms, &[])
+            );
+        }
+        assert_eq!(vartime_pippenger(&[], &[]), ExtendedPoint::IDENTITY);
+    }
+}
@@ -4,14 +4,18 @@ use super::window::wnaf::LookupTable;
 use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
 use crate::field::Scalar;
 use subtle::{Choice, ConditionallyNegatable};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 pub fn variable_base(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
     let mut result = ExtensiblePoint::IDENTITY;
 
     // Recode Scalar
-    let scalar = s.to_radix_16();
+    #[allow(unused_mut)]
+    let mut scalar = s.to_radix_16();
 
-    let lookup = LookupTable::from(point);
+    #[allow(unused_mut)]
+    let mut lookup = LookupTable::from(point);
 
     for i in (0..113).rev() {
         result = result.double();
@@ -29,6 +33,59 @@ pub fn variable_base(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
         neg_P.conditional_negate(Choice::from((sign) as u8));
 
         result = result.add_projective_niels(&neg_P);
+
+        #[cfg(feature = "zeroize")]
+        neg_P.zeroize();
+    }
+
+    // The digits and the table are derived from the secret scalar
+    #[cfg(feature = "zeroize")]
+    {
+        scalar.zeroize();
+        lookup.zeroize();
+    }
+
+    result.to_extended()
+}
+
+/// Computes the sum of `scalars[i] * points[i]` in constant time, with interleaved
+/// radix-16 windows (Straus' method) so that the doublings are shared between the terms.
+pub fn multiscalar_mul(scalars: &[Scalar], points: &[ExtendedPoint]) -> ExtendedPoint {
+    debug_assert_eq!(scalars.len(), points.len());
+    let mut result = ExtensiblePoint::IDENTITY;
+
+    #[allow(unused_mut)]
+    let mut digits = scalars.iter().map(|s| s.to_radix_16()).collect::<Vec<_>>();
+    #[allow(unused_mut)]
+    let mut lookups = points.iter().map(LookupTable::from).collect::<Vec<_>>();
+
+    for i in (0..113).rev() {
+        result = result.double();
+        result = result.double();
+        result = result.double();
+        result = result.double();
+
+        for (scalar, lookup) in digits.iter().zip(lookups.iter()) {
+            // The mask is the top bit, will be 1 for negative numbers, 0 for positive numbers
+            let mask = scalar[i] >> 7;
+            let sign = mask & 0x1;
+            // Use the mask to get the absolute value of scalar
+            let abs_value = ((scalar[i] + mask) ^ mask) as u32;
+
+            let mut neg_P = lookup.select(abs_value);
+            neg_P.conditional_negate(Choice::from((sign) as u8));
+
+            result = result.add_projective_niels(&neg_P);
+
+            #[cfg(feature = "zeroize")]
+            neg_P.zeroize();
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    {
+        digits.zeroize();
+        lookups.zeroize();
     }
 
     result.to_extended()
@@ -39,15 +96,15 @@ mod test {
     use super::*;
     use crate::curve::scalar_mul::double_and_add;
     use crate::TWISTED_EDWARDS_BASE_POINT;
+    use elliptic_curve::bigint::U448;
 
     #[test]
     fn test_scalar_mul() {
         // XXX: In the future use known multiples from Sage in bytes form?
         let twisted_point = TWISTED_EDWARDS_BASE_POINT;
-        let scalar = Scalar([
-            0x6ee372b7, 0xe128ae78, 0x1533427c, 0xad0b7015, 0x307f665e, 0xde8026c1, 0xb64629d1,
-            0xab454c66, 0x3fe5bf1a, 0x083f8304, 0x3c003777, 0xdef437f6, 0xee2e1b73, 0x05ca185a,
-        ]);
+        let scalar = Scalar(U448::from_be_hex(
+        "05ca185aee2e1b73def437f63c003777083f83043fe5bf1aab454c66b64629d1de8026c1307f665ead0b70151533427ce128ae786ee372b7",
+    ));
 
         let got = variable_base(&twisted_point, &scalar);
 
@@ -74,4 +131,24 @@ mod test {
         let got = variable_base(&x, &Scalar::from(4u8));
         assert!(expected_two_x.to_extended() == got);
     }
+
+    #[test]
+    fn test_multiscalar_mul() {
+        let B = TWISTED_EDWARDS_BASE_POINT;
+        let points = (1..5u32)
+            .map(|i| variable_base(&B, &Scalar::from(i * 1000 + 7)))
+            .collect::<Vec<_>>();
+        let scalars = (0..4)
+            .map(|_| Scalar::random(&mut rand_core::OsRng))
+            .collect::<Vec<_>>();
+
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(ExtendedPoint::IDENTITY, |acc, (s, P)| {
+                acc.add(&variable_base(P, s))
+            });
+        assert_eq!(multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(multiscalar_mul(&[], &[]), ExtendedPoint::IDENTITY);
+    }
 }
@@ -21,6 +21,13 @@ impl From<&ExtendedPoint> for LookupTable {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for LookupTable {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl LookupTable {
     /// Selects a projective niels point from a lookup table in constant time
     pub fn select(&self, index: u32) -> ProjectiveNielsPoint {
@@ -34,6 +41,46 @@ impl LookupTable {
     }
 }
 
+/// Odd multiples `[P, 3P, 5P, ..., (2N-1)P]` of a point, for use with the
+/// width-`WIDTH` non-adjacent form of a public scalar.
+///
+/// Lookups are done in variable time.
+#[derive(Clone)]
+pub struct NafLookupTable<const N: usize>([ProjectiveNielsPoint; N]);
+
+/// A table for width-5 NAF digits, cheap enough to build per multiplication
+pub type NafLookupTable5 = NafLookupTable<8>;
+/// A table for width-8 NAF digits, for points that are used many times
+pub type NafLookupTable8 = NafLookupTable<64>;
+
+impl<const N: usize> NafLookupTable<N> {
+    /// The NAF width this table covers
+    pub const WIDTH: usize = N.trailing_zeros() as usize + 2;
+
+    /// Returns `x * P` for odd `0 < x < 2N`
+    pub fn select(&self, x: usize) -> &ProjectiveNielsPoint {
+        debug_assert_eq!(x & 1, 1);
+        debug_assert!(x < 2 * N);
+        &self.0[x / 2]
+    }
+}
+
+impl<const N: usize> From<&ExtendedPoint> for NafLookupTable<N> {
+    fn from(point: &ExtendedPoint) -> Self {
+        let P = point.to_extensible();
+        let P2 = P.double().to_projective_niels();
+
+        let mut table = [P.to_projective_niels(); N];
+        let mut multiple = P;
+        for entry in table.iter_mut().skip(1) {
+            multiple = multiple.add_projective_niels(&P2);
+            *entry = multiple.to_projective_niels();
+        }
+
+        NafLookupTable(table)
+    }
+}
+
 // XXX: Add back tests to ensure that select works correctly
 
 #[test]
@@ -52,3 +99,18 @@ fn test_lookup() {
             .to_extended();
     }
 }
+
+#[test]
+fn test_naf_lookup() {
+    let p = ExtendedPoint::GENERATOR;
+    let table = NafLookupTable5::from(&p);
+    assert_eq!(NafLookupTable5::WIDTH, 5);
+    assert_eq!(NafLookupTable8::WIDTH, 8);
+
+    let p2 = p.double();
+    let mut expected_point = p;
+    for i in (1..16).step_by(2) {
+        assert_eq!(table.select(i).to_extended(), expected_point);
+        expected_point = expected_point.add(&p2);
+    }
+}
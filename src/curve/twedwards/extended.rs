@@ -2,10 +2,11 @@
 #![allow(dead_code)]
 
 use crate::curve::edwards::EdwardsPoint as EdwardsExtendedPoint;
+use crate::curve::model::{sealed::ExtendedCoordinates, CurveModel};
 use crate::curve::twedwards::affine::AffinePoint;
 use crate::curve::twedwards::extensible::ExtensiblePoint;
 use crate::field::FieldElement;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 
 #[derive(Copy, Clone, Debug)]
 pub struct ExtendedPoint {
@@ -38,6 +39,13 @@ impl ConditionallySelectable for ExtendedPoint {
     }
 }
 
+impl ConditionallyNegatable for ExtendedPoint {
+    fn conditional_negate(&mut self, choice: Choice) {
+        self.X.conditional_negate(choice);
+        self.T.conditional_negate(choice);
+    }
+}
+
 impl PartialEq for ExtendedPoint {
     fn eq(&self, other: &ExtendedPoint) -> bool {
         self.ct_eq(other).into()
@@ -51,6 +59,23 @@ impl Default for ExtendedPoint {
     }
 }
 
+impl CurveModel for ExtendedPoint {
+    const A_IS_MINUS_ONE: bool = true;
+    const D: FieldElement = FieldElement::TWISTED_D;
+
+    type Isogenous = EdwardsExtendedPoint;
+}
+
+impl ExtendedCoordinates for ExtendedPoint {
+    fn from_extended(X: FieldElement, Y: FieldElement, Z: FieldElement, T: FieldElement) -> Self {
+        Self { X, Y, Z, T }
+    }
+
+    fn extended(&self) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
+        (self.X, self.Y, self.Z, self.T)
+    }
+}
+
 impl ExtendedPoint {
     /// Identity point
     pub const IDENTITY: ExtendedPoint = ExtendedPoint {
@@ -70,12 +95,12 @@ impl ExtendedPoint {
 
     /// Doubles an extended point
     pub(crate) fn double(&self) -> ExtendedPoint {
-        self.to_extensible().double().to_extended()
+        self.model_double()
     }
 
     /// Adds an extended point to itself
     pub(crate) fn add(&self, other: &ExtendedPoint) -> ExtendedPoint {
-        self.to_extensible().add_extended(other).to_extended()
+        self.model_add(other)
     }
 
     /// Converts an ExtendedPoint to an ExtensiblePoint
@@ -103,53 +128,14 @@ impl ExtendedPoint {
         AffinePoint { x, y }
     }
 
-    /// Edwards_Isogeny is derived from the doubling formula
-    /// XXX: There is a duplicate method in the twisted edwards module to compute the dual isogeny
-    /// XXX: Not much point trying to make it generic I think. So what we can do is optimise each respective isogeny method for a=1 or a = -1 (currently, I just made it really slow and simple)
-    fn edwards_isogeny(&self, a: FieldElement) -> EdwardsExtendedPoint {
-        // Convert to affine now, then derive extended version later
-        let affine = self.to_affine();
-        let x = affine.x;
-        let y = affine.y;
-
-        // Compute x
-        let xy = x * y;
-        let x_numerator = xy + xy;
-        let x_denom = y.square() - (a * x.square());
-        let new_x = x_numerator * x_denom.invert();
-
-        // Compute y
-        let y_numerator = y.square() + (a * x.square());
-        let y_denom = (FieldElement::ONE + FieldElement::ONE) - y.square() - (a * x.square());
-        let new_y = y_numerator * y_denom.invert();
-
-        EdwardsExtendedPoint {
-            X: new_x,
-            Y: new_y,
-            Z: FieldElement::ONE,
-            T: new_x * new_y,
-        }
-    }
     /// Uses a 2-isogeny to map the point to the Ed448-Goldilocks
     pub fn to_untwisted(&self) -> EdwardsExtendedPoint {
-        self.edwards_isogeny(FieldElement::MINUS_ONE)
+        self.isogeny()
     }
 
     /// Checks if the point is on the curve
     pub(crate) fn is_on_curve(&self) -> Choice {
-        let XY = self.X * self.Y;
-        let ZT = self.Z * self.T;
-
-        // Y^2 - X^2 == Z^2 + T^2 * (TWISTED_D)
-
-        let YY = self.Y.square();
-        let XX = self.X.square();
-        let ZZ = self.Z.square();
-        let TT = self.T.square();
-        let lhs = YY - XX;
-        let rhs = ZZ + TT * FieldElement::TWISTED_D;
-
-        XY.ct_eq(&ZT) & lhs.ct_eq(&rhs)
+        self.model_is_on_curve()
     }
     /// Negates a point
     pub fn negate(&self) -> ExtendedPoint {
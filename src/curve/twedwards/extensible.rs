@@ -153,6 +153,27 @@ impl ExtensiblePoint {
             T2: D,
         }
     }
+    /// Subtracts a ProjectiveNiels point from an extensible point
+    /// Returns an extensible point
+    /// This is add_projective_niels with the negation of `other` folded in
+    pub fn sub_projective_niels(&self, other: &ProjectiveNielsPoint) -> ExtensiblePoint {
+        let Z = self.Z * other.Z;
+
+        let A = (self.Y - self.X) * other.Y_plus_X;
+        let B = (self.Y + self.X) * other.Y_minus_X;
+        let C = other.Td * self.T1 * self.T2;
+        let D = B + A;
+        let E = B - A;
+        let F = Z + C;
+        let G = Z - C;
+        ExtensiblePoint {
+            X: E * F,
+            Y: G * D,
+            Z: F * G,
+            T1: E,
+            T2: D,
+        }
+    }
     /// Converts an extensible point to an extended point
     pub fn to_extended(&self) -> ExtendedPoint {
         ExtendedPoint {
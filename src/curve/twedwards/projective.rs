@@ -37,6 +37,16 @@ impl ConditionallySelectable for ProjectiveNielsPoint {
         }
     }
 }
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ProjectiveNielsPoint {
+    fn zeroize(&mut self) {
+        self.Y_plus_X.zeroize();
+        self.Y_minus_X.zeroize();
+        self.Td.zeroize();
+        self.Z.zeroize();
+    }
+}
+
 impl ConditionallyNegatable for ProjectiveNielsPoint {
     fn conditional_negate(&mut self, choice: Choice) {
         FieldElement::conditional_swap(&mut self.Y_minus_X, &mut self.Y_plus_X, choice);
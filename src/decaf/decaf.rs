@@ -1,14 +1,27 @@
 #![allow(non_snake_case)]
 
 use crate::constants::DECAF_BASEPOINT;
+use crate::curve::scalar_mul::{
+    basepoint_naf_table, multiscalar_mul, vartime_double_base, window::wnaf::NafLookupTable5,
+};
 use crate::curve::twedwards::extended::ExtendedPoint;
-use crate::field::FieldElement;
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use crate::decaf::DecafBasepointTable;
+use crate::field::{FieldElement, Scalar};
+use elliptic_curve::{
+    generic_array::typenum::U56,
+    group::{prime::PrimeGroup, Group, GroupEncoding},
+    hash2curve::{ExpandMsg, ExpandMsgXof, Expander},
+};
+use rand_core::RngCore;
+use std::fmt::{Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// The bytes representation of a compressed point
 pub type DecafPointBytes = [u8; 56];
 
+/// The default hash to group domain separation tag
+pub const DEFAULT_HASH_TO_CURVE_SUITE: &[u8] = b"decaf448_XOF:SHAKE256_D448MAP_RO_";
+
 #[derive(Copy, Clone, Debug)]
 pub struct DecafPoint(pub(crate) ExtendedPoint);
 
@@ -47,6 +60,61 @@ impl PartialEq for DecafPoint {
 
 impl Eq for DecafPoint {}
 
+impl Default for DecafPoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Group for DecafPoint {
+    type Scalar = Scalar;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = [0u8; 112];
+        rng.fill_bytes(&mut bytes);
+        Self::from_uniform_bytes(&bytes)
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::IDENTITY)
+    }
+
+    fn double(&self) -> Self {
+        DecafPoint(self.0.double())
+    }
+}
+
+/// The decaf448 group has prime order, the cofactor is quotiented out by the encoding.
+impl PrimeGroup for DecafPoint {}
+
+/// The encoding is the 56-byte canonical decaf448 encoding.
+#[allow(deprecated)]
+impl GroupEncoding for DecafPoint {
+    type Repr = elliptic_curve::generic_array::GenericArray<u8, U56>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        let mut value = [0u8; 56];
+        value.copy_from_slice(bytes);
+        CompressedDecaf(value).decompress()
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        Self::Repr::from(self.compress().0)
+    }
+}
+
 impl From<DecafPoint> for DecafPointBytes {
     fn from(point: DecafPoint) -> DecafPointBytes {
         point.compress().0
@@ -139,16 +207,281 @@ impl DecafPoint {
         DecafPoint(self.0.to_extensible().sub_extended(&other.0).to_extended())
     }
 
+    /// Multiply the generator by `scalar`, using a precomputed table.
+    ///
+    /// This is several times faster than `DecafPoint::GENERATOR * scalar`.
+    pub fn mul_base(scalar: &Scalar) -> DecafPoint {
+        DecafBasepointTable::generator() * scalar
+    }
+
+    /// Compute the sum of `scalars[i] * points[i]` in constant time, so the scalars
+    /// may be secret, as in the openings of vector commitments.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    pub fn sum_of_products(scalars: &[Scalar], points: &[DecafPoint]) -> DecafPoint {
+        assert_eq!(scalars.len(), points.len());
+        let points = points.iter().map(|p| p.0).collect::<Vec<_>>();
+        DecafPoint(multiscalar_mul(scalars, &points))
+    }
+
+    /// Compute `aA + bB` in variable time, where `B` is the generator.
+    ///
+    /// This is the core of Schnorr-style signature verification. The running time
+    /// depends on the inputs, so only use it when `a`, `A` and `b` are public.
+    pub fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &DecafPoint,
+        b: &Scalar,
+    ) -> DecafPoint {
+        let A_table = NafLookupTable5::from(&A.0);
+        DecafPoint(vartime_double_base(a, &A_table, b, basepoint_naf_table()))
+    }
+
+    /// Map 112 uniformly random bytes, such as the output of a hash, to a point with the
+    /// one-way map of [RFC 9496 § 5.3.4].
+    ///
+    /// Each half of the bytes is mapped with Elligator and the two points are added,
+    /// so the result is indistinguishable from a uniformly random point.
+    ///
+    /// [RFC 9496 § 5.3.4]: https://www.rfc-editor.org/rfc/rfc9496#section-5.3.4
+    pub fn from_uniform_bytes(bytes: &[u8; 112]) -> DecafPoint {
+        let mut b0 = [0u8; 56];
+        let mut b1 = [0u8; 56];
+        b0.copy_from_slice(&bytes[..56]);
+        b1.copy_from_slice(&bytes[56..]);
+        Self::map(&b0) + Self::map(&b1)
+    }
+
+    /// Hash using the default domain separation tag and hash function
+    pub fn hash_with_defaults(msg: &[u8]) -> Self {
+        Self::hash::<ExpandMsgXof<sha3::Shake256>>(msg, DEFAULT_HASH_TO_CURVE_SUITE)
+    }
+
+    /// Implements hash to group for decaf448 according to
+    /// <https://datatracker.ietf.org/doc/rfc9380/>: expand `msg` to 112 bytes and
+    /// map them with [`DecafPoint::from_uniform_bytes`]
+    pub fn hash<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        let mut uniform_bytes = [0u8; 112];
+        let dst = [dst];
+        let mut expander = X::expand_message(&[msg], &dst, uniform_bytes.len()).unwrap();
+        expander.fill_bytes(&mut uniform_bytes);
+        Self::from_uniform_bytes(&uniform_bytes)
+    }
+
+    /// Find a representative of this point: 56 bytes that look uniformly random and that
+    /// [`DecafPoint::from_representative`] maps back to the point.
+    ///
+    /// Only some points have a representative, and the bytes are only uniform if they are
+    /// kept for every point on which this succeeds. To hide a public key, generate key
+    /// pairs until this returns `Some`, which takes eight tries on average.
+    pub fn to_representative(&self, mut rng: impl RngCore) -> CtOption<[u8; 56]> {
+        // Picking a slot rather than a preimage makes every t equally likely,
+        // no matter how many preimages its point has
+        let slot = rng.next_u32() % 32;
+        let mut representative = FieldElement::ZERO;
+        let mut found = Choice::from(0);
+        for (i, (t, is_preimage)) in self.map_preimages().iter().enumerate() {
+            let selected = slot.ct_eq(&(i as u32));
+            representative.conditional_assign(t, selected);
+            found.conditional_assign(is_preimage, selected);
+        }
+        CtOption::new(representative.to_bytes(), found)
+    }
+
+    /// Map a representative from [`DecafPoint::to_representative`] back to its point.
+    ///
+    /// This is the MAP function of [RFC 9496 § 5.3.4], so any 56 bytes give a point.
+    ///
+    /// [RFC 9496 § 5.3.4]: https://www.rfc-editor.org/rfc/rfc9496#section-5.3.4
+    pub fn from_representative(bytes: &[u8; 56]) -> DecafPoint {
+        Self::map(bytes)
+    }
+
+    /// The MAP function of RFC 9496 § 5.3.4.
+    pub(crate) fn map(bytes: &[u8; 56]) -> DecafPoint {
+        let t = FieldElement::from_bytes(bytes);
+        let (s, w, _) = Self::map_to_jacobi_quartic(&t);
+        Self::from_jacobi_quartic(&s, &w)
+    }
+
+    /// The first half of MAP, which computes a point `(s, w)` on the Jacobi quartic
+    /// `w^2 = s^4 + 2(1 - 2d)s^2 + 1`, and whether the square root was taken of a square
+    fn map_to_jacobi_quartic(t: &FieldElement) -> (FieldElement, FieldElement, Choice) {
+        let r = -t.square();
+        let u0 = FieldElement::EDWARDS_D * (r - FieldElement::ONE);
+        let u1 = (u0 + FieldElement::ONE) * (u0 - r);
+
+        let (v, was_square) = FieldElement::sqrt_ratio_i(
+            &FieldElement::ONE_MINUS_TWO_D,
+            &((r + FieldElement::ONE) * u1),
+        );
+        let v_prime = FieldElement::conditional_select(&(t * v), &v, was_square);
+        let sgn = FieldElement::conditional_select(
+            &FieldElement::MINUS_ONE,
+            &FieldElement::ONE,
+            was_square,
+        );
+        let s = v_prime * (r + FieldElement::ONE);
+        let w = v_prime * s * (r - FieldElement::ONE) * FieldElement::ONE_MINUS_TWO_D + sgn;
+        (s, w, was_square)
+    }
+
+    /// The second half of MAP.
+    ///
+    /// The RFC sends the Jacobi quartic point to edwards448, the same point is sent
+    /// here to the twisted curve.
+    fn from_jacobi_quartic(s: &FieldElement, w: &FieldElement) -> DecafPoint {
+        // (-2s / (1 - s^2), (1 + s^2) / w) on the twisted curve, where the RFC has
+        // (2s / (1 + s^2), (s^2 - 1) / w) on edwards448
+        let ss = s.square();
+        let w0 = -s.double();
+        let w1 = FieldElement::ONE - ss;
+        let w2 = FieldElement::ONE + ss;
+        let mut point = ExtendedPoint {
+            X: w0 * w,
+            Y: w2 * w1,
+            Z: w1 * w,
+            T: w0 * w2,
+        };
+        // s = ±1 are the points of order 4 on edwards448, which are the identity
+        point.conditional_assign(&ExtendedPoint::IDENTITY, w1.ct_eq(&FieldElement::ZERO));
+        DecafPoint(point)
+    }
+
+    /// Every field element `t` that MAP sends to this point, flagged by whether it is one.
+    ///
+    /// The point has four representatives on the twisted curve, each of which has two
+    /// preimages `(s, w)` and `(-1/s, w/s^2)` on the Jacobi quartic. Each of those is
+    /// reached from at most three `t`, which are found by solving MAP backwards.
+    /// The identity has no preimages here.
+    pub(crate) fn map_preimages(&self) -> [(FieldElement, Choice); 32] {
+        let (X, Y, Z) = (self.0.X, self.0.Y, self.0.Z);
+        // The other representatives are ±(1/(√d y), 1/(√d x))
+        let XY = FieldElement::SQRT_TWISTED_D * X * Y;
+        let representatives = [
+            (X, Y, Z),
+            (-X, -Y, Z),
+            (X * Z, Y * Z, XY),
+            (-(X * Z), -(Y * Z), XY),
+        ];
+        let is_identity = self.ct_eq(&Self::IDENTITY);
+
+        let mut preimages = [(FieldElement::ZERO, Choice::from(0)); 32];
+        for (i, (X, Y, Z)) in representatives.iter().enumerate() {
+            let Z_inv = Z.invert();
+            let (x, y) = (X * Z_inv, Y * Z_inv);
+            let (x_inv, y_inv) = (x.invert(), y.invert());
+
+            // x = -2s / (1 - s^2) has the roots s = (1 ± √(1 + x^2)) / x
+            let xx_plus_one = FieldElement::ONE + x.square();
+            let root = xx_plus_one.sqrt();
+            let root_exists = root.square().ct_eq(&xx_plus_one);
+
+            for (j, root) in [root, -root].iter().enumerate() {
+                let s = (FieldElement::ONE + root) * x_inv;
+                let ss = s.square();
+                let w = (FieldElement::ONE + ss) * y_inv;
+                let ss_inv = (ss * FieldElement::ONE_MINUS_TWO_D).invert();
+
+                for (k, sgn) in [FieldElement::ONE, FieldElement::MINUS_ONE]
+                    .iter()
+                    .enumerate()
+                {
+                    // MAP has (w - sgn) / s^2 = (1 - 2d)(r - 1) / (r + 1), and r = -t^2
+                    let q = (w - sgn) * ss_inv;
+                    let r = (FieldElement::ONE + q) * (FieldElement::ONE - q).invert();
+                    let t = (-r).sqrt();
+                    let t_exists = t.square().ct_eq(&-r);
+
+                    // -t is sent to the same point when was_square, and to (-s, w) otherwise
+                    let (s_t, w_t, was_square) = Self::map_to_jacobi_quartic(&t);
+                    let mut s_neg_t = s_t;
+                    s_neg_t.conditional_negate(!was_square);
+                    let found = !is_identity & root_exists & t_exists & w_t.ct_eq(&w);
+
+                    let index = 8 * i + 4 * j + 2 * k;
+                    preimages[index] = (t, found & s_t.ct_eq(&s));
+                    preimages[index + 1] = (
+                        -t,
+                        found & s_neg_t.ct_eq(&s) & !t.ct_eq(&FieldElement::ZERO),
+                    );
+                }
+            }
+        }
+        preimages
+    }
+
     /// Compress this point
     pub fn compress(&self) -> CompressedDecaf {
         let X = self.0.X;
-        // let Y = self.0.Y;
-        let Z = self.0.Z;
         let T = self.0.T;
 
         let XX_TT = (X + T) * (X - T);
 
         let (isr, _) = (X.square() * XX_TT * FieldElement::NEG_EDWARDS_D).inverse_square_root();
+        Self::compress_with_isr(&self.0, &isr)
+    }
+
+    /// Compress `[2]P` for every point `P` in `points`.
+    ///
+    /// The encoding of a point needs an inverse square root, but for a doubled point the
+    /// square root is a rational function of the point being doubled. This leaves a single
+    /// inversion shared by the whole batch, analogous to ristretto's `double_and_compress_batch`.
+    pub fn double_and_compress_batch(points: &[DecafPoint]) -> Vec<CompressedDecaf> {
+        let doubled = points
+            .iter()
+            .map(|point| {
+                let P = &point.0;
+                let XX = P.X.square();
+                let YY = P.Y.square();
+                let two_XY = (P.X * P.Y).double();
+                let G = YY - XX;
+                let F = G - P.Z.square().double();
+                let H = -(XX + YY);
+                let Q = ExtendedPoint {
+                    X: two_XY * F,
+                    Y: G * H,
+                    Z: F * G,
+                    T: two_XY * H,
+                };
+                // X_Q^2 (X_Q^2 - T_Q^2) (-d) is the square of this, by the curve equation
+                let root = Q.X * two_XY.square() * FieldElement::EDWARDS_D;
+                (Q, root)
+            })
+            .collect::<Vec<_>>();
+
+        // A zero root only occurs for X_Q = 0, which encodes to zero whatever the inverse
+        let mut isrs = doubled
+            .iter()
+            .map(|(_, root)| {
+                FieldElement::conditional_select(
+                    root,
+                    &FieldElement::ONE,
+                    root.ct_eq(&FieldElement::ZERO),
+                )
+            })
+            .collect::<Vec<_>>();
+        FieldElement::batch_invert(&mut isrs);
+
+        doubled
+            .iter()
+            .zip(isrs.iter())
+            .map(|((Q, _), isr)| Self::compress_with_isr(Q, isr))
+            .collect()
+    }
+
+    /// Compress `point`, given an inverse square root of `X^2 (X^2 - T^2) (-d)`
+    fn compress_with_isr(point: &ExtendedPoint, isr: &FieldElement) -> CompressedDecaf {
+        let X = point.X;
+        let Z = point.Z;
+        let T = point.T;
+
+        let XX_TT = (X + T) * (X - T);
         let mut ratio = isr * XX_TT;
         let altx = ratio * FieldElement::DECAF_FACTOR; // Sign choice
         ratio.conditional_negate(altx.is_negative());
@@ -163,8 +496,44 @@ impl DecafPoint {
 
 /// A compressed decaf point
 #[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
 pub struct CompressedDecaf(pub DecafPointBytes);
 
+define_byte_slice_casts!(TYPE = CompressedDecaf, LEN = 56);
+
+impl Display for CompressedDecaf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:x}", self)
+    }
+}
+
+impl LowerHex for CompressedDecaf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for b in &self.0[..] {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl UpperHex for CompressedDecaf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for b in &self.0[..] {
+            write!(f, "{:02X}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the hex printed by [`Display`], rejecting invalid and non-canonical encodings
+impl std::str::FromStr for CompressedDecaf {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(crate::encoding::hex_decode(s)?)
+    }
+}
+
 impl Default for CompressedDecaf {
     fn default() -> CompressedDecaf {
         Self::IDENTITY
@@ -387,6 +756,28 @@ impl CompressedDecaf {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for DecafPoint {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.compress().serialize(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DecafPoint {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let compressed = CompressedDecaf::deserialize(d)?;
+        Option::<DecafPoint>::from(compressed.decompress())
+            .ok_or_else(|| serde::de::Error::custom("invalid point"))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for DecafPoint {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -529,6 +920,181 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_from_uniform_bytes() {
+        use hex_literal::hex;
+
+        // RFC 9496 § B.3
+        let bytes = hex!("cbb8c991fd2f0b7e1913462d6463e4fd2ce4ccdd28274dc2ca1f4165d5ee6cdccea57be3416e166fd06718a31af45a2f8e987e301be59ae6673e963001dbbda80df47014a21a26d6c7eb4ebe0312aa6fffb8d1b26bc62ca40ed51f8057a635a02c2b8c83f48fa6a2d70f58a1185902c0");
+        let expected = hex!("0c709c9607dbb01c94513358745b7c23953d03b33e39c7234e268d1d6e24f34014ccbc2216b965dd231d5327e591dc3c0e8844ccfd568848");
+        let point = DecafPoint::from_uniform_bytes(&bytes);
+        assert_eq!(point.compress().0, expected);
+        assert_eq!(CompressedDecaf(expected).decompress().unwrap(), point);
+
+        // Both branches of the square root, and zero, which maps to the identity
+        assert_eq!(
+            DecafPoint::from_uniform_bytes(&[0u8; 112]),
+            DecafPoint::IDENTITY
+        );
+        for byte in [1u8, 2, 0xff] {
+            let point = DecafPoint::from_uniform_bytes(&[byte; 112]);
+            assert_eq!(point.compress().decompress().unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        use hex_literal::hex;
+
+        // RFC 9380 has no vectors for this suite, these were computed with an independent
+        // implementation of expand_message_xof and of the map, checked against RFC 9496 § B.3
+        const DST: &[u8] = b"QUUX-V01-CS02-with-decaf448_XOF:SHAKE256_D448MAP_RO_";
+        const MSGS: &[(&[u8], [u8; 56])] = &[
+            (b"", hex!("fc38a42c7c6f894d742301dd32db779502a132a3882161226df575909356b83df14fa80ca427d1042841a58a2eeab08a384cb81a862f9bd8")),
+            (b"abc", hex!("a0054d87debaa4b52aca1f9f4f9a77c189bca1424b6c3301c373cef56ff6983c4f1fc492883df10f576f172afdf01f2584bd5b41aee33da3")),
+            (b"abcdef0123456789", hex!("fce6f18a78fad7e787fe4bf388a39e55becc79c45134e6678f926af2ad894bc22839e2777a314d98a783a21b2b8f693c6863b20ca6573d98")),
+            (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", hex!("f6458cf8797f80ec9478e035f3f112bf0bb19a1112b95d95860e0599b9f6333878ec0ed05e0451f9f294f5d7fb56cc5510d65cc0d9a62081")),
+            (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", hex!("12e3f9d28e56262c45958a16dc5b32dab2e5966d99ef10b61666bfc66f3c51d09f4212eec01f1c5eb6d96f28f0dbc856ecfeeb143095993f")),
+        ];
+
+        for (msg, expected) in MSGS {
+            let point = DecafPoint::hash::<ExpandMsgXof<sha3::Shake256>>(msg, DST);
+            assert_eq!(point.compress().0, *expected);
+        }
+        assert_ne!(
+            DecafPoint::hash_with_defaults(b"abc"),
+            DecafPoint::hash::<ExpandMsgXof<sha3::Shake256>>(b"abc", DST)
+        );
+    }
+
+    #[test]
+    fn test_double_and_compress_batch() {
+        let mut rng = rand_core::OsRng;
+        let P = DecafPoint::random(&mut rng);
+        // (0, -1) has order 2 on the twisted curve, so it is the identity in decaf448
+        let two_torsion = DecafPoint(ExtendedPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        });
+        let points = [
+            DecafPoint::IDENTITY,
+            DecafPoint::GENERATOR,
+            P,
+            P + two_torsion,
+            two_torsion,
+            DecafPoint::random(&mut rng),
+        ];
+
+        let compressed = DecafPoint::double_and_compress_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        for (point, compressed) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*compressed, (point + point).compress());
+        }
+        assert_eq!(compressed[0], CompressedDecaf::IDENTITY);
+        assert!(DecafPoint::double_and_compress_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_vartime_double_scalar_mul_basepoint() {
+        let mut rng = rand_core::OsRng;
+        let A = DecafPoint::random(&mut rng);
+        for _ in 0..4 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            assert_eq!(
+                DecafPoint::vartime_double_scalar_mul_basepoint(&a, &A, &b),
+                A * a + DecafPoint::GENERATOR * b
+            );
+        }
+        assert_eq!(
+            DecafPoint::vartime_double_scalar_mul_basepoint(&Scalar::ZERO, &A, &Scalar::ZERO),
+            DecafPoint::IDENTITY
+        );
+        assert_eq!(
+            DecafPoint::vartime_double_scalar_mul_basepoint(&Scalar::ONE, &A, &-Scalar::ONE),
+            A - DecafPoint::GENERATOR
+        );
+    }
+
+    #[test]
+    fn test_representative() {
+        let mut rng = rand_core::OsRng;
+        let mut found = 0;
+        while found < 4 {
+            let point = DecafPoint::random(&mut rng);
+            let representative = point.to_representative(&mut rng);
+            if let Some(bytes) = Option::<[u8; 56]>::from(representative) {
+                assert_eq!(DecafPoint::from_representative(&bytes), point);
+                found += 1;
+            }
+        }
+        assert!(bool::from(
+            DecafPoint::IDENTITY.to_representative(&mut rng).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let compressed = (DecafPoint::GENERATOR * Scalar::from(5u8)).compress();
+        let hex = compressed.to_string();
+        assert_eq!(hex, format!("{:x}", compressed));
+        assert_eq!(hex.parse::<CompressedDecaf>().unwrap(), compressed);
+        assert_eq!(
+            format!("{:X}", compressed)
+                .parse::<CompressedDecaf>()
+                .unwrap(),
+            compressed
+        );
+        assert!(hex[2..].parse::<CompressedDecaf>().is_err());
+        assert!("ff".repeat(56).parse::<CompressedDecaf>().is_err());
+    }
+
+    #[test]
+    fn test_byte_slice_casts() {
+        let compressed = [
+            DecafPoint::GENERATOR.compress(),
+            DecafPoint::IDENTITY.compress(),
+        ];
+        let bytes = CompressedDecaf::slice_as_bytes(&compressed);
+        assert_eq!(bytes.len(), 2 * 56);
+        assert_eq!(
+            CompressedDecaf::slice_from_bytes(bytes).unwrap(),
+            &compressed[..]
+        );
+        assert!(CompressedDecaf::slice_from_bytes(&bytes[..55]).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let point = DecafPoint::GENERATOR * Scalar::from(1234567u32);
+
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, serde_json::to_string(&point.compress()).unwrap());
+        assert_eq!(serde_json::from_str::<DecafPoint>(&json).unwrap(), point);
+
+        let bare = serde_bare::to_vec(&point).unwrap();
+        assert_eq!(bare.len(), 56);
+        assert_eq!(serde_bare::from_slice::<DecafPoint>(&bare).unwrap(), point);
+
+        // Negative s is not a canonical encoding
+        let bytes = (-FieldElement::from_bytes(&point.compress().0)).to_bytes();
+        let json = serde_json::to_string(&hex::encode(bytes)).unwrap();
+        assert!(serde_json::from_str::<DecafPoint>(&json).is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut point = DecafPoint::GENERATOR;
+        point.zeroize();
+        assert_eq!(point, DecafPoint::IDENTITY);
+    }
+
     #[test]
     fn test_invalid_point() {
         // Test that the identity point is not on the curve
@@ -537,4 +1103,44 @@ mod test {
         let all_twos = CompressedDecaf([2u8; 56]);
         assert_eq!(all_twos.decompress().is_none().unwrap_u8(), 1u8);
     }
+
+    #[test]
+    fn test_group() {
+        fn encode_sum<G: PrimeGroup>(points: &[G], scalar: G::Scalar) -> G::Repr {
+            (points.iter().sum::<G>() * scalar).to_bytes()
+        }
+
+        let mut rng = rand_core::OsRng;
+        let P = DecafPoint::random(&mut rng);
+        let Q = DecafPoint::random(&mut rng);
+        assert_ne!(P, Q);
+        assert_eq!(Group::double(&P), P + P);
+        assert_eq!(DecafPoint::generator(), DECAF_BASEPOINT);
+        assert!(bool::from(DecafPoint::identity().is_identity()));
+        assert!(!bool::from(P.is_identity()));
+
+        let scalar = Scalar::from(5u8);
+        let bytes = encode_sum(&[P, Q], scalar);
+        assert_eq!(bytes[..], ((P + Q) * scalar).compress().0[..]);
+        assert_eq!(DecafPoint::from_bytes(&bytes).unwrap(), (P + Q) * scalar);
+
+        // Non-canonical encodings are rejected
+        let bytes = DecafPoint::identity().to_bytes();
+        assert_eq!(bytes[..], [0u8; 56][..]);
+        let mut bytes = P.to_bytes();
+        bytes[0] |= 1;
+        assert!(bool::from(DecafPoint::from_bytes(&bytes).is_none()));
+    }
+
+    #[test]
+    fn test_sum_of_products() {
+        let mut rng = rand_core::OsRng;
+        let points = [DecafPoint::random(&mut rng), DecafPoint::random(&mut rng)];
+        let scalars = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+        assert_eq!(
+            DecafPoint::sum_of_products(&scalars, &points),
+            points[0] * scalars[0] + points[1] * scalars[1]
+        );
+        assert_eq!(DecafPoint::sum_of_products(&[], &[]), DecafPoint::IDENTITY);
+    }
 }
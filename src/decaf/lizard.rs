@@ -0,0 +1,105 @@
+//! Lizard, an injective encoding of data into decaf448 points.
+//!
+//! The data is embedded in the middle of a hash of itself and the result is mapped to
+//! a point with Elligator. Decoding inverts Elligator and keeps the one preimage whose
+//! hash matches, so points that were not made by [`DecafPoint::from_lizard_bytes`]
+//! decode to `None` with overwhelming probability.
+//!
+//! The technique is the one of Bas Westerbaan's Lizard for ristretto255, with SHAKE256
+//! in place of SHA-256 and room for 32 bytes in a 56-byte field element.
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use super::DecafPoint;
+
+/// The number of bytes embedded in a point
+pub const LIZARD_DATA_LENGTH: usize = 32;
+
+impl DecafPoint {
+    /// Embed `data` into a point, which [`DecafPoint::lizard_decode`] recovers
+    pub fn from_lizard_bytes(data: &[u8; LIZARD_DATA_LENGTH]) -> DecafPoint {
+        DecafPoint::map(&lizard_representative(data))
+    }
+
+    /// Recover the data embedded with [`DecafPoint::from_lizard_bytes`], or `None` if
+    /// this point does not embed any
+    pub fn lizard_decode(&self) -> Option<[u8; LIZARD_DATA_LENGTH]> {
+        let mut result = [0u8; LIZARD_DATA_LENGTH];
+        let mut found = 0u8;
+        for (t, is_preimage) in self.map_preimages() {
+            let bytes = t.to_bytes();
+            let mut data = [0u8; LIZARD_DATA_LENGTH];
+            data.copy_from_slice(&bytes[12..44]);
+            let is_lizard = is_preimage & lizard_representative(&data)[..].ct_eq(&bytes[..]);
+            for (r, b) in result.iter_mut().zip(data.iter()) {
+                r.conditional_assign(b, is_lizard);
+            }
+            found += is_lizard.unwrap_u8();
+        }
+        (found == 1).then_some(result)
+    }
+}
+
+/// The field element encoding `data`: `SHAKE256(data)` with the data written over
+/// bytes 12 to 44, and the top bit cleared so that it is canonical
+fn lizard_representative(data: &[u8; LIZARD_DATA_LENGTH]) -> [u8; 56] {
+    let mut bytes = [0u8; 56];
+    Shake256::default()
+        .chain(data)
+        .finalize_xof()
+        .read(&mut bytes);
+    bytes[12..44].copy_from_slice(data);
+    bytes[55] &= 0x7f;
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::Group;
+    use rand_core::{OsRng, RngCore};
+
+    #[test]
+    fn round_trip() {
+        for _ in 0..8 {
+            let mut data = [0u8; LIZARD_DATA_LENGTH];
+            OsRng.fill_bytes(&mut data);
+            let point = DecafPoint::from_lizard_bytes(&data);
+            assert_eq!(point.lizard_decode(), Some(data));
+
+            // Survives the wire
+            let point = point.compress().decompress().unwrap();
+            assert_eq!(point.lizard_decode(), Some(data));
+        }
+        let data = [0u8; LIZARD_DATA_LENGTH];
+        assert_eq!(
+            DecafPoint::from_lizard_bytes(&data).lizard_decode(),
+            Some(data)
+        );
+    }
+
+    #[test]
+    fn other_points() {
+        assert_eq!(DecafPoint::IDENTITY.lizard_decode(), None);
+        assert_eq!(DecafPoint::GENERATOR.lizard_decode(), None);
+        assert_eq!(DecafPoint::random(&mut OsRng).lizard_decode(), None);
+    }
+
+    #[test]
+    fn preimages() {
+        for _ in 0..8 {
+            let point = DecafPoint::random(&mut OsRng);
+            let mut count = 0;
+            for (t, is_preimage) in point.map_preimages() {
+                if bool::from(is_preimage) {
+                    assert_eq!(DecafPoint::map(&t.to_bytes()), point);
+                    count += 1;
+                }
+            }
+            assert!(count <= 24);
+        }
+    }
+}
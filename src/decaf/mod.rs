@@ -2,5 +2,9 @@
 // This is the newer version of the Decaf strategy, which looks simpler
 
 pub mod decaf;
+mod lizard;
 mod ops;
+mod table;
 pub use decaf::{CompressedDecaf, DecafPoint};
+pub use lizard::LIZARD_DATA_LENGTH;
+pub use table::{DecafBasepointTable, DecafCombTable};
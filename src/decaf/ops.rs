@@ -34,14 +34,20 @@ impl Mul<Scalar> for DecafPoint {
         DecafPoint(double_and_add(&self.0, &scalar))
     }
 }
+impl Mul<&Scalar> for DecafPoint {
+    type Output = DecafPoint;
+    fn mul(self, scalar: &Scalar) -> DecafPoint {
+        DecafPoint(double_and_add(&self.0, scalar))
+    }
+}
 impl<'s> MulAssign<&'s Scalar> for DecafPoint {
     fn mul_assign(&mut self, scalar: &'s Scalar) {
-        *self = &*self * scalar;
+        *self = *self * scalar;
     }
 }
 impl MulAssign<Scalar> for DecafPoint {
     fn mul_assign(&mut self, scalar: Scalar) {
-        *self = &*self * &scalar;
+        *self = *self * scalar;
     }
 }
 
@@ -0,0 +1,123 @@
+use std::ops::Mul;
+use std::sync::OnceLock;
+
+use crate::{
+    curve::scalar_mul::{BasepointTable, CombTable},
+    Scalar,
+};
+
+use super::DecafPoint;
+
+/// A precomputed table of multiples of a point, for fast constant-time
+/// multiplication of that fixed point by many scalars.
+///
+/// Building the table costs about as much as one scalar multiplication, so it pays off
+/// for points such as the generator or a long-lived public key.
+pub struct DecafBasepointTable(BasepointTable);
+
+impl From<&DecafPoint> for DecafBasepointTable {
+    fn from(point: &DecafPoint) -> Self {
+        DecafBasepointTable(BasepointTable::from(&point.0))
+    }
+}
+
+impl DecafBasepointTable {
+    /// The point the table was built from
+    pub fn basepoint(&self) -> DecafPoint {
+        DecafPoint(self.0.basepoint())
+    }
+
+    /// The table of the generator, built on first use
+    pub fn generator() -> &'static DecafBasepointTable {
+        static TABLE: OnceLock<DecafBasepointTable> = OnceLock::new();
+        TABLE.get_or_init(|| DecafBasepointTable::from(&DecafPoint::GENERATOR))
+    }
+}
+
+impl Mul<&Scalar> for &DecafBasepointTable {
+    type Output = DecafPoint;
+    fn mul(self, scalar: &Scalar) -> DecafPoint {
+        DecafPoint(self.0.mul(scalar))
+    }
+}
+impl Mul<&DecafBasepointTable> for &Scalar {
+    type Output = DecafPoint;
+    fn mul(self, table: &DecafBasepointTable) -> DecafPoint {
+        table * self
+    }
+}
+
+/// A Lim-Lee comb table of a fixed point, for constant-time multiplications
+/// with a chosen trade-off between table size and speed.
+///
+/// See [`EdwardsCombTable`](crate::EdwardsCombTable) for how `teeth` and `spacing` are chosen.
+/// The default of [`From`] is about a quarter faster than [`DecafBasepointTable`].
+pub struct DecafCombTable(CombTable);
+
+impl From<&DecafPoint> for DecafCombTable {
+    fn from(point: &DecafPoint) -> Self {
+        DecafCombTable::new(point, 6, 15)
+    }
+}
+
+impl DecafCombTable {
+    /// Build the table of `point` with combs of `teeth` bits spaced `spacing` bits apart.
+    ///
+    /// # Panics
+    ///
+    /// If `teeth` is not between 1 and 16, or `spacing` is zero.
+    pub fn new(point: &DecafPoint, teeth: usize, spacing: usize) -> Self {
+        DecafCombTable(CombTable::new(&point.0, teeth, spacing))
+    }
+
+    /// The point the table was built from
+    pub fn basepoint(&self) -> DecafPoint {
+        DecafPoint(self.0.basepoint())
+    }
+}
+
+impl Mul<&Scalar> for &DecafCombTable {
+    type Output = DecafPoint;
+    fn mul(self, scalar: &Scalar) -> DecafPoint {
+        DecafPoint(self.0.mul(scalar))
+    }
+}
+impl Mul<&DecafCombTable> for &Scalar {
+    type Output = DecafPoint;
+    fn mul(self, table: &DecafCombTable) -> DecafPoint {
+        table * self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_basepoint_table() {
+        let table = DecafBasepointTable::generator();
+        assert_eq!(table.basepoint(), DecafPoint::GENERATOR);
+        for _ in 0..4 {
+            let s = Scalar::random(&mut OsRng);
+            assert_eq!(table * &s, DecafPoint::GENERATOR * s);
+            assert_eq!(&s * table, DecafPoint::mul_base(&s));
+        }
+
+        let P = DecafPoint::GENERATOR * Scalar::from(1234567u32);
+        let table = DecafBasepointTable::from(&P);
+        let s = Scalar::random(&mut OsRng);
+        assert_eq!(&table * &s, P * s);
+    }
+
+    #[test]
+    fn test_comb_table() {
+        let P = DecafPoint::GENERATOR * Scalar::from(1234567u32);
+        let s = Scalar::random(&mut OsRng);
+        for table in [DecafCombTable::from(&P), DecafCombTable::new(&P, 8, 7)] {
+            assert_eq!(table.basepoint(), P);
+            assert_eq!(&table * &s, P * s);
+            assert_eq!(&s * &table, P * s);
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! The [`Ed448`] curve type, which plugs the Edwards448 group into the generic
+//! `elliptic_curve` machinery such as [`SecretKey`](elliptic_curve::SecretKey),
+//! [`PublicKey`](elliptic_curve::PublicKey) and [`NonZeroScalar`](elliptic_curve::NonZeroScalar).
+//!
+//! Field bytes are the 57-byte little-endian scalar encoding of RFC 8032, as used by
+//! [`Scalar::to_bytes_rfc_8032`], rather than the big-endian encoding of the Weierstrass curves.
+//! The group has cofactor 4, so the curve does not implement `PrimeCurve`.
+//!
+//! With the `zeroize` feature [`Ed448`] is also a `GroupDigest`, whose `hash_from_bytes` and
+//! `encode_from_bytes` agree with [`EdwardsPoint::hash`] and [`EdwardsPoint::encode`].
+use crate::constants::BASEPOINT_ORDER;
+use crate::{AffinePoint, EdwardsPoint, FieldElement, Scalar, ScalarBytes, WideScalarBytes};
+use core::ops::ShrAssign;
+use elliptic_curve::{
+    bigint::{Encoding, U448, U896},
+    generic_array::typenum::U57,
+    hash2curve::MapToCurve,
+    ops::{Invert, LinearCombination, MulByGenerator, Reduce},
+    point::AffineCoordinates,
+    scalar::{FromUintUnchecked, IsHigh},
+    Curve, FieldBytes, FieldBytesEncoding, ScalarPrimitive,
+};
+use subtle::{Choice, ConstantTimeGreater, CtOption};
+
+/// The Edwards448 curve, with scalars modulo the prime order ℓ of its generator
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ed448;
+
+impl Curve for Ed448 {
+    type FieldBytesSize = U57;
+    type Uint = U448;
+
+    const ORDER: U448 = BASEPOINT_ORDER.0;
+}
+
+#[cfg(feature = "zeroize")]
+impl elliptic_curve::CurveArithmetic for Ed448 {
+    type AffinePoint = AffinePoint;
+    type ProjectivePoint = EdwardsPoint;
+    type Scalar = Scalar;
+}
+
+#[cfg(feature = "zeroize")]
+impl elliptic_curve::hash2curve::GroupDigest for Ed448 {
+    type FieldElement = FieldElement;
+}
+
+/// Elligator 2 onto Curve448, then the 4-isogeny to Edwards448.
+/// The cofactor is left for the caller to clear.
+impl MapToCurve for FieldElement {
+    type Output = EdwardsPoint;
+
+    fn map_to_curve(&self) -> EdwardsPoint {
+        self.map_to_curve_elligator2().isogeny().to_edwards()
+    }
+}
+
+/// Little-endian, with the top byte zero
+impl FieldBytesEncoding<Ed448> for U448 {
+    fn decode_field_bytes(field_bytes: &FieldBytes<Ed448>) -> Self {
+        U448::from_le_slice(&field_bytes[..56])
+    }
+
+    fn encode_field_bytes(&self) -> FieldBytes<Ed448> {
+        let mut field_bytes = FieldBytes::<Ed448>::default();
+        field_bytes[..56].copy_from_slice(&self.to_le_bytes());
+        field_bytes
+    }
+}
+
+impl AffineCoordinates for AffinePoint {
+    type FieldRepr = FieldBytes<Ed448>;
+
+    fn x(&self) -> FieldBytes<Ed448> {
+        let mut bytes = FieldBytes::<Ed448>::default();
+        bytes[..56].copy_from_slice(&self.x());
+        bytes
+    }
+
+    fn y_is_odd(&self) -> Choice {
+        self.y.is_negative()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for AffinePoint {}
+
+impl LinearCombination for EdwardsPoint {
+    fn lincomb(x: &Self, k: &Scalar, y: &Self, l: &Scalar) -> Self {
+        EdwardsPoint::multiscalar_mul(&[*k, *l], &[*x, *y])
+    }
+}
+
+impl MulByGenerator for EdwardsPoint {
+    fn mul_by_generator(scalar: &Scalar) -> Self {
+        EdwardsPoint::mul_base(scalar)
+    }
+}
+
+impl AsRef<Scalar> for Scalar {
+    fn as_ref(&self) -> &Scalar {
+        self
+    }
+}
+
+impl From<ScalarPrimitive<Ed448>> for Scalar {
+    fn from(scalar: ScalarPrimitive<Ed448>) -> Scalar {
+        Scalar(*scalar.as_uint())
+    }
+}
+
+impl From<Scalar> for ScalarPrimitive<Ed448> {
+    fn from(scalar: Scalar) -> ScalarPrimitive<Ed448> {
+        ScalarPrimitive::new(scalar.0).expect("scalars are reduced")
+    }
+}
+
+impl From<Scalar> for U448 {
+    fn from(scalar: Scalar) -> U448 {
+        scalar.0
+    }
+}
+
+impl FromUintUnchecked for Scalar {
+    type Uint = U448;
+
+    fn from_uint_unchecked(uint: U448) -> Scalar {
+        Scalar(uint)
+    }
+}
+
+impl Invert for Scalar {
+    type Output = CtOption<Scalar>;
+
+    fn invert(&self) -> CtOption<Scalar> {
+        elliptic_curve::Field::invert(self)
+    }
+}
+
+impl IsHigh for Scalar {
+    fn is_high(&self) -> Choice {
+        const HALF_ORDER: U448 = BASEPOINT_ORDER.0.shr_vartime(1);
+        self.0.ct_gt(&HALF_ORDER)
+    }
+}
+
+impl Reduce<U448> for Scalar {
+    type Bytes = ScalarBytes;
+
+    fn reduce(n: U448) -> Scalar {
+        Scalar::from_bytes_rfc_8032(&n.encode_field_bytes().into())
+    }
+
+    fn reduce_bytes(bytes: &ScalarBytes) -> Scalar {
+        Scalar::from_bytes_rfc_8032(&(*bytes).into())
+    }
+}
+
+/// Bytes are the 114 little-endian bytes of [`Scalar::from_bytes_mod_order_wide`],
+/// of which an integer fills the first 112
+impl Reduce<U896> for Scalar {
+    type Bytes = WideScalarBytes;
+
+    fn reduce(n: U896) -> Scalar {
+        let mut bytes = WideScalarBytes::default();
+        bytes[..112].copy_from_slice(&n.to_le_bytes());
+        Scalar::from_bytes_mod_order_wide(&bytes)
+    }
+
+    fn reduce_bytes(bytes: &WideScalarBytes) -> Scalar {
+        Scalar::from_bytes_mod_order_wide(bytes)
+    }
+}
+
+impl ShrAssign<usize> for Scalar {
+    fn shr_assign(&mut self, shift: usize) {
+        self.0 >>= shift;
+    }
+}
+
+impl PartialOrd for Scalar {
+    fn partial_cmp(&self, other: &Scalar) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scalar {
+    fn cmp(&self, other: &Scalar) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[cfg(all(test, feature = "zeroize"))]
+mod tests {
+    use super::*;
+    use elliptic_curve::{NonZeroScalar, PrimeField, SecretKey};
+    use rand_core::OsRng;
+
+    #[test]
+    fn secret_key() {
+        let secret = SecretKey::<Ed448>::random(&mut OsRng);
+        let scalar = *secret.to_nonzero_scalar();
+        assert_eq!(secret.to_bytes(), scalar.to_repr());
+        assert_eq!(
+            SecretKey::<Ed448>::from_bytes(&scalar.to_repr()).unwrap(),
+            secret
+        );
+        assert_eq!(
+            secret.public_key().to_projective(),
+            EdwardsPoint::mul_base(&scalar)
+        );
+
+        // The order itself is not a valid key
+        let order = Ed448::ORDER.encode_field_bytes();
+        assert!(SecretKey::<Ed448>::from_bytes(&order).is_err());
+        assert!(bool::from(
+            NonZeroScalar::<Ed448>::from_repr(ScalarBytes::default()).is_none()
+        ));
+    }
+
+    #[test]
+    fn group_digest() {
+        use elliptic_curve::hash2curve::{ExpandMsgXof, GroupDigest};
+        use sha3::Shake256;
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-edwards448_XOF:SHAKE256_ELL2_RO_";
+        for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+            assert_eq!(
+                Ed448::hash_from_bytes::<ExpandMsgXof<Shake256>>(&[msg], &[DST]).unwrap(),
+                EdwardsPoint::hash::<ExpandMsgXof<Shake256>>(msg, DST)
+            );
+            assert_eq!(
+                Ed448::encode_from_bytes::<ExpandMsgXof<Shake256>>(&[msg], &[DST]).unwrap(),
+                EdwardsPoint::encode::<ExpandMsgXof<Shake256>>(msg, DST)
+            );
+            assert_eq!(
+                Ed448::hash_to_scalar::<ExpandMsgXof<Shake256>>(&[msg], &[DST]).unwrap(),
+                Scalar::hash::<ExpandMsgXof<Shake256>>(msg, DST)
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_conversions() {
+        let s = Scalar::random(&mut OsRng);
+        assert_eq!(Scalar::from(ScalarPrimitive::from(s)), s);
+        assert_eq!(Scalar::from_uint_unchecked(U448::from(s)), s);
+        assert_eq!(
+            U448::decode_field_bytes(&U448::from(s).encode_field_bytes()),
+            s.0
+        );
+        assert_eq!(Scalar::reduce(s.0), s);
+        assert_eq!(
+            Scalar::reduce(U448::MAX),
+            Scalar::from_bytes_rfc_8032(&{
+                let mut bytes = [0xff; 57];
+                bytes[56] = 0;
+                bytes
+            })
+        );
+
+        // ℓ^2 + s, and the largest 896-bit integer
+        let square = U896::from(Ed448::ORDER.mul_wide(&Ed448::ORDER));
+        let wide = square.wrapping_add(&U896::from((s.0, U448::ZERO)));
+        assert_eq!(Scalar::reduce(wide), s);
+        let mut max = WideScalarBytes::default();
+        max[..112].fill(0xff);
+        assert_eq!(
+            Scalar::reduce(U896::MAX),
+            <Scalar as Reduce<U896>>::reduce_bytes(&max)
+        );
+
+        let half = Scalar(Ed448::ORDER.shr_vartime(1));
+        assert!(bool::from(!half.is_high()));
+        assert!(bool::from((half + Scalar::ONE).is_high()));
+        assert!(half < half + Scalar::ONE);
+
+        let mut shifted = s;
+        shifted >>= 2;
+        let mut floor = s;
+        floor.div_by_four_floor();
+        assert_eq!(shifted, floor);
+    }
+
+    #[test]
+    fn point_traits() {
+        let (k, l) = (Scalar::random(&mut OsRng), Scalar::random(&mut OsRng));
+        let (x, y) = (EdwardsPoint::GENERATOR * k, EdwardsPoint::GENERATOR * l);
+        assert_eq!(EdwardsPoint::lincomb(&x, &l, &y, &k), x * l + y * k);
+        assert_eq!(EdwardsPoint::mul_by_generator(&k), x);
+
+        let affine = x.to_affine();
+        let x_bytes = AffineCoordinates::x(&affine);
+        assert_eq!(x_bytes[..56], affine.x()[..]);
+        assert_eq!(x_bytes[56], 0);
+        assert_eq!(
+            bool::from(affine.y_is_odd()),
+            affine.y.to_bytes()[0] & 1 == 1
+        );
+    }
+}
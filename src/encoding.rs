@@ -0,0 +1,130 @@
+//! Constant-time text encodings for secret material.
+//!
+//! The `hex` crate and most base64 implementations use lookup tables and
+//! data-dependent branches, which is fine for public values like points but
+//! can leak secret scalars, seeds and shared secrets through timing when
+//! loading configuration or exporting keys. The functions in this module
+//! wrap the constant-time [`base16ct`] and [`base64ct`] codecs so the
+//! running time only depends on the length of the input.
+//!
+//! Errors are reported without echoing the offending input.
+use base64ct::{Base64, Base64UrlUnpadded, Encoding};
+
+/// Encode `bytes` as lowercase hex in constant time.
+pub fn hex_encode(bytes: &[u8]) -> String {
+    base16ct::lower::encode_string(bytes)
+}
+
+/// Encode `bytes` as uppercase hex in constant time.
+pub fn hex_encode_upper(bytes: &[u8]) -> String {
+    base16ct::upper::encode_string(bytes)
+}
+
+/// Decode a hex string in constant time. Upper and lowercase digits are
+/// both accepted.
+pub fn hex_decode(s: &str) -> Result<Vec<u8>, String> {
+    base16ct::mixed::decode_vec(s).map_err(|_| "invalid hex string".to_string())
+}
+
+/// Decode a hex string in constant time into `out`, which must be exactly
+/// half the length of `s`. This avoids leaving a copy of the secret on the heap.
+pub fn hex_decode_into(s: &str, out: &mut [u8]) -> Result<(), String> {
+    if s.len() != out.len() * 2 {
+        return Err(format!("invalid hex length, expected {}", out.len() * 2));
+    }
+    base16ct::mixed::decode(s, out).map_err(|_| "invalid hex string".to_string())?;
+    Ok(())
+}
+
+/// Encode `bytes` as padded standard base64 (RFC 4648 §4) in constant time.
+pub fn base64_encode(bytes: &[u8]) -> String {
+    Base64::encode_string(bytes)
+}
+
+/// Decode padded standard base64 (RFC 4648 §4) in constant time.
+pub fn base64_decode(s: &str) -> Result<Vec<u8>, String> {
+    Base64::decode_vec(s).map_err(|_| "invalid base64 string".to_string())
+}
+
+/// Decode padded standard base64 in constant time into `out`, which must
+/// be exactly the decoded length.
+pub fn base64_decode_into(s: &str, out: &mut [u8]) -> Result<(), String> {
+    let decoded = Base64::decode(s, out).map_err(|_| "invalid base64 string".to_string())?;
+    if decoded.len() != out.len() {
+        return Err(format!(
+            "invalid base64 length, expected {} bytes",
+            out.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Encode `bytes` as unpadded URL-safe base64 (RFC 4648 §5) in constant time.
+pub fn base64url_encode(bytes: &[u8]) -> String {
+    Base64UrlUnpadded::encode_string(bytes)
+}
+
+/// Decode unpadded URL-safe base64 (RFC 4648 §5) in constant time.
+pub fn base64url_decode(s: &str) -> Result<Vec<u8>, String> {
+    Base64UrlUnpadded::decode_vec(s).map_err(|_| "invalid base64url string".to_string())
+}
+
+/// Decode unpadded URL-safe base64 in constant time into `out`, which must
+/// be exactly the decoded length.
+pub fn base64url_decode_into(s: &str, out: &mut [u8]) -> Result<(), String> {
+    let decoded =
+        Base64UrlUnpadded::decode(s, out).map_err(|_| "invalid base64url string".to_string())?;
+    if decoded.len() != out.len() {
+        return Err(format!(
+            "invalid base64url length, expected {} bytes",
+            out.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scalar;
+
+    #[test]
+    fn hex_round_trip() {
+        let bytes = (0u8..=255).collect::<Vec<_>>();
+        let s = hex_encode(&bytes);
+        assert_eq!(s, hex::encode(&bytes));
+        assert_eq!(hex_encode_upper(&bytes), hex::encode_upper(&bytes));
+        assert_eq!(hex_decode(&s).unwrap(), bytes);
+        assert_eq!(hex_decode(&s.to_uppercase()).unwrap(), bytes);
+
+        let mut out = [0u8; 256];
+        hex_decode_into(&s, &mut out).unwrap();
+        assert_eq!(&out[..], &bytes[..]);
+
+        assert!(hex_decode("0g").is_err());
+        assert!(hex_decode("abc").is_err());
+        assert!(hex_decode_into("abcd", &mut [0u8; 1]).is_err());
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let bytes = Scalar::from(0x0102030405060708u64).to_bytes_rfc_8032();
+        let s = base64_encode(&bytes);
+        assert_eq!(base64_decode(&s).unwrap(), bytes.to_vec());
+        let mut out = [0u8; 57];
+        base64_decode_into(&s, &mut out).unwrap();
+        assert_eq!(&out[..], &bytes[..]);
+        assert!(base64_decode_into(&s, &mut [0u8; 58]).is_err());
+
+        let s = base64url_encode(&[0xfb, 0xff]);
+        assert_eq!(s, "-_8");
+        assert_eq!(base64url_decode(&s).unwrap(), vec![0xfb, 0xff]);
+        let mut out = [0u8; 2];
+        base64url_decode_into(&s, &mut out).unwrap();
+        assert_eq!(out, [0xfb, 0xff]);
+
+        assert_eq!(base64_encode(&[0xfb, 0xff]), "+/8=");
+        assert!(base64_decode("-_8").is_err());
+        assert!(base64url_decode("+/8=").is_err());
+    }
+}
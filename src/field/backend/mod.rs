@@ -0,0 +1,31 @@
+//! Limb representations of field elements, one module per arithmetic backend.
+//!
+//! [`FieldElement`](super::FieldElement) wraps whichever of them is selected at compile time:
+//!
+//! - `u64`: eight unsaturated 56-bit limbs, the default on 64-bit targets.
+//! - `u32`: sixteen 28-bit limbs that only need 32x32-bit multiplications, used on targets
+//!   whose pointers are narrower than 64 bits, or everywhere with the `force_32bit` feature.
+//!
+//! A backend is a `Copy + Default` type implementing `Add`, `Sub`, `Mul`, `Neg` and
+//! `ConditionallySelectable`, with `square`, `to_bytes` and a const `from_bytes`.
+//!
+//! The `simd` and `fiat` features are reserved for a vectorised backend and one generated
+//! by fiat-crypto, and fail to compile until those exist. A vectorised backend needs its
+//! own point types with coordinates in lanes, and fiat-crypto is not a dependency yet.
+//! Choosing a backend at runtime from the CPU features is not supported either: the
+//! selection above is made entirely at compile time, and a runtime check would go in this
+//! module, falling back to the portable backend, once a backend needs one.
+#[cfg(feature = "simd")]
+compile_error!("the `simd` field backend is reserved but not implemented yet");
+#[cfg(feature = "fiat")]
+compile_error!("the `fiat` field backend is reserved but not implemented yet");
+
+#[cfg(any(feature = "force_32bit", not(target_pointer_width = "64")))]
+mod u32;
+#[cfg(not(any(feature = "force_32bit", not(target_pointer_width = "64"))))]
+mod u64;
+
+#[cfg(any(feature = "force_32bit", not(target_pointer_width = "64")))]
+pub(crate) use self::u32::FieldElement28 as Limbs;
+#[cfg(not(any(feature = "force_32bit", not(target_pointer_width = "64"))))]
+pub(crate) use self::u64::FieldElement56 as Limbs;
@@ -0,0 +1,247 @@
+//! Field arithmetic with sixteen 28-bit limbs in `u32` words, for targets without a
+//! fast 64-bit multiplication.
+use core::ops::{Add, Mul, Neg, Sub};
+use subtle::{Choice, ConditionallySelectable};
+
+/// The number of bits in a limb
+const LIMB_BITS: u32 = 28;
+const LIMB_MASK: u32 = (1 << LIMB_BITS) - 1;
+/// Every operation carries its result back below this, and accepts inputs below it
+const LIMB_BOUND: u32 = (1 << LIMB_BITS) + (1 << 10);
+/// `p = 2^448 - 2^224 - 1` in limbs
+const MODULUS: [u32; 16] = {
+    let mut limbs = [LIMB_MASK; 16];
+    limbs[8] = LIMB_MASK - 1;
+    limbs
+};
+
+/// An element of GF(p), `p = 2^448 - 2^224 - 1`, in sixteen 28-bit limbs.
+///
+/// Every operation carries its result, so limbs stay just above `2^28` and any number of
+/// additions can be chained.
+/// The value is only made canonical when it is encoded.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct FieldElement28(pub(crate) [u32; 16]);
+
+impl ConditionallySelectable for FieldElement28 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(core::array::from_fn(|i| {
+            u32::conditional_select(&a.0[i], &b.0[i], choice)
+        }))
+    }
+}
+
+impl Add for FieldElement28 {
+    type Output = FieldElement28;
+
+    fn add(self, other: FieldElement28) -> FieldElement28 {
+        Self(core::array::from_fn(|i| self.0[i] + other.0[i])).weak_reduce()
+    }
+}
+
+impl Sub for FieldElement28 {
+    type Output = FieldElement28;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: FieldElement28) -> FieldElement28 {
+        // 2p has limbs above every limb of `other`
+        debug_assert!(other.0.iter().all(|&limb| limb < LIMB_BOUND));
+        Self(core::array::from_fn(|i| {
+            self.0[i] + 2 * MODULUS[i] - other.0[i]
+        }))
+        .weak_reduce()
+    }
+}
+
+impl Mul for FieldElement28 {
+    type Output = FieldElement28;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: FieldElement28) -> FieldElement28 {
+        // Karatsuba over a = a0 + a1 φ with φ = 2^224, where φ^2 = φ + 1:
+        // a b = (a0 b0 + a1 b1) + ((a0 + a1)(b0 + b1) - a0 b0) φ
+        let (a, b) = (&self.0, &other.0);
+        debug_assert!(a.iter().chain(b.iter()).all(|&limb| limb < LIMB_BOUND));
+        let a_sum: [u32; 8] = core::array::from_fn(|i| a[i] + a[i + 8]);
+        let b_sum: [u32; 8] = core::array::from_fn(|i| b[i] + b[i + 8]);
+
+        let low = mul_8(&a[..8], &b[..8]);
+        let high = mul_8(&a[8..], &b[8..]);
+        let mid = mul_8(&a_sum, &b_sum);
+        Self::reduce_wide(
+            core::array::from_fn(|i| low[i] + high[i]),
+            core::array::from_fn(|i| mid[i] - low[i]),
+        )
+    }
+}
+
+impl Neg for FieldElement28 {
+    type Output = FieldElement28;
+
+    fn neg(self) -> FieldElement28 {
+        Self::default() - self
+    }
+}
+
+impl FieldElement28 {
+    pub(crate) fn square(&self) -> Self {
+        let a = &self.0;
+        debug_assert!(a.iter().all(|&limb| limb < LIMB_BOUND));
+        let a_sum: [u32; 8] = core::array::from_fn(|i| a[i] + a[i + 8]);
+
+        let low = square_8(&a[..8]);
+        let high = square_8(&a[8..]);
+        let mid = square_8(&a_sum);
+        Self::reduce_wide(
+            core::array::from_fn(|i| low[i] + high[i]),
+            core::array::from_fn(|i| mid[i] - low[i]),
+        )
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; 56] {
+        let limbs = self.strong_reduce().0;
+        let mut bytes = [0u8; 56];
+        for (chunk, pair) in bytes.chunks_exact_mut(7).zip(limbs.chunks_exact(2)) {
+            let word = pair[0] as u64 | (pair[1] as u64) << LIMB_BITS;
+            chunk.copy_from_slice(&word.to_le_bytes()[..7]);
+        }
+        bytes
+    }
+
+    /// Decodes 56 little-endian bytes, values of `p` and above are reduced
+    pub(crate) const fn from_bytes(bytes: &[u8; 56]) -> Self {
+        let mut limbs = [0u32; 16];
+        let mut i = 0;
+        while i < 8 {
+            // Every seven bytes fill two limbs
+            let mut word = 0u64;
+            let mut j = 0;
+            while j < 7 {
+                word |= (bytes[7 * i + j] as u64) << (8 * j);
+                j += 1;
+            }
+            limbs[2 * i] = word as u32 & LIMB_MASK;
+            limbs[2 * i + 1] = (word >> LIMB_BITS) as u32;
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// Carries every limb into the next, the top one wrapping around as `2^448 = 2^224 + 1`.
+    ///
+    /// Limbs below `2^31` come out below `2^28 + 2^4`.
+    fn weak_reduce(self) -> Self {
+        let a = self.0;
+        let top = a[15] >> LIMB_BITS;
+        let mut limbs = [0u32; 16];
+        limbs[0] = (a[0] & LIMB_MASK) + top;
+        for i in 1..16 {
+            limbs[i] = (a[i] & LIMB_MASK) + (a[i - 1] >> LIMB_BITS);
+        }
+        limbs[8] += top;
+        Self(limbs)
+    }
+
+    /// Reduces to the canonical representative, with every limb below `2^28`
+    fn strong_reduce(self) -> Self {
+        // Below 2p once weakly reduced, so subtracting p and adding it back if that
+        // borrowed gives the canonical value
+        let mut limbs = self.weak_reduce().0;
+        let mut borrow = 0i64;
+        for (limb, m) in limbs.iter_mut().zip(MODULUS.iter()) {
+            borrow += *limb as i64 - *m as i64;
+            *limb = borrow as u32 & LIMB_MASK;
+            borrow >>= LIMB_BITS;
+        }
+        debug_assert!(borrow == 0 || borrow == -1);
+
+        let add_back = borrow as u32;
+        let mut carry = 0u32;
+        for (limb, m) in limbs.iter_mut().zip(MODULUS.iter()) {
+            carry += *limb + (m & add_back);
+            *limb = carry & LIMB_MASK;
+            carry >>= LIMB_BITS;
+        }
+        Self(limbs)
+    }
+
+    /// Reduces `low + high φ`, each of fifteen 28-bit positions, to an element,
+    /// using `φ^2 = φ + 1` with `φ = 2^224`
+    fn reduce_wide(low: [u64; 15], high: [u64; 15]) -> Self {
+        let mut wide = [0u64; 16];
+        for i in 0..8 {
+            let high_wrap = if i < 7 { high[i + 8] } else { 0 };
+            let low_wrap = if i < 7 { low[i + 8] } else { 0 };
+            wide[i] = low[i] + high_wrap;
+            wide[i + 8] = high[i] + low_wrap + high_wrap;
+        }
+
+        let mut limbs = [0u32; 16];
+        let mut carry = 0u64;
+        for (limb, w) in limbs.iter_mut().zip(wide.iter()) {
+            carry += w;
+            *limb = carry as u32 & LIMB_MASK;
+            carry >>= LIMB_BITS;
+        }
+        // The carry out of the top is below 2^36, fold it back in and carry once more
+        let mut low_carry = limbs[0] as u64 + carry;
+        let mut high_carry = limbs[8] as u64 + carry;
+        limbs[0] = low_carry as u32 & LIMB_MASK;
+        limbs[8] = high_carry as u32 & LIMB_MASK;
+        low_carry >>= LIMB_BITS;
+        high_carry >>= LIMB_BITS;
+        limbs[1] += low_carry as u32;
+        limbs[9] += high_carry as u32;
+        Self(limbs)
+    }
+}
+
+/// The product of two eight-limb polynomials, by coefficient
+#[inline(always)]
+fn mul_8(a: &[u32], b: &[u32]) -> [u64; 15] {
+    let mut result = [0u64; 15];
+    for i in 0..8 {
+        for j in 0..8 {
+            result[i + j] += a[i] as u64 * b[j] as u64;
+        }
+    }
+    result
+}
+
+/// The square of an eight-limb polynomial, by coefficient
+#[inline(always)]
+fn square_8(a: &[u32]) -> [u64; 15] {
+    let mut result = [0u64; 15];
+    for i in 0..8 {
+        result[2 * i] += a[i] as u64 * a[i] as u64;
+        for j in i + 1..8 {
+            result[i + j] += 2 * (a[i] as u64 * a[j] as u64);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limb_bounds() {
+        // The largest limbs an operation may return
+        let top = FieldElement28([LIMB_BOUND - 1; 16]);
+        let reduced = FieldElement28::from_bytes(&top.to_bytes());
+        assert_eq!(reduced.0, top.strong_reduce().0);
+        assert_eq!((top * top).to_bytes(), (reduced * reduced).to_bytes());
+        assert_eq!(top.square().to_bytes(), reduced.square().to_bytes());
+        assert_eq!((top + top).to_bytes(), (reduced + reduced).to_bytes());
+        assert_eq!((-top).to_bytes(), (-reduced).to_bytes());
+        assert_eq!((top - reduced).to_bytes(), [0u8; 56]);
+        assert!((top * top).0.iter().all(|&limb| limb < LIMB_BOUND));
+
+        // Neither p nor 2^448 - 1 is canonical
+        assert_eq!(FieldElement28(MODULUS).to_bytes(), [0u8; 56]);
+        let mut two_224 = [0u8; 56];
+        two_224[28] = 1;
+        assert_eq!(FieldElement28::from_bytes(&[0xff; 56]).to_bytes(), two_224);
+    }
+}
@@ -0,0 +1,270 @@
+//! Field arithmetic with eight unsaturated 56-bit limbs in `u64` words.
+use core::ops::{Add, Mul, Neg, Sub};
+use subtle::{Choice, ConditionallySelectable};
+
+/// The number of bits in a limb
+const LIMB_BITS: u32 = 56;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+/// Subtractions, multiplications and squarings are only correct for limbs below this.
+/// Every operation returns limbs below `2^56 + 2^7`, far inside it.
+const LIMB_BOUND: u64 = 1 << 60;
+/// `p = 2^448 - 2^224 - 1` in limbs
+const MODULUS: [u64; 8] = [
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK - 1,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+];
+
+/// An element of GF(p), `p = 2^448 - 2^224 - 1`, in eight unsaturated 56-bit limbs.
+///
+/// Limbs are not kept below `2^56`. Every operation carries its result back down to about
+/// `2^56` per limb, so any number of additions can be chained, and checks that its inputs
+/// are below [`LIMB_BOUND`].
+/// The value is only made canonical when it is encoded.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct FieldElement56(pub(crate) [u64; 8]);
+
+impl ConditionallySelectable for FieldElement56 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(core::array::from_fn(|i| {
+            u64::conditional_select(&a.0[i], &b.0[i], choice)
+        }))
+    }
+}
+
+impl Add for FieldElement56 {
+    type Output = FieldElement56;
+
+    fn add(self, other: FieldElement56) -> FieldElement56 {
+        check_bound(&self.0);
+        check_bound(&other.0);
+        Self(core::array::from_fn(|i| self.0[i] + other.0[i])).weak_reduce()
+    }
+}
+
+impl Sub for FieldElement56 {
+    type Output = FieldElement56;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: FieldElement56) -> FieldElement56 {
+        // Add a multiple of p with limbs above every limb of `other` before subtracting
+        check_bound(&self.0);
+        check_bound(&other.0);
+        Self(core::array::from_fn(|i| {
+            self.0[i] + 32 * MODULUS[i] - other.0[i]
+        }))
+        .weak_reduce()
+    }
+}
+
+impl Mul for FieldElement56 {
+    type Output = FieldElement56;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: FieldElement56) -> FieldElement56 {
+        // Karatsuba over a = a0 + a1 φ with φ = 2^224, where φ^2 = φ + 1:
+        // a b = (a0 b0 + a1 b1) + ((a0 + a1)(b0 + b1) - a0 b0) φ
+        let (a, b) = (&self.0, &other.0);
+        check_bound(a);
+        check_bound(b);
+        let a_sum: [u64; 4] = core::array::from_fn(|i| a[i] + a[i + 4]);
+        let b_sum: [u64; 4] = core::array::from_fn(|i| b[i] + b[i + 4]);
+
+        let low = mul_4(&a[..4], &b[..4]);
+        let high = mul_4(&a[4..], &b[4..]);
+        let mid = mul_4(&a_sum, &b_sum);
+        Self::reduce_wide(
+            core::array::from_fn(|i| low[i] + high[i]),
+            core::array::from_fn(|i| mid[i] - low[i]),
+        )
+    }
+}
+
+impl Neg for FieldElement56 {
+    type Output = FieldElement56;
+
+    fn neg(self) -> FieldElement56 {
+        Self::default() - self
+    }
+}
+
+impl FieldElement56 {
+    pub(crate) fn square(&self) -> Self {
+        let a = &self.0;
+        check_bound(a);
+        let a_sum: [u64; 4] = core::array::from_fn(|i| a[i] + a[i + 4]);
+
+        let low = square_4(&a[..4]);
+        let high = square_4(&a[4..]);
+        let mid = square_4(&a_sum);
+        Self::reduce_wide(
+            core::array::from_fn(|i| low[i] + high[i]),
+            core::array::from_fn(|i| mid[i] - low[i]),
+        )
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; 56] {
+        let limbs = self.strong_reduce().0;
+        let mut bytes = [0u8; 56];
+        for (chunk, limb) in bytes.chunks_exact_mut(7).zip(limbs.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes()[..7]);
+        }
+        bytes
+    }
+
+    /// Decodes 56 little-endian bytes, values of `p` and above are reduced
+    pub(crate) const fn from_bytes(bytes: &[u8; 56]) -> Self {
+        let mut limbs = [0u64; 8];
+        let mut i = 0;
+        while i < 56 {
+            limbs[i / 7] |= (bytes[i] as u64) << (8 * (i % 7));
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// Carries every limb into the next, the top one wrapping around as `2^448 = 2^224 + 1`.
+    ///
+    /// Limbs below `2^62` come out below `2^56 + 2^7`.
+    fn weak_reduce(self) -> Self {
+        let a = self.0;
+        let top = a[7] >> LIMB_BITS;
+        let mut limbs = [0u64; 8];
+        limbs[0] = (a[0] & LIMB_MASK) + top;
+        for i in 1..8 {
+            limbs[i] = (a[i] & LIMB_MASK) + (a[i - 1] >> LIMB_BITS);
+        }
+        limbs[4] += top;
+        Self(limbs)
+    }
+
+    /// Reduces to the canonical representative, with every limb below `2^56`
+    fn strong_reduce(self) -> Self {
+        // Below 2p once weakly reduced, so subtracting p and adding it back if that
+        // borrowed gives the canonical value
+        let mut limbs = self.weak_reduce().0;
+        let mut borrow = 0i64;
+        for (limb, m) in limbs.iter_mut().zip(MODULUS.iter()) {
+            borrow += *limb as i64 - *m as i64;
+            *limb = borrow as u64 & LIMB_MASK;
+            borrow >>= LIMB_BITS;
+        }
+        debug_assert!(borrow == 0 || borrow == -1);
+
+        let add_back = borrow as u64;
+        let mut carry = 0u64;
+        for (limb, m) in limbs.iter_mut().zip(MODULUS.iter()) {
+            carry += *limb + (m & add_back);
+            *limb = carry & LIMB_MASK;
+            carry >>= LIMB_BITS;
+        }
+        Self(limbs)
+    }
+
+    /// Reduces `low + high φ`, each of seven 56-bit positions, to an element,
+    /// using `φ^2 = φ + 1` with `φ = 2^224`
+    fn reduce_wide(low: [u128; 7], high: [u128; 7]) -> Self {
+        let mut wide = [0u128; 8];
+        for i in 0..4 {
+            let high_wrap = if i < 3 { high[i + 4] } else { 0 };
+            let low_wrap = if i < 3 { low[i + 4] } else { 0 };
+            wide[i] = low[i] + high_wrap;
+            wide[i + 4] = high[i] + low_wrap + high_wrap;
+        }
+
+        let mut limbs = [0u64; 8];
+        let mut carry = 0u128;
+        for (limb, w) in limbs.iter_mut().zip(wide.iter()) {
+            carry += w;
+            *limb = carry as u64 & LIMB_MASK;
+            carry >>= LIMB_BITS;
+        }
+        // The carry out of the top is below 2^72, fold it back in and carry once more
+        let mut low_carry = limbs[0] as u128 + carry;
+        let mut high_carry = limbs[4] as u128 + carry;
+        limbs[0] = low_carry as u64 & LIMB_MASK;
+        limbs[4] = high_carry as u64 & LIMB_MASK;
+        low_carry >>= LIMB_BITS;
+        high_carry >>= LIMB_BITS;
+        limbs[1] += low_carry as u64;
+        limbs[5] += high_carry as u64;
+        Self(limbs)
+    }
+}
+
+/// Panics if a limb is not below [`LIMB_BOUND`], where the arithmetic would overflow.
+///
+/// The bound is a power of two, so this ORs the limbs together and branches once,
+/// which takes the same time for every valid element.
+#[inline(always)]
+fn check_bound(limbs: &[u64; 8]) {
+    let all = limbs.iter().fold(0, |acc, limb| acc | limb);
+    assert!(all < LIMB_BOUND, "field element limbs out of range");
+}
+
+/// The product of two four-limb polynomials, by coefficient
+#[inline(always)]
+fn mul_4(a: &[u64], b: &[u64]) -> [u128; 7] {
+    let mut result = [0u128; 7];
+    for i in 0..4 {
+        for j in 0..4 {
+            result[i + j] += a[i] as u128 * b[j] as u128;
+        }
+    }
+    result
+}
+
+/// The square of a four-limb polynomial, by coefficient
+#[inline(always)]
+fn square_4(a: &[u64]) -> [u128; 7] {
+    let mut result = [0u128; 7];
+    for i in 0..4 {
+        result[2 * i] += a[i] as u128 * a[i] as u128;
+        for j in i + 1..4 {
+            result[i + j] += 2 * (a[i] as u128 * a[j] as u128);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limb_bounds() {
+        // The largest limbs the arithmetic accepts
+        let top = FieldElement56([LIMB_BOUND - 1; 8]);
+        let reduced = FieldElement56::from_bytes(&top.to_bytes());
+        assert_eq!(reduced.0, top.strong_reduce().0);
+        assert_eq!((top * top).to_bytes(), (reduced * reduced).to_bytes());
+        assert_eq!(top.square().to_bytes(), reduced.square().to_bytes());
+        assert_eq!((-top).to_bytes(), (-reduced).to_bytes());
+        assert_eq!((top - reduced).to_bytes(), [0u8; 56]);
+        assert_eq!((top + top).to_bytes(), (reduced + reduced).to_bytes());
+
+        // Additions carry, so a long chain of them stays in range
+        let mut chain = top;
+        for _ in 0..10_000 {
+            chain = chain + top;
+        }
+        assert!(chain.0.iter().all(|&limb| limb < (1 << 56) + (1 << 7)));
+
+        // Neither p nor 2^448 - 1 is canonical
+        assert_eq!(FieldElement56(MODULUS).to_bytes(), [0u8; 56]);
+        let mut two_224 = [0u8; 56];
+        two_224[28] = 1;
+        assert_eq!(FieldElement56::from_bytes(&[0xff; 56]).to_bytes(), two_224);
+    }
+
+    #[test]
+    #[should_panic(expected = "field element limbs out of range")]
+    fn rejects_limbs_out_of_range() {
+        let _ = FieldElement56([LIMB_BOUND; 8]) * FieldElement56::default();
+    }
+}
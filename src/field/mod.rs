@@ -1,79 +1,89 @@
+mod backend;
 mod scalar;
 
 pub use scalar::{Scalar, ScalarBytes, WideScalarBytes};
 
+use backend::Limbs;
+
 use crate::curve::edwards::affine::AffinePoint;
 use crate::curve::edwards::EdwardsPoint;
 use crate::curve::twedwards::extended::ExtendedPoint as TwExtendedPoint;
 
 use elliptic_curve::{
-    bigint::{impl_modulus, modular::constant_mod::*, Encoding, U448, U704},
+    bigint::{Encoding, Word, U448, U704},
+    ff::{helpers, Field, PrimeField},
     generic_array::{
-        typenum::{U84, U88},
+        typenum::{U56, U84, U88},
         GenericArray,
     },
     hash2curve::FromOkm,
 };
+use rand_core::RngCore;
 use std::{
     fmt::{Debug, Display, Formatter, LowerHex, Result as FmtResult, UpperHex},
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
-use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
-use zeroize::DefaultIsZeroes;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 pub const GOLDILOCKS_BASE_POINT: EdwardsPoint = EdwardsPoint {
-    X: FieldElement(ResidueType::new(&U448::from_be_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e"))),
-    Y: FieldElement(ResidueType::new(&U448::from_be_hex("693f46716eb6bc248876203756c9c7624bea73736ca3984087789c1e05a0c2d73ad3ff1ce67c39c4fdbd132c4ed7c8ad9808795bf230fa14"))),
+    X: FieldElement::from_u448(&U448::from_be_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e")),
+    Y: FieldElement::from_u448(&U448::from_be_hex("693f46716eb6bc248876203756c9c7624bea73736ca3984087789c1e05a0c2d73ad3ff1ce67c39c4fdbd132c4ed7c8ad9808795bf230fa14")),
     Z: FieldElement::ONE,
-    T: FieldElement(ResidueType::new(&U448::from_be_hex("c75eb58aee221c6ccec39d2d508d91c9c5056a183f8451d260d71667e2356d58f179de90b5b27da1f78fa07d85662d1deb06624e82af95f3"))),
+    T: FieldElement::from_u448(&U448::from_be_hex("c75eb58aee221c6ccec39d2d508d91c9c5056a183f8451d260d71667e2356d58f179de90b5b27da1f78fa07d85662d1deb06624e82af95f3")),
 };
 
 pub const TWISTED_EDWARDS_BASE_POINT: TwExtendedPoint = TwExtendedPoint {
-    X: FieldElement(ResidueType::new(&U448::from_be_hex("7ffffffffffffffffffffffffffffffffffffffffffffffffffffffe80000000000000000000000000000000000000000000000000000000"))),
-    Y: FieldElement(ResidueType::new(&U448::from_be_hex("8508de14f04286d48d06c13078ca240805264370504c74c393d5242c5045271414181844d73f48e5199b0c1e3ab470a1c86079b4dfdd4a64"))),
+    X: FieldElement::from_u448(&U448::from_be_hex("7ffffffffffffffffffffffffffffffffffffffffffffffffffffffe80000000000000000000000000000000000000000000000000000000")),
+    Y: FieldElement::from_u448(&U448::from_be_hex("8508de14f04286d48d06c13078ca240805264370504c74c393d5242c5045271414181844d73f48e5199b0c1e3ab470a1c86079b4dfdd4a64")),
     Z: FieldElement::ONE,
-    T: FieldElement(ResidueType::new(&U448::from_be_hex("6d3669e173c6a450e23d5682a9ffe1ddc2b86da60f794be956382384a319b57519c9854dde98e342140362071833f4e093e3c816dc198105"))),
+    T: FieldElement::from_u448(&U448::from_be_hex("6d3669e173c6a450e23d5682a9ffe1ddc2b86da60f794be956382384a319b57519c9854dde98e342140362071833f4e093e3c816dc198105")),
 };
 
-impl_modulus!(MODULUS, U448, "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
-type ResidueType = Residue<MODULUS, { MODULUS::LIMBS }>;
-
+/// An element of the base field GF(p), `p = 2^448 - 2^224 - 1`, of Curve448 and Edwards448.
+///
+/// The limbs come from the backend selected at compile time.
+/// The value is only made canonical when it is encoded or compared.
 #[derive(Clone, Copy, Default)]
-pub(crate) struct FieldElement(pub(crate) ResidueType);
+pub struct FieldElement(pub(crate) Limbs);
+
+/// The little-endian encoding of a field element
+#[allow(deprecated)]
+pub type FieldElementBytes = GenericArray<u8, U56>;
 
 impl Display for FieldElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:x}", self.0.retrieve())
+        write!(f, "{:x}", self.to_u448())
     }
 }
 
 impl Debug for FieldElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "FieldElement({:x})", self.0.retrieve())
+        write!(f, "FieldElement({:x})", self.to_u448())
     }
 }
 
 impl LowerHex for FieldElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:x}", self.0.retrieve())
+        write!(f, "{:x}", self.to_u448())
     }
 }
 
 impl UpperHex for FieldElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:X}", self.0.retrieve())
+        write!(f, "{:X}", self.to_u448())
     }
 }
 
 impl ConstantTimeEq for FieldElement {
     fn ct_eq(&self, other: &Self) -> Choice {
-        self.0.ct_eq(&other.0)
+        self.to_bytes().ct_eq(&other.to_bytes())
     }
 }
 
 impl ConditionallySelectable for FieldElement {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        Self(ResidueType::conditional_select(&a.0, &b.0, choice))
+        Self(Limbs::conditional_select(&a.0, &b.0, choice))
     }
 }
 
@@ -95,11 +105,12 @@ impl FromOkm for FieldElement {
         let mut num = U704::from_be_slice(&tmp[..]);
         num = num.wrapping_rem(&SEMI_WIDE_MODULUS);
         let bytes = <[u8; 56]>::try_from(&num.to_le_bytes()[..56]).unwrap();
-        FieldElement(ResidueType::new(&U448::from_le_slice(&bytes)))
+        FieldElement::from_bytes(&bytes)
     }
 }
 
-impl DefaultIsZeroes for FieldElement {}
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for FieldElement {}
 
 impl Add<&FieldElement> for &FieldElement {
     type Output = FieldElement;
@@ -129,7 +140,7 @@ impl Add for FieldElement {
     type Output = FieldElement;
 
     fn add(self, other: FieldElement) -> FieldElement {
-        Self(self.0.add(&other.0))
+        Self(self.0 + other.0)
     }
 }
 
@@ -173,7 +184,7 @@ impl Sub for FieldElement {
     type Output = FieldElement;
 
     fn sub(self, other: FieldElement) -> FieldElement {
-        Self(self.0.sub(&other.0))
+        Self(self.0 - other.0)
     }
 }
 
@@ -217,7 +228,7 @@ impl Mul for FieldElement {
     type Output = FieldElement;
 
     fn mul(self, other: FieldElement) -> FieldElement {
-        Self(self.0.mul(&other.0))
+        Self(self.0 * other.0)
     }
 }
 
@@ -245,36 +256,160 @@ impl Neg for FieldElement {
     type Output = FieldElement;
 
     fn neg(self) -> FieldElement {
-        Self(self.0.neg())
+        Self(-self.0)
     }
 }
 
-impl FieldElement {
-    pub const ZERO: Self = Self(ResidueType::new(&U448::ZERO));
-    pub const ONE: Self = Self(ResidueType::new(&U448::ONE));
-    pub const MINUS_ONE: Self = Self(ResidueType::new(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffe")));
-    pub const NEG_FOUR_TIMES_TWISTED_D: Self = Self(ResidueType::new(&U448::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000262a8")));
-    pub const EDWARDS_D: Self = Self(ResidueType::new(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffff6756")));
-    pub const NEG_EDWARDS_D: Self = Self(ResidueType::new(&U448::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000098a9")));
-    pub const TWISTED_D: Self = Self(ResidueType::new(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffff6755")));
-    pub const TWO_TIMES_TWISTED_D: Self = Self(ResidueType::new(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffeceab")));
-    pub const DECAF_FACTOR: Self = Self(ResidueType::new(&U448::from_be_hex("22d962fbeb24f7683bf68d722fa26aa0a1f1a7b8a5b8d54b64a2d780968c14ba839a66f4fd6eded260337bf6aa20ce529642ef0f45572736")));
-    pub const A_PLUS_TWO_OVER_FOUR: Self = Self(ResidueType::new(&U448::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000098aa")));
-    pub const J: Self = Self(ResidueType::new(&U448::from_u64(156326)));
-    pub const Z: Self = Self(ResidueType::new(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffe")));
+impl Sum for FieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(FieldElement::ZERO, |acc, e| acc + e)
+    }
+}
+
+impl<'a> Sum<&'a FieldElement> for FieldElement {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(FieldElement::ZERO, |acc, e| acc + e)
+    }
+}
+
+impl Product for FieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(FieldElement::ONE, |acc, e| acc * e)
+    }
+}
+
+impl<'a> Product<&'a FieldElement> for FieldElement {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(FieldElement::ONE, |acc, e| acc * e)
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> Self {
+        Self::from_u448(&U448::from_u64(n))
+    }
+}
+
+impl Field for FieldElement {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    #[allow(deprecated)]
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut seed = GenericArray::<u8, U84>::default();
+        rng.fill_bytes(&mut seed);
+        Self::from_okm(&seed)
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        CtOption::new(self.invert(), !self.ct_eq(&Self::ZERO))
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        let root = self.sqrt();
+        CtOption::new(root, root.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldElementBytes;
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let bytes = repr.into();
+        let element = Self::from_bytes(&bytes);
+        CtOption::new(element, element.to_bytes().ct_eq(&bytes))
+    }
 
+    fn to_repr(&self) -> Self::Repr {
+        self.to_bytes().into()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_negative()
+    }
+
+    const MODULUS: &'static str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    const NUM_BITS: u32 = 448;
+    const CAPACITY: u32 = Self::NUM_BITS - 1;
+    const TWO_INV: Self = Self::from_u448(&U448::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffff80000000000000000000000000000000000000000000000000000000"));
+    // p - 1 = 2 (2^223 - 1)(2^224 + 1), and 7 has none of the quotients as its order
+    const MULTIPLICATIVE_GENERATOR: Self = Self::from_u448(&U448::from_u8(7));
+    const S: u32 = 1;
+    const ROOT_OF_UNITY: Self = Self::MINUS_ONE;
+    const ROOT_OF_UNITY_INV: Self = Self::MINUS_ONE;
+    const DELTA: Self = Self::from_u448(&U448::from_u8(49));
+}
+
+impl FieldElement {
+    /// The additive identity
+    pub const ZERO: Self = Self::from_u448(&U448::ZERO);
+    /// The multiplicative identity
+    pub const ONE: Self = Self::from_u448(&U448::ONE);
+    /// `p - 1`
+    pub const MINUS_ONE: Self = Self::from_u448(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffe"));
+    pub(crate) const NEG_FOUR_TIMES_TWISTED_D: Self = Self::from_u448(&U448::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000262a8"));
+    pub(crate) const EDWARDS_D: Self = Self::from_u448(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffff6756"));
+    pub(crate) const NEG_EDWARDS_D: Self = Self::from_u448(&U448::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000098a9"));
+    pub(crate) const TWISTED_D: Self = Self::from_u448(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffff6755"));
+    pub(crate) const TWO_TIMES_TWISTED_D: Self = Self::from_u448(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffeceab"));
+    pub(crate) const DECAF_FACTOR: Self = Self::from_u448(&U448::from_be_hex("22d962fbeb24f7683bf68d722fa26aa0a1f1a7b8a5b8d54b64a2d780968c14ba839a66f4fd6eded260337bf6aa20ce529642ef0f45572736"));
+    pub(crate) const A_PLUS_TWO_OVER_FOUR: Self = Self::from_u448(&U448::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000098aa"));
+    pub(crate) const J: Self = Self::from_u448(&U448::from_u64(156326));
+    pub(crate) const ONE_MINUS_TWO_D: Self = Self::from_u448(&U448::from_u64(78163));
+    pub(crate) const SQRT_TWISTED_D: Self = Self::from_u448(&U448::from_be_hex("12fec0c0b25b7a49443b8748734adcac4628c5f656a49f7b424d977051e65ca6f14c065a189aabdeea38888db42b4f0179d2e21836749f46"));
+    pub(crate) const Z: Self = Self::from_u448(&U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffe"));
+
+    /// Whether the canonical encoding is odd, the `sgn0` of RFC 9380
     pub fn is_negative(&self) -> Choice {
         let bytes = self.to_bytes();
         (bytes[0] & 1).into()
     }
 
-    /// Inverts a field element
-    /// Previous chain length: 462, new length 460
+    /// Whether the element is larger than (p-1)/2.
+    /// For those `2x` wraps around the odd modulus, so this is the parity of `2x`
+    pub fn is_upper_half(&self) -> Choice {
+        self.double().is_negative()
+    }
+
+    /// Inverts a field element, zero is mapped to zero
     pub fn invert(&self) -> Self {
-        const INV_EXP: U448 = U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
-        Self(self.0.pow(&INV_EXP))
+        // x^(p - 2) = (x^((p - 3) / 4))^4 x
+        let (isr, _) = self.inverse_square_root();
+        isr.square().square() * self
+    }
+
+    /// Invert every element of `elements` in place with a single inversion,
+    /// using Montgomery's trick. All elements must be non-zero.
+    pub fn batch_invert(elements: &mut [FieldElement]) {
+        // products[i] = elements[0] * ... * elements[i - 1]
+        let mut products = Vec::with_capacity(elements.len());
+        let mut acc = FieldElement::ONE;
+        for e in elements.iter() {
+            products.push(acc);
+            acc *= *e;
+        }
+
+        acc = acc.invert();
+        for (e, product) in elements.iter_mut().zip(products.iter()).rev() {
+            let inverse = acc * *product;
+            acc *= *e;
+            *e = inverse;
+        }
     }
 
+    /// Square this element
     pub fn square(&self) -> Self {
         Self(self.0.square())
     }
@@ -293,28 +428,53 @@ impl FieldElement {
         result
     }
 
+    /// Whether the element is a non-zero square, `x^((p - 1) / 2) == 1`
     pub fn is_square(&self) -> Choice {
-        const IS_SQUARE_EXP: U448 = U448::from_le_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffff7fffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
-        self.0.pow(&IS_SQUARE_EXP).ct_eq(&FieldElement::ONE.0)
+        let (_, is_residue) = self.inverse_square_root();
+        is_residue
     }
 
+    /// A square root of a square element, computed as `x^((p + 1) / 4)`.
+    ///
+    /// For a non-square this is a square root of `-x` instead,
+    /// [`Field::sqrt`] checks the result.
     pub fn sqrt(&self) -> FieldElement {
-        const SQRT_EXP: U448 = U448::from_be_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffffc0000000000000000000000000000000000000000000000000000000");
-        Self(self.0.pow(&SQRT_EXP))
+        // x^((p + 1) / 4) = x^((p - 3) / 4) x
+        let (isr, _) = self.inverse_square_root();
+        isr * self
     }
 
+    /// The canonical little-endian encoding
     pub fn to_bytes(&self) -> [u8; 56] {
+        self.0.to_bytes()
+    }
+
+    /// Decodes 56 little-endian bytes, values of `p` and above are reduced
+    pub const fn from_bytes(bytes: &[u8; 56]) -> Self {
+        Self(Limbs::from_bytes(bytes))
+    }
+
+    /// The element for an integer below `2^448`
+    const fn from_u448(n: &U448) -> Self {
+        const WORD_BYTES: usize = Word::BITS as usize / 8;
+        let words = n.as_words();
         let mut bytes = [0u8; 56];
-        bytes.copy_from_slice(&self.0.retrieve().to_le_bytes()[..56]);
-        bytes
+        let mut i = 0;
+        while i < 56 {
+            bytes[i] = (words[i / WORD_BYTES] >> (8 * (i % WORD_BYTES))) as u8;
+            i += 1;
+        }
+        Self::from_bytes(&bytes)
     }
 
-    pub fn from_bytes(bytes: &[u8; 56]) -> Self {
-        Self(ResidueType::new(&U448::from_le_slice(bytes)))
+    /// The canonical representative as an integer
+    fn to_u448(self) -> U448 {
+        U448::from_le_slice(&self.to_bytes())
     }
 
+    /// Double this element
     pub fn double(&self) -> Self {
-        Self(self.0.add(&self.0))
+        self + self
     }
 
     /// Computes the inverse square root of a field element
@@ -355,7 +515,7 @@ impl FieldElement {
     }
 
     /// Computes the square root ratio of two elements
-    pub(crate) fn sqrt_ratio(u: &FieldElement, v: &FieldElement) -> (FieldElement, Choice) {
+    pub(crate) fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (FieldElement, Choice) {
         // Compute sqrt(1/(uv))
         let x = *u * v;
         let (inv_sqrt_x, is_res) = x.inverse_square_root();
@@ -459,6 +619,85 @@ mod tests {
         assert_eq!(m1, FieldElement::MINUS_ONE);
     }
 
+    #[test]
+    fn matches_modular_arithmetic() {
+        use elliptic_curve::bigint::{impl_modulus, modular::constant_mod::Residue};
+        use rand_core::{OsRng, RngCore};
+
+        impl_modulus!(Modulus, U448, "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        type Reference = Residue<Modulus, { U448::LIMBS }>;
+        let reference = |x: &FieldElement| Reference::new(&x.to_u448());
+
+        let mut bytes = [0u8; 56];
+        let mut samples = vec![
+            FieldElement::ZERO,
+            FieldElement::MINUS_ONE,
+            FieldElement::from_bytes(&[0xff; 56]),
+        ];
+        for _ in 0..32 {
+            OsRng.fill_bytes(&mut bytes);
+            samples.push(FieldElement::from_bytes(&bytes));
+        }
+        // A sum of reduced elements, which some backends leave unreduced
+        let mut sum = FieldElement::MINUS_ONE;
+        for _ in 0..6 {
+            sum += FieldElement::MINUS_ONE;
+        }
+        samples.push(sum);
+
+        for a in &samples {
+            assert_eq!(FieldElement::from_bytes(&a.to_bytes()), *a);
+            assert_eq!(reference(&a.square()), reference(a).square());
+            assert_eq!(reference(&-a), -reference(a));
+            for b in &samples {
+                assert_eq!(reference(&(a * b)), reference(a) * reference(b));
+                assert_eq!(reference(&(a + b)), reference(a) + reference(b));
+                assert_eq!(reference(&(a - b)), reference(a) - reference(b));
+            }
+        }
+
+        // 2^448 - 1 = 2^224 mod p is not canonical
+        assert_eq!(
+            FieldElement::from_bytes(&[0xff; 56]).to_u448(),
+            U448::ONE.shl_vartime(224)
+        );
+    }
+
+    #[test]
+    fn prime_field() {
+        use rand_core::OsRng;
+
+        assert_eq!(FieldElement::TWO_INV.double(), FieldElement::ONE);
+        let g = FieldElement::MULTIPLICATIVE_GENERATOR;
+        assert!(bool::from(!g.is_square()));
+        assert_eq!(g.square(), FieldElement::DELTA);
+        assert_eq!(
+            FieldElement::ROOT_OF_UNITY * FieldElement::ROOT_OF_UNITY_INV,
+            FieldElement::ONE
+        );
+        assert_eq!(
+            U448::from_be_hex(FieldElement::MODULUS).to_le_bytes(),
+            FieldElement::MINUS_ONE
+                .to_u448()
+                .wrapping_add(&U448::ONE)
+                .to_le_bytes()
+        );
+        assert_eq!(FieldElement::from(7u64), g);
+
+        let x = FieldElement::random(&mut OsRng);
+        assert_eq!(FieldElement::from_repr(x.to_repr()).unwrap(), x);
+        let root = Field::sqrt(&x.square()).unwrap();
+        assert!(root == x || root == -x);
+        assert!(bool::from(Field::sqrt(&(g * x.square())).is_none()));
+        assert!(bool::from(Field::invert(&FieldElement::ZERO).is_none()));
+        assert_eq!(Field::invert(&x).unwrap() * x, FieldElement::ONE);
+
+        // p itself is not a canonical encoding
+        let mut p = FieldElement::MINUS_ONE.to_repr();
+        p[0] += 1;
+        assert!(bool::from(FieldElement::from_repr(p).is_none()));
+    }
+
     #[test]
     fn sqrt() {
         let nine = FieldElement::from_bytes(&[
@@ -475,4 +714,30 @@ mod tests {
         ]);
         assert_eq!(three, nine.sqrt());
     }
+
+    #[test]
+    fn long_sums() {
+        // A long run of additions must stay usable, checked against n * x
+        let n = 5000u64;
+        for x in [
+            FieldElement::MINUS_ONE,
+            FieldElement::random(&mut rand_core::OsRng),
+        ] {
+            let expected = x * FieldElement::from(n);
+            let sum = core::iter::repeat_n(x, n as usize).sum::<FieldElement>();
+            let sum_ref = [x; 100].iter().sum::<FieldElement>();
+
+            assert_eq!(sum.to_bytes(), expected.to_bytes());
+            assert_eq!(sum_ref, x * FieldElement::from(100u64));
+            assert_eq!(sum - x, x * FieldElement::from(n - 1));
+            assert_eq!(sum * sum, expected.square());
+            assert_eq!(-sum, -expected);
+
+            let mut chain = x;
+            for _ in 1..n {
+                chain += x;
+            }
+            assert_eq!(chain - expected, FieldElement::ZERO);
+        }
+    }
 }
@@ -1,8 +1,12 @@
 use core::iter::{Product, Sum};
-use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use elliptic_curve::{
-    bigint::{Encoding, U704},
+    bigint::{
+        impl_modulus,
+        modular::constant_mod::{Residue, ResidueParams},
+        Encoding, Integer, U448, U704,
+    },
     ff::{helpers, Field},
     generic_array::{
         typenum::{U114, U57, U84, U88},
@@ -13,15 +17,17 @@ use elliptic_curve::{
 };
 use rand_core::{CryptoRng, RngCore};
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption};
 
 use crate::constants;
 
 /// This is the scalar field
 /// size = 4q = 2^446 - 0x8335dc163bb124b65129c96fde933d8d723a70aadc873d6d54a7bb0d
-/// We can therefore use 14 saturated 32-bit limbs
+///
+/// Scalars are kept reduced as a [`U448`], and multiplied in Montgomery form
+/// with `crypto-bigint`'s constant-modulus residues.
 #[derive(Debug, Copy, Clone)]
-pub struct Scalar(pub(crate) [u32; 14]);
+pub struct Scalar(pub(crate) U448);
 
 /// The number of bytes needed to represent the scalar field
 pub type ScalarBytes = GenericArray<u8, U57>;
@@ -30,39 +36,31 @@ pub type WideScalarBytes = GenericArray<u8, U114>;
 
 pub(crate) const MODULUS: Scalar = constants::BASEPOINT_ORDER;
 
-// Montgomomery R^2
-const R2: Scalar = Scalar([
-    0x049b9b60, 0xe3539257, 0xc1b195d9, 0x7af32c4b, 0x88ea1859, 0x0d66de23, 0x5ee4d838, 0xae17cf72,
-    0xa3c47c44, 0x1a9cc14b, 0xe4d070af, 0x2052bcb7, 0xf823b729, 0x3402a939,
-]);
-const R: Scalar = Scalar([
-    0x529eec34, 0x721cf5b5, 0xc8e9c2ab, 0x7a4cf635, 0x44a725bf, 0xeec492d9, 0xcd77058, 0x2, 0, 0,
-    0, 0, 0, 0,
-]);
+impl_modulus!(
+    ScalarModulus,
+    U448,
+    "3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3"
+);
+type ScalarResidue = Residue<ScalarModulus, { U448::LIMBS }>;
+
+/// 2^448 mod ℓ, the Montgomery R
+const R: ScalarResidue = ScalarResidue::new(&ScalarModulus::R);
 
 impl Display for Scalar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let bytes = self.to_bytes_rfc_8032();
-        for b in &bytes {
-            write!(f, "{:02x}", b)?;
-        }
-        Ok(())
+        write!(f, "{:x}", self)
     }
 }
 
 impl ConstantTimeEq for Scalar {
     fn ct_eq(&self, other: &Self) -> Choice {
-        self.to_bytes().ct_eq(&other.to_bytes())
+        self.0.ct_eq(&other.0)
     }
 }
 
 impl ConditionallySelectable for Scalar {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        let mut nums = [0u32; 14];
-        for i in 0..14 {
-            nums[i] = u32::conditional_select(&a.0[i], &b.0[i], choice);
-        }
-        Self(nums)
+        Self(U448::conditional_select(&a.0, &b.0, choice))
     }
 }
 
@@ -76,74 +74,31 @@ impl Eq for Scalar {}
 
 impl From<u8> for Scalar {
     fn from(a: u8) -> Self {
-        Scalar([a as u32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        Scalar(U448::from_u8(a))
     }
 }
 
 impl From<u16> for Scalar {
     fn from(a: u16) -> Self {
-        Scalar([a as u32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        Scalar(U448::from_u16(a))
     }
 }
 
 impl From<u32> for Scalar {
     fn from(a: u32) -> Scalar {
-        Scalar([a, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        Scalar(U448::from_u32(a))
     }
 }
 
 impl From<u64> for Scalar {
     fn from(a: u64) -> Self {
-        Scalar([
-            a as u32,
-            (a >> 32) as u32,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ])
+        Scalar(U448::from_u64(a))
     }
 }
 
 impl From<u128> for Scalar {
     fn from(a: u128) -> Self {
-        Scalar([
-            a as u32,
-            (a >> 32) as u32,
-            (a >> 64) as u32,
-            (a >> 96) as u32,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ])
-    }
-}
-
-impl Index<usize> for Scalar {
-    type Output = u32;
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
-    }
-}
-
-impl IndexMut<usize> for Scalar {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+        Scalar(U448::from_u128(a))
     }
 }
 
@@ -195,8 +150,9 @@ impl Mul<&Scalar> for &Scalar {
     type Output = Scalar;
 
     fn mul(self, rhs: &Scalar) -> Self::Output {
-        let unreduced = montgomery_multiply(self, rhs);
-        montgomery_multiply(&unreduced, &R2)
+        // (a R) b / R = a b, a single reduction once a is in Montgomery form
+        let product = ScalarResidue::new(&self.0) * ScalarResidue::from_montgomery(rhs.0);
+        Scalar(*product.as_montgomery())
     }
 }
 
@@ -239,7 +195,7 @@ impl Sub<&Scalar> for &Scalar {
     type Output = Scalar;
 
     fn sub(self, rhs: &Scalar) -> Self::Output {
-        sub_extra(self, rhs, 0)
+        sub(self, rhs)
     }
 }
 
@@ -247,7 +203,7 @@ impl Sub<&Scalar> for Scalar {
     type Output = Scalar;
 
     fn sub(self, rhs: &Scalar) -> Self::Output {
-        sub_extra(&self, rhs, 0)
+        sub(&self, rhs)
     }
 }
 
@@ -255,7 +211,7 @@ impl Sub<Scalar> for &Scalar {
     type Output = Scalar;
 
     fn sub(self, rhs: Scalar) -> Self::Output {
-        sub_extra(self, &rhs, 0)
+        sub(self, &rhs)
     }
 }
 
@@ -263,7 +219,7 @@ impl Sub<Scalar> for Scalar {
     type Output = Scalar;
 
     fn sub(self, rhs: Scalar) -> Self::Output {
-        sub_extra(&self, &rhs, 0)
+        sub(&self, &rhs)
     }
 }
 
@@ -363,6 +319,13 @@ impl Field for Scalar {
         CtOption::new(self.invert(), !self.ct_eq(&Self::ZERO))
     }
 
+    fn sqrt(&self) -> CtOption<Self> {
+        // ℓ = 3 mod 4, so a square root of x is x^((ℓ + 1) / 4)
+        const EXPONENT: U448 = U448::from_be_hex("0fffffffffffffffffffffffffffffffffffffffffffffffffffffffdf3288fa7113b6d26bb58da4085b309ca37163d548de30a4aad6113d");
+        let root = Scalar(ScalarResidue::new(&self.0).pow(&EXPONENT).retrieve());
+        CtOption::new(root, root.square().ct_eq(self))
+    }
+
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
         helpers::sqrt_ratio_generic(num, div)
     }
@@ -380,27 +343,23 @@ impl PrimeField for Scalar {
     }
 
     fn is_odd(&self) -> Choice {
-        Choice::from((self.0[0] & 1) as u8)
+        self.0.is_odd()
     }
 
     const MODULUS: &'static str = "3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3";
-    const NUM_BITS: u32 = 448;
+    const NUM_BITS: u32 = 446;
     const CAPACITY: u32 = Self::NUM_BITS - 1;
-    const TWO_INV: Self = Self([
-        0x55ac227a, 0x91bc6149, 0x46e2c7aa, 0x10b66139, 0xd76b1b48, 0xe2276da4, 0xbe6511f4,
-        0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x1fffffff,
-    ]);
-    const MULTIPLICATIVE_GENERATOR: Self = Self([7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const TWO_INV: Self = Self(U448::from_be_hex(
+        "1fffffffffffffffffffffffffffffffffffffffffffffffffffffffbe6511f4e2276da4d76b1b4810b6613946e2c7aa91bc614955ac227a",
+    ));
+    const MULTIPLICATIVE_GENERATOR: Self = Self(U448::from_u8(7));
+    // ℓ - 1 is twice an odd number, so the only non-trivial root of unity of 2-power order is -1
     const S: u32 = 1;
-    const ROOT_OF_UNITY: Self = Self([
-        0xbdc63ffe, 0xa3a90a90, 0x8330209f, 0x8990470e, 0x3f5c425d, 0x63ea6e70, 0xc2efc65d,
-        0xf9b5c014, 0x1d478bc5, 0xb26d8405, 0x59cd81e2, 0x5a1f2fb9, 0x838ee01b, 0x2935b8ce,
-    ]);
-    const ROOT_OF_UNITY_INV: Self = Self([
-        0x284d98cd, 0x37bc82e8, 0x2e8ae84c, 0xe42ddad7, 0xaea94041, 0x1a21435e, 0x1b644703,
-        0x2c07bf6c, 0x330b2d96, 0x1f0163dc, 0x8b6172cd, 0x8925b1ee, 0x6717df40, 0x1f87f25a,
-    ]);
-    const DELTA: Self = Self([0x961, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const ROOT_OF_UNITY: Self = Self(U448::from_be_hex(
+        "3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f2",
+    ));
+    const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY;
+    const DELTA: Self = Self(U448::from_u8(49));
 }
 
 impl From<Scalar> for Vec<u8> {
@@ -474,7 +433,7 @@ impl serde::Serialize for Scalar {
 
         let bytes = self.to_bytes_rfc_8032();
         if s.is_human_readable() {
-            hex::encode(bytes).serialize(s)
+            crate::encoding::hex_encode(&bytes).serialize(s)
         } else {
             let mut tupler = s.serialize_tuple(bytes.len())?;
             for i in &bytes {
@@ -493,8 +452,9 @@ impl<'de> serde::Deserialize<'de> for Scalar {
     {
         if d.is_human_readable() {
             let hex_s = String::deserialize(d)?;
-            let bytes =
-                hex::decode(hex_s).map_err(|_| serde::de::Error::custom("invalid hex string"))?;
+            let mut bytes = ScalarBytes::default();
+            crate::encoding::hex_decode_into(&hex_s, &mut bytes)
+                .map_err(serde::de::Error::custom)?;
             Scalar::try_from(&bytes[..]).map_err(serde::de::Error::custom)
         } else {
             use serde::de::{SeqAccess, Visitor};
@@ -533,20 +493,32 @@ impl zeroize::DefaultIsZeroes for Scalar {}
 impl core::fmt::LowerHex for Scalar {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let tmp = self.to_bytes_rfc_8032();
-        for &b in tmp.iter() {
-            write!(f, "{:02x}", b)?;
-        }
-        Ok(())
+        let mut buf = [0u8; 114];
+        let s = base16ct::lower::encode_str(&tmp, &mut buf).map_err(|_| core::fmt::Error)?;
+        f.write_str(s)
     }
 }
 
 impl core::fmt::UpperHex for Scalar {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let tmp = self.to_bytes_rfc_8032();
-        for &b in tmp.iter() {
-            write!(f, "{:02X}", b)?;
-        }
-        Ok(())
+        let mut buf = [0u8; 114];
+        let s = base16ct::upper::encode_str(&tmp, &mut buf).map_err(|_| core::fmt::Error)?;
+        f.write_str(s)
+    }
+}
+
+/// Parses the hex of the canonical 57-byte encoding, as printed by [`Display`], in constant time
+impl core::str::FromStr for Scalar {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = ScalarBytes::default();
+        crate::encoding::hex_decode_into(s, &mut bytes)?;
+        let scalar = Scalar::try_from(&bytes[..]);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut bytes[..]);
+        scalar
     }
 }
 
@@ -566,38 +538,33 @@ impl FromOkm for Scalar {
 }
 
 impl Scalar {
-    pub const ONE: Scalar = Scalar([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// The multiplicative identity
+    pub const ONE: Scalar = Scalar(U448::ONE);
 
-    pub const TWO: Scalar = Scalar([2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// The scalar two
+    pub const TWO: Scalar = Scalar(U448::from_u8(2));
 
-    pub const ZERO: Scalar = Scalar([0; 14]);
+    /// The additive identity
+    pub const ZERO: Scalar = Scalar(U448::ZERO);
 
-    /// Is this scalar equal to zero?
+    /// Is this scalar equal to zero? This runs in constant time.
     pub fn is_zero(&self) -> Choice {
-        let mut res = 0i32;
-        let mut i = 0;
-        while i < 14 {
-            res |= self.0[i] as i32;
-            i += 1;
-        }
-        Choice::from((((res | -res) >> 31) + 1) as u8)
+        self.ct_eq(&Self::ZERO)
     }
 
-    /// Divides a scalar by four without reducing mod p
+    /// Divides a scalar by four, rounding down, without reducing mod p
     /// This is used in the 2-isogeny when mapping points from Ed448-Goldilocks
     /// to Twisted-Goldilocks
-    pub(crate) fn div_by_four(&mut self) {
-        for i in 0..=12 {
-            self.0[i] = (self.0[i + 1] << 30) | (self.0[i] >> 2);
-        }
-        self.0[13] >>= 2
+    pub(crate) fn div_by_four_floor(&mut self) {
+        self.0 = self.0.shr_vartime(2);
     }
     // This method was modified from Curve25519-Dalek codebase. [scalar.rs]
-    // We start with 14 u32s and convert them to 56 u8s.
+    // We start by converting the scalar to 56 u8s.
     // We then use the code copied from Dalek to convert the 56 u8s to radix-16 and re-center the coefficients to be between [-16,16)
     // XXX: We can recode the scalar without converting it to bytes, will refactor this method to use this and check which is faster.
     pub(crate) fn to_radix_16(&self) -> [i8; 113] {
-        let bytes = self.to_bytes();
+        #[allow(unused_mut)]
+        let mut bytes = self.to_bytes();
         let mut output = [0i8; 113];
 
         // Step 1: change radix.
@@ -623,150 +590,164 @@ impl Scalar {
             output[i + 1] += carry;
         }
 
-        output
-    }
-    // XXX: Better if this method returns an array of 448 items
-    pub fn bits(&self) -> Vec<bool> {
-        let mut bits: Vec<bool> = Vec::with_capacity(14 * 32);
-        // We have 14 limbs, each 32 bits
-        // First we iterate each limb
-        for limb in self.0.iter() {
-            // Then we iterate each bit in the limb
-            for j in 0..32 {
-                bits.push(limb & (1 << j) != 0)
-            }
-        }
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut bytes);
 
-        // XXX :We are doing LSB first
-        bits
+        output
     }
+    // This method was modified from Curve25519-Dalek codebase. [scalar.rs]
+    /// Compute the width-`w` non-adjacent form of this scalar.
+    ///
+    /// Every non-zero digit is odd and bounded by 2^(w-1) in absolute value,
+    /// and any window of `w` consecutive digits has at most one non-zero digit.
+    /// The scalar must be reduced, which leaves room for the final carry.
+    ///
+    /// This runs in variable time and must only be used with public scalars.
+    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 448] {
+        debug_assert!((2..=8).contains(&w));
 
-    /// Construct a `Scalar` from a little-endian byte representation.
-    pub fn from_bytes(bytes: &[u8; 56]) -> Scalar {
-        let load7 = |input: &[u8]| -> u64 {
-            (input[0] as u64)
-                | ((input[1] as u64) << 8)
-                | ((input[2] as u64) << 16)
-                | ((input[3] as u64) << 24)
-        };
+        let mut naf = [0i8; 448];
 
-        let mut res = Scalar::ZERO;
-        for i in 0..14 {
-            // Load i'th 32 bytes
-            let out = load7(&bytes[i * 4..]);
-            res[i] = out as u32;
+        // One extra word so windows straddling the top limb can be read
+        let mut x_u64 = [0u64; 8];
+        for (word, bytes) in x_u64.iter_mut().zip(self.to_bytes().chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
         }
 
-        res
-    }
+        let width = 1u64 << w;
+        let window_mask = width - 1;
 
-    /// Convert this `Scalar` to a little-endian byte array.
-    pub fn to_bytes(&self) -> [u8; 56] {
-        let mut res = [0u8; 56];
-
-        for i in 0..14 {
-            let mut l = self.0[i];
-            for j in 0..4 {
-                res[4 * i + j] = l as u8;
-                l >>= 8;
-            }
-        }
-        res
-    }
-
-    /// Square this scalar
-    pub fn square(&self) -> Scalar {
-        montgomery_multiply(self, self)
-    }
+        let mut pos = 0;
+        let mut carry = 0;
+        while pos < 448 {
+            let u64_idx = pos / 64;
+            let bit_idx = pos % 64;
+            let bit_buf = if bit_idx < 64 - w {
+                x_u64[u64_idx] >> bit_idx
+            } else {
+                (x_u64[u64_idx] >> bit_idx) | (x_u64[u64_idx + 1] << (64 - bit_idx))
+            };
 
-    /// Invert this scalar
-    pub fn invert(&self) -> Self {
-        let mut pre_comp = [Scalar::ZERO; 8];
-        let mut result = Scalar::ZERO;
+            let window = carry + (bit_buf & window_mask);
 
-        let scalar_window_bits = 3;
-        let last = (1 << scalar_window_bits) - 1;
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
 
-        // precompute [a^1, a^3,,..]
-        pre_comp[0] = montgomery_multiply(self, &R2);
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i8).wrapping_sub(width as i8);
+            }
 
-        if last > 0 {
-            pre_comp[last] = montgomery_multiply(&pre_comp[0], &pre_comp[0]);
+            pos += w;
         }
 
-        for i in 1..=last {
-            pre_comp[i] = montgomery_multiply(&pre_comp[i - 1], &pre_comp[last])
-        }
+        naf
+    }
 
-        // Sliding window
-        let mut residue: usize = 0;
-        let mut trailing: usize = 0;
-        let mut started: usize = 0;
-
-        // XXX: This can definitely be refactored to be readable
-        let loop_start = -scalar_window_bits as isize;
-        let loop_end = 446 - 1;
-        for i in (loop_start..=loop_end).rev() {
-            if started != 0 {
-                result = result.square()
-            }
+    /// Write this scalar in radix `2^w` with signed digits in `[-2^(w-1), 2^(w-1))`.
+    ///
+    /// The scalar must be reduced, so that the final carry fits in the last digit.
+    pub(crate) fn as_radix_2w(&self, w: usize) -> Vec<i16> {
+        debug_assert!((4..=9).contains(&w));
+
+        // One extra word so windows straddling the top limb can be read
+        let mut x_u64 = [0u64; 8];
+        for (word, bytes) in x_u64.iter_mut().zip(self.to_bytes().chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
 
-            let mut w: u32;
-            if i >= 0 {
-                w = MODULUS[(i / 32) as usize];
+        let radix = 1i16 << w;
+        let window_mask = (1u64 << w) - 1;
+        let digits_count = 446usize.div_ceil(w);
+
+        let mut digits = vec![0i16; digits_count + 1];
+        let mut carry = 0;
+        for (i, digit) in digits.iter_mut().take(digits_count).enumerate() {
+            let pos = i * w;
+            let u64_idx = pos / 64;
+            let bit_idx = pos % 64;
+            let bit_buf = if bit_idx <= 64 - w {
+                x_u64[u64_idx] >> bit_idx
             } else {
-                w = 0;
-            }
+                (x_u64[u64_idx] >> bit_idx) | (x_u64[u64_idx + 1] << (64 - bit_idx))
+            };
 
-            if (0..32).contains(&i) {
-                w -= 2
-            }
+            // Recenter the window, and carry into the next one
+            let coefficient = carry + (bit_buf & window_mask) as i16;
+            carry = (coefficient + radix / 2) >> w;
+            *digit = coefficient - (carry << w);
+        }
+        digits[digits_count] = carry;
 
-            residue = (((residue as u32) << 1) | ((w >> ((i as u32) % 32)) & 1)) as usize;
-            if residue >> scalar_window_bits != 0 {
-                trailing = residue;
-                residue = 0
-            }
+        digits
+    }
 
-            if trailing > 0 && (trailing & ((1 << scalar_window_bits) - 1)) == 0 {
-                if started != 0 {
-                    result = montgomery_multiply(
-                        &result,
-                        &pre_comp[trailing >> (scalar_window_bits + 1)],
-                    )
-                } else {
-                    result = pre_comp[trailing >> (scalar_window_bits + 1)];
-                    started = 1
-                }
-                trailing = 0
+    // XXX: Better if this method returns an array of 448 items
+    pub fn bits(&self) -> Vec<bool> {
+        let mut bits: Vec<bool> = Vec::with_capacity(448);
+        // First we iterate each byte
+        for byte in self.to_bytes().iter() {
+            // Then we iterate each bit in the byte
+            for j in 0..8 {
+                bits.push(byte & (1 << j) != 0)
             }
-            trailing <<= 1
         }
 
-        // de-montgomerize and return result
+        // XXX :We are doing LSB first
+        bits
+    }
 
-        montgomery_multiply(&result, &Scalar::ONE)
+    /// Construct a `Scalar` from a little-endian byte representation.
+    pub fn from_bytes(bytes: &[u8; 56]) -> Scalar {
+        Scalar(U448::from_le_slice(bytes))
     }
 
-    /// Halves a Scalar modulo the prime
-    pub fn halve(&self) -> Self {
-        let mut result = Scalar::ZERO;
+    /// Convert this `Scalar` to a little-endian byte array.
+    pub fn to_bytes(&self) -> [u8; 56] {
+        self.0.to_le_bytes()
+    }
 
-        let mask = 0u32.wrapping_sub(self[0] & 1);
-        let mut chain = 0u64;
+    /// Compute `self * b + c`.
+    ///
+    /// The addend is added to the product while it is still in Montgomery form,
+    /// which saves converting it.
+    pub fn mul_add(&self, b: &Scalar, c: &Scalar) -> Scalar {
+        // (a R) b / R + c, the representation of a b + c
+        let product = ScalarResidue::new(&self.0) * ScalarResidue::from_montgomery(b.0)
+            + ScalarResidue::from_montgomery(c.0);
+        Scalar(*product.as_montgomery())
+    }
 
-        for i in 0..14 {
-            chain += (self[i] as u64) + ((MODULUS[i] & mask) as u64);
-            result[i] = chain as u32;
-            chain >>= 32
-        }
+    /// Square this scalar
+    pub fn square(&self) -> Scalar {
+        self * self
+    }
 
-        for i in 0..13 {
-            result[i] = (result[i] >> 1) | (result[i + 1] << 31);
-        }
-        result[13] = (result[13] >> 1) | ((chain << 31) as u32);
+    /// Invert this scalar in constant time, zero is mapped to zero
+    pub fn invert(&self) -> Self {
+        let (inverse, _) = ScalarResidue::new(&self.0).invert();
+        Scalar(inverse.retrieve())
+    }
 
-        result
+    /// Divides a Scalar by four modulo the prime, i.e. multiplies by 4⁻¹.
+    ///
+    /// When the scalar is a multiple of four as an integer this is exact integer division,
+    /// which is what cofactored equations need to undo a multiplication by the cofactor.
+    pub fn div_by_four(&self) -> Self {
+        self.halve().halve()
+    }
+
+    /// Halves a Scalar modulo the prime, i.e. multiplies by 2⁻¹.
+    pub fn halve(&self) -> Self {
+        // Add ℓ to odd scalars, the sum is even and below 2^447
+        let odd = U448::ZERO.wrapping_sub(&(self.0 & U448::ONE));
+        let sum = self.0.wrapping_add(&(MODULUS.0 & odd));
+        Scalar(sum.shr_vartime(1))
     }
 
     /// Attempt to construct a `Scalar` from a canonical byte representation.
@@ -778,13 +759,11 @@ impl Scalar {
     /// - `None` if `bytes` is not a canonical byte representation.
     pub fn from_canonical_bytes(bytes: &ScalarBytes) -> CtOption<Scalar> {
         // Check that the 10 high bits are not set
-        let is_valid = is_zero(bytes[56]) | is_zero(bytes[55] >> 6);
+        let is_valid = is_zero(bytes[56]) & is_zero(bytes[55] >> 6);
         let bytes: [u8; 56] = core::array::from_fn(|i| bytes[i]);
         let candidate = Scalar::from_bytes(&bytes);
 
-        let reduced = sub_extra(&candidate, &MODULUS, 0);
-
-        CtOption::new(candidate, candidate.ct_eq(&reduced) & is_valid)
+        CtOption::new(candidate, candidate.0.ct_lt(&MODULUS.0) & is_valid)
     }
 
     /// Serialize the scalar into 57 bytes, per RFC 8032.
@@ -795,30 +774,65 @@ impl Scalar {
         bytes
     }
 
+    /// Construct a `Scalar` by reducing a 456-bit little-endian integer, such as
+    /// the pruned secret scalar of RFC 8032, modulo the group order ℓ.
+    ///
+    /// Unlike [`Scalar::from_canonical_bytes`] this accepts every input,
+    /// use that one to decode the `S` half of a signature.
+    pub fn from_bytes_rfc_8032(bytes: &[u8; 57]) -> Scalar {
+        let mut wide = WideScalarBytes::default();
+        wide[..57].copy_from_slice(bytes);
+        Scalar::from_bytes_mod_order_wide(&wide)
+    }
+
+    /// Construct a `Scalar` from an X448 secret key, clamped as in [RFC 7748 § 5]
+    /// with [`clamp_integer`](crate::x448::clamp_integer) and reduced modulo ℓ.
+    ///
+    /// Multiplying a point of order ℓ, such as the X448 base point or the Ed448 generator,
+    /// by this scalar gives the same result as X448. Points with a torsion component differ,
+    /// since reducing modulo ℓ undoes the cofactor clearing, use
+    /// [`MontgomeryPoint::mul_clamped`](crate::MontgomeryPoint::mul_clamped) for those.
+    ///
+    /// [RFC 7748 § 5]: https://www.rfc-editor.org/rfc/rfc7748#section-5
+    pub fn clamp_x448(bytes: &[u8; 56]) -> Scalar {
+        let mut wide = WideScalarBytes::default();
+        wide[..56].copy_from_slice(&crate::x448::clamp_integer(*bytes));
+        let scalar = Scalar::from_bytes_mod_order_wide(&wide);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut wide[..]);
+        scalar
+    }
+
+    /// Attempt to construct a `Scalar` from its canonical 57-byte big-endian
+    /// encoding, as used by some PKCS#11 tokens and HSMs.
+    pub fn from_be_bytes(bytes: &[u8; 57]) -> CtOption<Scalar> {
+        let mut le = ScalarBytes::default();
+        for (l, b) in le.iter_mut().zip(bytes.iter().rev()) {
+            *l = *b;
+        }
+        Scalar::from_canonical_bytes(&le)
+    }
+
+    /// Serialize the scalar into 57 big-endian bytes.
+    /// This is the byte reversal of [`Scalar::to_bytes_rfc_8032`].
+    pub fn to_be_bytes(&self) -> [u8; 57] {
+        let mut bytes = [0u8; 57];
+        bytes[..56].copy_from_slice(&self.to_bytes());
+        bytes.reverse();
+        bytes
+    }
+
     /// Construct a `Scalar` by reducing a 912-bit little-endian integer
     /// modulo the group order ℓ.
     pub fn from_bytes_mod_order_wide(input: &WideScalarBytes) -> Scalar {
-        let lo: [u8; 56] = (&input[..56]).try_into().unwrap();
-        let lo = Scalar::from_bytes(&lo);
-        // montgomery_multiply computes ((a*b)/R) mod ℓ, thus this computes
-        // ((lo*R)/R) = lo mod ℓ
-        let lo = montgomery_multiply(&lo, &R);
-
-        let hi: [u8; 56] = (&input[56..112]).try_into().unwrap();
-        let hi = Scalar::from_bytes(&hi);
-        // ((hi*R^2)/R) = hi * R mod ℓ
-        let hi = montgomery_multiply(&hi, &R2);
-
+        let lo = ScalarResidue::new(&U448::from_le_slice(&input[..56]));
+        let hi = ScalarResidue::new(&U448::from_le_slice(&input[56..112]));
         // There are only two bytes left, build an array with them and pad with zeroes
         let top: [u8; 56] = core::array::from_fn(|i| if i < 2 { input[i + 112] } else { 0 });
-        let top = Scalar::from_bytes(&top);
-        // ((top*R^2)/R) = top * R mod ℓ
-        let top = montgomery_multiply(&top, &R2);
-        // (((top*R)*R^2)/R) = top * R^2 mod ℓ
-        let top = montgomery_multiply(&top, &R2);
+        let top = ScalarResidue::new(&U448::from_le_slice(&top));
 
         // lo + hi*R + top*R^2 mod ℓ is the final result we want
-        add(&lo, &hi).add(top)
+        Scalar((lo + (hi + top * R) * R).retrieve())
     }
 
     /// Return a `Scalar` chosen uniformly at random using a user-provided RNG.
@@ -861,96 +875,14 @@ impl Scalar {
     }
 }
 
-/// Computes a + b mod p
+/// Computes a + b mod ℓ
 pub(crate) fn add(a: &Scalar, b: &Scalar) -> Scalar {
-    // First add the two Scalars together
-    // Since our limbs are saturated, the result of each
-    // limb being added can be a 33-bit integer so we propagate the carry bit
-    let mut result = Scalar::ZERO;
-
-    // a + b
-    let mut chain = 0u64;
-    //XXX: Can unroll all of these for loops. They are mainly just ripple carry/borrow adders.
-    for i in 0..14 {
-        chain += (a[i] as u64) + (b[i] as u64);
-        // Low 32 bits are the results
-        result[i] = chain as u32;
-        // 33rd bit is the carry
-        chain >>= 32;
-    }
-
-    // Now reduce the results
-    sub_extra(&result, &MODULUS, chain as u32)
+    Scalar(a.0.add_mod(&b.0, &MODULUS.0))
 }
 
-/// Compute a - b mod p
-/// Computes a - b and conditionally computes the modulus if the result was negative
-fn sub_extra(a: &Scalar, b: &Scalar, carry: u32) -> Scalar {
-    let mut result = Scalar::ZERO;
-
-    // a - b
-    let mut chain = 0i64;
-    for i in 0..14 {
-        chain += a[i] as i64 - b[i] as i64;
-        // Low 32 bits are the results
-        result[i] = chain as u32;
-        // 33rd bit is the borrow
-        chain >>= 32
-    }
-
-    // if the result of a-b was negative and carry was zero
-    // then borrow will be 0xfff..fff and the modulus will be added conditionally to the result
-    // If the carry was 1 and a-b was not negative, then the borrow will be 0x00000...001 ( this should not happen)
-    // Since the borrow should never be more than 0, the carry should never be more than 1;
-    // XXX: Explain why the case of borrow == 1 should never happen
-    let borrow = chain + (carry as i64);
-
-    chain = 0i64;
-    for i in 0..14 {
-        chain += (result[i] as i64) + ((MODULUS[i] as i64) & borrow);
-        // Low 32 bits are the results
-        result[i] = chain as u32;
-        // 33rd bit is the carry
-        chain >>= 32;
-    }
-
-    result
-}
-
-fn montgomery_multiply(x: &Scalar, y: &Scalar) -> Scalar {
-    const MONTGOMERY_FACTOR: u32 = 0xae918bc5;
-
-    let mut result = Scalar::ZERO;
-    let mut carry = 0u32;
-
-    // (a * b ) + c
-    let mul_add = |a: u32, b: u32, c: u32| -> u64 { ((a as u64) * (b as u64)) + (c as u64) };
-
-    for i in 0..14 {
-        let mut chain = 0u64;
-        for j in 0..14 {
-            chain += mul_add(x[i], y[j], result[j]);
-            result[j] = chain as u32;
-            chain >>= 32;
-        }
-
-        let saved = chain as u32;
-        let multiplicand = result[0].wrapping_mul(MONTGOMERY_FACTOR);
-        chain = 0u64;
-
-        for j in 0..14 {
-            chain += mul_add(multiplicand, MODULUS[j], result[j]);
-            if j > 0 {
-                result[j - 1] = chain as u32;
-            }
-            chain >>= 32;
-        }
-        chain += (saved as u64) + (carry as u64);
-        result[14 - 1] = chain as u32;
-        carry = (chain >> 32) as u32;
-    }
-
-    sub_extra(&result, &MODULUS, carry)
+/// Computes a - b mod ℓ
+fn sub(a: &Scalar, b: &Scalar) -> Scalar {
+    Scalar(a.0.sub_mod(&b.0, &MODULUS.0))
 }
 
 fn is_zero(b: u8) -> Choice {
@@ -988,43 +920,47 @@ mod test {
 
     #[test]
     fn test_mul() {
-        let a = Scalar([
-            0xffb823a3, 0xc96a3c35, 0x7f8ed27d, 0x087b8fb9, 0x1d9ac30a, 0x74d65764, 0xc0be082e,
-            0xa8cb0ae8, 0xa8fa552b, 0x2aae8688, 0x2c3dc273, 0x47cf8cac, 0x3b089f07, 0x1e63e807,
-        ]);
+        let a = Scalar(U448::from_be_hex(
+        "1e63e8073b089f0747cf8cac2c3dc2732aae8688a8fa552ba8cb0ae8c0be082e74d657641d9ac30a087b8fb97f8ed27dc96a3c35ffb823a3",
+    ));
 
-        let b = Scalar([
-            0xd8bedc42, 0x686eb329, 0xe416b899, 0x17aa6d9b, 0x1e30b38b, 0x188c6b1a, 0xd099595b,
-            0xbc343bcb, 0x1adaa0e7, 0x24e8d499, 0x8e59b308, 0x0a92de2d, 0xcae1cb68, 0x16c5450a,
-        ]);
+        let b = Scalar(U448::from_be_hex(
+        "16c5450acae1cb680a92de2d8e59b30824e8d4991adaa0e7bc343bcbd099595b188c6b1a1e30b38b17aa6d9be416b899686eb329d8bedc42",
+    ));
 
-        let exp = Scalar([
-            0xa18d010a, 0x1f5b3197, 0x994c9c2b, 0x6abd26f5, 0x08a3a0e4, 0x36a14920, 0x74e9335f,
-            0x07bcd931, 0xf2d89c1e, 0xb9036ff6, 0x203d424b, 0xfccd61b3, 0x4ca389ed, 0x31e055c1,
-        ]);
+        let exp = Scalar(U448::from_be_hex(
+        "31e055c14ca389edfccd61b3203d424bb9036ff6f2d89c1e07bcd93174e9335f36a1492008a3a0e46abd26f5994c9c2b1f5b3197a18d010a",
+    ));
 
         assert_eq!(a * b, exp)
     }
     #[test]
-    fn test_basic_square() {
-        let a = Scalar([
-            0xcf5fac3d, 0x7e56a34b, 0xf640922b, 0x3fa50692, 0x1370f8b8, 0x6f08f331, 0x8dccc486,
-            0x4bb395e0, 0xf22c6951, 0x21cc3078, 0xd2391f9d, 0x930392e5, 0x04b3273b, 0x31620816,
-        ]);
-        let expected_a_squared = Scalar([
-            0x15598f62, 0xb9b1ed71, 0x52fcd042, 0x862a9f10, 0x1e8a309f, 0x9988f8e0, 0xa22347d7,
-            0xe9ab2c22, 0x38363f74, 0xfd7c58aa, 0xc49a1433, 0xd9a6c4c3, 0x75d3395e, 0x0d79f6e3,
-        ]);
-
-        assert_eq!(a.square(), expected_a_squared)
+    fn test_mul_add() {
+        let mut rng = rand_core::OsRng;
+        for _ in 0..32 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let c = Scalar::random(&mut rng);
+            assert_eq!(a.mul_add(&b, &c), a * b + c);
+        }
+        let m = -Scalar::ONE;
+        assert_eq!(m.mul_add(&m, &m), Scalar::ZERO);
+        assert_eq!(Scalar::ZERO.mul_add(&m, &m), m);
     }
 
     #[test]
-    fn test_sanity_check_index_mut() {
-        let mut x = Scalar::ONE;
-        x[0] = 2u32;
-        assert_eq!(x, Scalar::from(2u8))
+    fn test_basic_square() {
+        let a = Scalar(U448::from_be_hex(
+        "3162081604b3273b930392e5d2391f9d21cc3078f22c69514bb395e08dccc4866f08f3311370f8b83fa50692f640922b7e56a34bcf5fac3d",
+    ));
+        let expected_a_squared = Scalar(U448::from_be_hex(
+        "1c1e32fc66b21c9c42d6e8e20487193cf6d49916421b290098f30de3713006cfe8ee9d21eeef7427f82a1fe036630c74b9acc2c2ede40f04",
+    ));
+
+        assert_eq!(a.square(), expected_a_squared);
+        assert_eq!(a.square(), a * a)
     }
+
     #[test]
     fn test_basic_halving() {
         let eight = Scalar::from(8u8);
@@ -1041,7 +977,99 @@ mod test {
         let b = Scalar::from(5u8);
         let c = Scalar::from(10u8);
         assert!(a == b);
-        assert!(!(a == c))
+        assert!(a != c)
+    }
+
+    #[test]
+    fn test_from_bytes_rfc_8032() {
+        let s = Scalar::random(&mut rand_core::OsRng);
+        let bytes: [u8; 57] = s.to_bytes_rfc_8032().into();
+        assert_eq!(Scalar::from_bytes_rfc_8032(&bytes), s);
+
+        let order: [u8; 57] = MODULUS.to_bytes_rfc_8032().into();
+        assert_eq!(Scalar::from_bytes_rfc_8032(&order), Scalar::ZERO);
+
+        // The 57th byte is part of the value, 2^448 = (2^224)^2
+        let mut top = [0u8; 57];
+        top[56] = 1;
+        let mut two_224 = [0u8; 56];
+        two_224[28] = 1;
+        let two_224 = Scalar::from_bytes(&two_224);
+        assert_eq!(Scalar::from_bytes_rfc_8032(&top), two_224 * two_224);
+    }
+
+    #[test]
+    fn test_clamp_x448() {
+        // Alice's key from RFC 7748 § 6.2
+        let k = hex!("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b");
+        let public = hex!("9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0");
+        let s = Scalar::clamp_x448(&k);
+        assert_eq!((crate::MontgomeryPoint::generator() * s).0, public);
+
+        // Only the clamped bits matter
+        let mut other = k;
+        other[0] |= 3;
+        other[55] &= 0x7f;
+        assert_eq!(Scalar::clamp_x448(&other), s);
+    }
+
+    #[test]
+    fn test_div_by_four() {
+        let four = Scalar::from(4u8);
+        assert_eq!(Scalar::from(28u8).div_by_four(), Scalar::from(7u8));
+        for _ in 0..8 {
+            let s = Scalar::random(&mut rand_core::OsRng);
+            assert_eq!((s * four).div_by_four(), s);
+            assert_eq!(s.div_by_four() * four, s);
+        }
+
+        let mut floor = Scalar::from(31u8);
+        floor.div_by_four_floor();
+        assert_eq!(floor, Scalar::from(7u8));
+    }
+
+    #[test]
+    fn prime_field_constants() {
+        let two = Scalar::ONE.double();
+        assert_eq!(Scalar::TWO_INV * two, Scalar::ONE);
+
+        // The generator is a non-residue, and the root of unity is its odd part
+        let g = Scalar::MULTIPLICATIVE_GENERATOR;
+        assert!(bool::from(g.sqrt().is_none()));
+        let t = MODULUS.0.shr_vartime(Scalar::S as usize);
+        let g_t = ScalarResidue::new(&g.0).pow(&t).retrieve();
+        assert_eq!(Scalar(g_t), Scalar::ROOT_OF_UNITY);
+        assert_eq!(Scalar::ROOT_OF_UNITY, -Scalar::ONE);
+        assert_eq!(
+            Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV,
+            Scalar::ONE
+        );
+        assert_eq!(g.square(), Scalar::DELTA);
+
+        // The modulus has NUM_BITS bits
+        assert_eq!(MODULUS.0.bits(), Scalar::NUM_BITS as usize);
+        assert_eq!(
+            U448::from_be_hex(Scalar::MODULUS).to_le_bytes()[..],
+            MODULUS.0.to_le_bytes()[..]
+        );
+
+        let s = Scalar::random(&mut rand_core::OsRng);
+        assert_eq!(Scalar::from_repr(s.to_repr()).unwrap(), s);
+        let root = s.square().sqrt().unwrap();
+        assert!(root == s || root == -s);
+    }
+
+    #[test]
+    fn test_is_zero() {
+        assert_eq!(Scalar::ZERO.is_zero().unwrap_u8(), 1);
+        assert_eq!(Scalar::ONE.is_zero().unwrap_u8(), 0);
+        assert_eq!((Scalar::ONE - Scalar::ONE).is_zero().unwrap_u8(), 1);
+
+        // A top bit in a limb used to overflow the sign trick
+        let high = Scalar::from(1u128 << 127);
+        assert_eq!(high.is_zero().unwrap_u8(), 0);
+        assert_eq!(high.ct_eq(&high).unwrap_u8(), 1);
+        assert_eq!(high.ct_eq(&Scalar::ZERO).unwrap_u8(), 0);
     }
 
     #[test]
@@ -1053,6 +1081,11 @@ mod test {
             assert_eq!(x_inv * x, Scalar::ONE)
         }
 
+        for _ in 0..8 {
+            let x = Scalar::random(&mut rand_core::OsRng);
+            assert_eq!(x.invert() * x, Scalar::ONE)
+        }
+
         // Inversion of zero is zero
         let zero = Scalar::ZERO;
         let expected_zero = zero.invert();
@@ -1060,18 +1093,17 @@ mod test {
     }
     #[test]
     fn test_serialise() {
-        let scalar = Scalar([
-            0x15598f62, 0xb9b1ed71, 0x52fcd042, 0x862a9f10, 0x1e8a309f, 0x9988f8e0, 0xa22347d7,
-            0xe9ab2c22, 0x38363f74, 0xfd7c58aa, 0xc49a1433, 0xd9a6c4c3, 0x75d3395e, 0x0d79f6e3,
-        ]);
+        let scalar = Scalar(U448::from_be_hex(
+        "0d79f6e375d3395ed9a6c4c3c49a1433fd7c58aa38363f74e9ab2c22a22347d79988f8e01e8a309f862a9f1052fcd042b9b1ed7115598f62",
+    ));
         let got = Scalar::from_bytes(&scalar.to_bytes());
         assert_eq!(scalar, got)
     }
     #[test]
     fn test_debug() {
-        let k = Scalar([
-            200, 210, 250, 145, 130, 180, 147, 122, 222, 230, 214, 247, 203, 32,
-        ]);
+        let k = Scalar(U448::from_be_hex(
+        "00000020000000cb000000f7000000d6000000e6000000de0000007a00000093000000b40000008200000091000000fa000000d2000000c8",
+    ));
         let s = k;
         dbg!(&s.to_radix_16()[..]);
     }
@@ -1097,6 +1129,11 @@ mod test {
             Some(s) => assert_eq!(s, Scalar::ZERO - Scalar::ONE),
             None => panic!("should not return None"),
         };
+
+        // n-1 with a bit set in the 57th byte should fail
+        bytes[56] = 1;
+        let s = Scalar::from_canonical_bytes(&bytes);
+        assert!(<Choice as Into<bool>>::into(s.is_none()));
     }
 
     #[test]
@@ -1129,6 +1166,84 @@ mod test {
         assert_eq!(s, reduced);
     }
 
+    #[test]
+    fn test_non_adjacent_form() {
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::from(0xdeadbeefu32),
+            -Scalar::from(0x1234567890abcdefu64),
+        ];
+        for x in scalars.iter() {
+            for w in 2..=8 {
+                let naf = x.non_adjacent_form(w);
+                let mut y = Scalar::ZERO;
+                for digit in naf.iter().rev() {
+                    y += y;
+                    if *digit > 0 {
+                        y += Scalar::from(*digit as u8);
+                    } else if *digit < 0 {
+                        y -= Scalar::from(digit.unsigned_abs());
+                    }
+                }
+                assert_eq!(*x, y);
+
+                for (i, digit) in naf.iter().enumerate() {
+                    if *digit != 0 {
+                        assert_eq!(digit & 1, 1);
+                        assert!(digit.unsigned_abs() < 1 << (w - 1));
+                        assert!(naf[i + 1..(i + w).min(448)].iter().all(|d| *d == 0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix_2w() {
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::from(0xdeadbeefu32),
+            -Scalar::from(0x1234567890abcdefu64),
+        ];
+        for x in scalars.iter() {
+            for w in 4..=9 {
+                let digits = x.as_radix_2w(w);
+                let radix = Scalar::from(1u16 << w);
+                let mut y = Scalar::ZERO;
+                for digit in digits.iter().rev() {
+                    assert!((-(1 << (w - 1))..1 << (w - 1)).contains(digit));
+                    y *= radix;
+                    if *digit > 0 {
+                        y += Scalar::from(*digit as u16);
+                    } else {
+                        y -= Scalar::from(digit.unsigned_abs());
+                    }
+                }
+                assert_eq!(*x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_be_bytes() {
+        let x = Scalar::from(0x0102030405060708u64);
+        let be = x.to_be_bytes();
+        let mut le = x.to_bytes_rfc_8032();
+        le.reverse();
+        assert_eq!(&be[..], &le[..]);
+        assert_eq!(be[49..], [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(Scalar::from_be_bytes(&be).unwrap(), x);
+
+        let mut modulus = MODULUS.to_bytes_rfc_8032();
+        modulus.reverse();
+        let modulus: [u8; 57] = modulus.into();
+        assert!(bool::from(Scalar::from_be_bytes(&modulus).is_none()));
+    }
+
     #[test]
     fn test_to_bytes_rfc8032() {
         // n-1
@@ -1139,6 +1254,21 @@ mod test {
         assert_eq!(&bytes[..], &candidate[..]);
     }
 
+    #[test]
+    fn hex_round_trip() {
+        let s = Scalar::random(&mut rand_core::OsRng);
+        let hex = s.to_string();
+        assert_eq!(hex, format!("{:x}", s));
+        assert_eq!(hex.parse::<Scalar>().unwrap(), s);
+        assert_eq!(format!("{:X}", s).parse::<Scalar>().unwrap(), s);
+
+        // Wrong length, not hex, and ℓ itself
+        assert!(hex[2..].parse::<Scalar>().is_err());
+        assert!("zz".repeat(57).parse::<Scalar>().is_err());
+        let order = constants::BASEPOINT_ORDER.to_string();
+        assert!(order.parse::<Scalar>().is_err());
+    }
+
     #[test]
     fn serde() {
         let res = serde_json::to_string(&Scalar::TWO_INV);
@@ -0,0 +1,314 @@
+//! JSON Web Key encoding of keys, as specified for Octet Key Pairs in [RFC 8037].
+//!
+//! A key is `{"kty":"OKP","crv":"Ed448","x":...}` where `x` is the unpadded
+//! base64url encoding of the public key, and a private key adds `d`, the
+//! encoding of the secret seed. X448 keys have `"crv":"X448"`. With the `serde`
+//! feature [`Jwk`] serializes to and from exactly that object, so it plugs into
+//! any JOSE library that takes a JSON value. The base64url codec is the
+//! constant-time one from [`crate::encoding`].
+//!
+//! [RFC 8037]: https://www.rfc-editor.org/rfc/rfc8037
+use crate::encoding::{base64url_decode_into, base64url_encode};
+use crate::x448::{self, KEY_LENGTH};
+use crate::{SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH};
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// The key type of every key here, an Octet Key Pair
+pub(crate) const KEY_TYPE_OKP: &str = "OKP";
+/// The curve name of Ed448 keys
+pub(crate) const CURVE_ED448: &str = "Ed448";
+/// The curve name of X448 keys
+pub(crate) const CURVE_X448: &str = "X448";
+
+/// An Octet Key Pair JSON Web Key.
+///
+/// Other members such as `kid` or `use` are ignored on deserialization.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jwk {
+    /// The key type, always `OKP`
+    pub kty: String,
+    /// The curve name
+    pub crv: String,
+    /// The base64url encoded public key
+    pub x: String,
+    /// The base64url encoded private key, if this is a private key
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub d: Option<String>,
+}
+
+impl Debug for Jwk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.d);
+    }
+}
+
+/// Errors produced when converting a [`Jwk`] to a key
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum JwkError {
+    /// `kty` is not `OKP`
+    UnsupportedKeyType,
+    /// `crv` is not the curve of the requested key type
+    UnsupportedCurve,
+    /// `x` or `d` is not unpadded base64url of the right length
+    InvalidEncoding,
+    /// `x` is not a valid public key, or does not match `d`
+    InvalidKey,
+    /// A private key was requested but `d` is absent
+    MissingPrivateKey,
+}
+
+impl Display for JwkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JwkError::UnsupportedKeyType => write!(f, "key type is not OKP"),
+            JwkError::UnsupportedCurve => write!(f, "curve is not supported"),
+            JwkError::InvalidEncoding => write!(f, "key encoding is invalid"),
+            JwkError::InvalidKey => write!(f, "key is invalid"),
+            JwkError::MissingPrivateKey => write!(f, "private key is missing"),
+        }
+    }
+}
+
+impl std::error::Error for JwkError {}
+
+impl Jwk {
+    /// Check `kty` and `crv`, and decode `x` into `N` bytes
+    fn public_bytes<const N: usize>(&self, crv: &str) -> Result<[u8; N], JwkError> {
+        if self.kty != KEY_TYPE_OKP {
+            return Err(JwkError::UnsupportedKeyType);
+        }
+        if self.crv != crv {
+            return Err(JwkError::UnsupportedCurve);
+        }
+        decode(&self.x)
+    }
+
+    /// Decode `d` into `N` bytes
+    fn private_bytes<const N: usize>(&self) -> Result<[u8; N], JwkError> {
+        decode(self.d.as_ref().ok_or(JwkError::MissingPrivateKey)?)
+    }
+}
+
+fn decode<const N: usize>(s: &str) -> Result<[u8; N], JwkError> {
+    let mut bytes = [0u8; N];
+    base64url_decode_into(s, &mut bytes).map_err(|_| JwkError::InvalidEncoding)?;
+    Ok(bytes)
+}
+
+impl From<&VerifyingKey> for Jwk {
+    fn from(key: &VerifyingKey) -> Self {
+        Self {
+            kty: KEY_TYPE_OKP.to_string(),
+            crv: CURVE_ED448.to_string(),
+            x: base64url_encode(key.as_bytes()),
+            d: None,
+        }
+    }
+}
+
+impl From<&SigningKey> for Jwk {
+    fn from(key: &SigningKey) -> Self {
+        let mut jwk = Self::from(&key.verifying_key);
+        jwk.d = Some(base64url_encode(key.as_bytes()));
+        jwk
+    }
+}
+
+/// Accepts private keys too, only `x` is read.
+impl TryFrom<&Jwk> for VerifyingKey {
+    type Error = JwkError;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let x = jwk.public_bytes::<PUBLIC_KEY_LENGTH>(CURVE_ED448)?;
+        VerifyingKey::from_bytes(&x).map_err(|_| JwkError::InvalidKey)
+    }
+}
+
+/// `x` must be the public key of `d`.
+impl TryFrom<&Jwk> for SigningKey {
+    type Error = JwkError;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let x = jwk.public_bytes::<PUBLIC_KEY_LENGTH>(CURVE_ED448)?;
+        #[allow(unused_mut)]
+        let mut d = jwk.private_bytes::<SECRET_KEY_LENGTH>()?;
+        let key = SigningKey::from_bytes(&d);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut d);
+        if key.verifying_key.as_bytes() != &x {
+            return Err(JwkError::InvalidKey);
+        }
+        Ok(key)
+    }
+}
+
+impl From<&x448::PublicKey> for Jwk {
+    fn from(key: &x448::PublicKey) -> Self {
+        Self {
+            kty: KEY_TYPE_OKP.to_string(),
+            crv: CURVE_X448.to_string(),
+            x: base64url_encode(key.as_bytes()),
+            d: None,
+        }
+    }
+}
+
+impl From<&x448::StaticSecret> for Jwk {
+    fn from(secret: &x448::StaticSecret) -> Self {
+        let mut jwk = Self::from(&x448::PublicKey::from(secret));
+        jwk.d = Some(base64url_encode(secret.as_bytes()));
+        jwk
+    }
+}
+
+/// Accepts private keys too, only `x` is read.
+impl TryFrom<&Jwk> for x448::PublicKey {
+    type Error = JwkError;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        Ok(jwk.public_bytes::<KEY_LENGTH>(CURVE_X448)?.into())
+    }
+}
+
+/// `x` must be the public key of `d`.
+impl TryFrom<&Jwk> for x448::StaticSecret {
+    type Error = JwkError;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let x = jwk.public_bytes::<KEY_LENGTH>(CURVE_X448)?;
+        #[allow(unused_mut)]
+        let mut d = jwk.private_bytes::<KEY_LENGTH>()?;
+        let secret = x448::StaticSecret::from(d);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut d);
+        if x448::PublicKey::from(&secret).as_bytes() != &x {
+            return Err(JwkError::InvalidKey);
+        }
+        Ok(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // RFC 8037 § A has no Ed448 example, this is the first RFC 8032 § 7.4 key
+    const SEED: [u8; 57] = hex!("6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b");
+    const X: &str = "X9dEm1m0Yf0s54fsYWrUah2hNCSFpw4fig6nXYDpZ3jt8SR2m0bHBhvWeD3x5Q9s0foavq_oJWGA";
+    const D: &str = "bIKlYsuAjRDWMr6JyFE-v2ySnzTd-oyfY8mWDvbjSKNSjIo_zC8ETjmj_FuUSS-PAy51SaIAmPlb";
+
+    #[test]
+    fn round_trip() {
+        let key = SigningKey::from_bytes(&SEED);
+        let jwk = Jwk::from(&key);
+        assert_eq!(jwk.kty, "OKP");
+        assert_eq!(jwk.crv, "Ed448");
+        assert_eq!(jwk.x, X);
+        assert_eq!(jwk.d.as_deref(), Some(D));
+        assert_eq!(SigningKey::try_from(&jwk).unwrap(), key);
+        assert_eq!(VerifyingKey::try_from(&jwk).unwrap(), key.verifying_key());
+
+        let public = Jwk::from(&key.verifying_key());
+        assert_eq!(public.d, None);
+        assert_eq!(
+            SigningKey::try_from(&public),
+            Err(JwkError::MissingPrivateKey)
+        );
+        assert!(!format!("{:?}", jwk).contains(D));
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let jwk = Jwk::from(&SigningKey::from_bytes(&SEED));
+
+        let mut bad = jwk.clone();
+        bad.kty = "EC".to_string();
+        assert_eq!(
+            VerifyingKey::try_from(&bad),
+            Err(JwkError::UnsupportedKeyType)
+        );
+
+        let mut bad = jwk.clone();
+        bad.crv = "Ed25519".to_string();
+        assert_eq!(
+            VerifyingKey::try_from(&bad),
+            Err(JwkError::UnsupportedCurve)
+        );
+
+        // Padded, and one byte short
+        let mut bad = jwk.clone();
+        bad.x.push('=');
+        assert_eq!(VerifyingKey::try_from(&bad), Err(JwkError::InvalidEncoding));
+        let mut bad = jwk.clone();
+        bad.x.truncate(72);
+        assert_eq!(VerifyingKey::try_from(&bad), Err(JwkError::InvalidEncoding));
+
+        let mut bad = jwk.clone();
+        bad.d = Some(base64url_encode(&[1u8; 57]));
+        assert_eq!(SigningKey::try_from(&bad), Err(JwkError::InvalidKey));
+    }
+
+    #[test]
+    fn x448() {
+        // Alice's key from RFC 7748 § 6.2
+        let d = hex!("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b");
+        let secret = x448::StaticSecret::from(d);
+        let jwk = Jwk::from(&secret);
+        assert_eq!(jwk.crv, "X448");
+        assert_eq!(
+            jwk.x,
+            "mwj3zDG34-Z9ItWuoSEHSic70rg94Jxj-qc9LCLF2bvINmRyQdlT1AxbEtqIEg1TF3-A5TLEH6A"
+        );
+        let decoded = x448::StaticSecret::try_from(&jwk).unwrap();
+        assert_eq!(decoded.as_bytes(), secret.as_bytes());
+        assert_eq!(
+            x448::PublicKey::try_from(&jwk).unwrap(),
+            x448::PublicKey::from(&secret)
+        );
+
+        // The curves don't mix
+        assert_eq!(
+            VerifyingKey::try_from(&jwk),
+            Err(JwkError::UnsupportedCurve)
+        );
+        let ed448 = Jwk::from(&SigningKey::from_bytes(&SEED));
+        assert_eq!(
+            x448::PublicKey::try_from(&ed448),
+            Err(JwkError::UnsupportedCurve)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let key = SigningKey::from_bytes(&SEED);
+        let json = serde_json::to_string(&Jwk::from(&key.verifying_key())).unwrap();
+        assert_eq!(
+            json,
+            format!(r#"{{"kty":"OKP","crv":"Ed448","x":"{}"}}"#, X)
+        );
+
+        let json = format!(
+            r#"{{"kty":"OKP","crv":"Ed448","kid":"1","x":"{}","d":"{}"}}"#,
+            X, D
+        );
+        let jwk: Jwk = serde_json::from_str(&json).unwrap();
+        assert_eq!(SigningKey::try_from(&jwk).unwrap(), key);
+    }
+}
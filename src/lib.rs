@@ -33,6 +33,25 @@
 //!
 //! [`EdwardsPoint`] implements the [`elliptic_curve::Group`] and [`elliptic_curve::group::GroupEncoding`]
 //! and [`Scalar`] implements [`elliptic_curve::Field`] and [`elliptic_curve::PrimeField`] traits.
+//!
+//! # Serde
+//! With the `serde` feature, scalars, points and their compressed forms serialize to their
+//! canonical encodings, and deserializing rejects invalid or non-canonical bytes.
+//! Human-readable formats such as JSON or TOML get a hex string, binary formats such as
+//! CBOR or bincode get the bytes as a fixed-size tuple with no length prefix.
+#![cfg_attr(feature = "serde", doc = "```")]
+#![cfg_attr(not(feature = "serde"), doc = "```ignore")]
+//! use ed448_goldilocks_plus::{EdwardsPoint, Scalar};
+//!
+//! let point = EdwardsPoint::GENERATOR * Scalar::from(3u8);
+//! let hex = hex::encode(point.compress().to_bytes());
+//! assert_eq!(serde_json::to_string(&point).unwrap(), format!("\"{hex}\""));
+//!
+//! let mut cbor = Vec::new();
+//! ciborium::into_writer(&point, &mut cbor).unwrap();
+//! assert_eq!(ciborium::from_reader::<EdwardsPoint, _>(&cbor[..]).unwrap(), point);
+//! assert_eq!(serde_bare::to_vec(&point).unwrap().len(), 57);
+//! ```
 // XXX: Change this to deny later on
 #![warn(unused_attributes, unused_imports, unused_mut, unused_must_use)]
 #![allow(non_snake_case)]
@@ -41,23 +60,58 @@
 #[macro_use]
 pub(crate) mod macros;
 
+pub use crypto_signature as signature;
 pub use elliptic_curve;
+#[cfg(feature = "pkcs8")]
+pub use pkcs8;
 pub use rand_core;
 pub use sha3;
 pub use subtle;
 
 // As usual, we will use this file to carefully define the API/ what we expose to the user
+pub(crate) mod blinding;
 pub(crate) mod constants;
+#[cfg(feature = "cose")]
+pub(crate) mod cose;
 pub(crate) mod curve;
 pub(crate) mod decaf;
+pub(crate) mod ed448;
+pub mod encoding;
 pub(crate) mod field;
+pub(crate) mod jwk;
+pub(crate) mod nonzero;
+#[cfg(feature = "simplest-ot")]
+pub mod ot;
+pub(crate) mod ring;
 pub(crate) mod ristretto;
+pub mod sharing;
+pub(crate) mod sign;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod traits;
+pub mod vartime;
+pub mod x448;
 
 pub(crate) use field::{GOLDILOCKS_BASE_POINT, TWISTED_EDWARDS_BASE_POINT};
 
+pub use blinding::Blinder;
+#[cfg(feature = "cose")]
+pub use cose::CoseKeyError;
 pub use curve::{
-    AffinePoint, CompressedEdwardsY, EdwardsPoint, MontgomeryPoint, ProjectiveMontgomeryPoint,
+    AffinePoint, CompressedEdwardsY, CurveModel, EdwardsBasepointTable, EdwardsCombTable,
+    EdwardsPoint, MontgomeryPoint, MontgomeryPointFull, ProjectiveMontgomeryPoint, SignConvention,
+    SubgroupPoint, UncompressedEdwardsBytes,
+};
+pub use decaf::{
+    CompressedDecaf, DecafBasepointTable, DecafCombTable, DecafPoint, LIZARD_DATA_LENGTH,
 };
-pub use decaf::{CompressedDecaf, DecafPoint};
-pub use field::{Scalar, ScalarBytes, WideScalarBytes};
+pub use ed448::Ed448;
+pub use field::{FieldElement, FieldElementBytes, Scalar, ScalarBytes, WideScalarBytes};
+pub use jwk::{Jwk, JwkError};
+pub use nonzero::{NonIdentity, NonZeroScalar};
+pub use ring::{KeyImage, RingSignature, RingSignatureError};
 pub use ristretto::{CompressedRistretto, RistrettoPoint};
+pub use sign::{
+    derive_nonce, verify_batch, PreHasher, Signature, SigningError, SigningKey, VerifyingKey,
+    PREHASH_LENGTH, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH, SIGNATURE_LENGTH,
+};
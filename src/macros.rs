@@ -121,3 +121,50 @@ macro_rules! define_mul_assign_variants {
         }
     };
 }
+
+/// Define zero-copy conversions between byte slices and slices of an encoding type.
+///
+/// The type must be `#[repr(transparent)]` over `[u8; $len]`, so it has the size of
+/// `$len` bytes, alignment one, and every bit pattern is a value of the type.
+macro_rules! define_byte_slice_casts {
+    (TYPE = $ty:ty, LEN = $len:expr) => {
+        // The casts below rely on this layout, so a change to the type fails to compile
+        const _: () =
+            assert!(core::mem::size_of::<$ty>() == $len && core::mem::align_of::<$ty>() == 1);
+
+        impl $ty {
+            /// View a buffer of concatenated encodings as a slice of them without copying,
+            /// or `None` if its length is not a multiple of the encoding length.
+            /// The encodings are not validated.
+            pub fn slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+                if bytes.len() % $len != 0 {
+                    return None;
+                }
+                // SAFETY: the type is a transparent wrapper around `[u8; $len]`, which has
+                // alignment one and no invalid bit patterns, and the length was checked
+                Some(unsafe {
+                    core::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / $len)
+                })
+            }
+
+            /// Like [`Self::slice_from_bytes`], but mutable, to decode or encode in place
+            pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> Option<&mut [Self]> {
+                if bytes.len() % $len != 0 {
+                    return None;
+                }
+                // SAFETY: as in `slice_from_bytes`
+                Some(unsafe {
+                    core::slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), bytes.len() / $len)
+                })
+            }
+
+            /// View a slice of encodings as their concatenated bytes without copying
+            pub fn slice_as_bytes(encodings: &[Self]) -> &[u8] {
+                // SAFETY: the type is a transparent wrapper around `[u8; $len]`
+                unsafe {
+                    core::slice::from_raw_parts(encodings.as_ptr().cast(), encodings.len() * $len)
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,277 @@
+//! Wrappers that rule out the identity point and the zero scalar at the type level.
+//!
+//! Protocols such as Diffie-Hellman or VRFs need a non-zero secret and a public point
+//! other than the identity. Checking once on construction or deserialization means
+//! the rest of the code can take [`NonZeroScalar`] and [`NonIdentity`] instead of
+//! checking again.
+use crate::Scalar;
+use elliptic_curve::group::{prime::PrimeGroup, Group};
+use rand_core::{CryptoRng, RngCore};
+use std::ops::{Deref, Mul, Neg};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// A [`Scalar`] that is not zero
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NonZeroScalar(Scalar);
+
+impl NonZeroScalar {
+    /// Wrap `scalar`, which fails if it is zero
+    pub fn new(scalar: Scalar) -> CtOption<Self> {
+        let is_zero = scalar.is_zero();
+        CtOption::new(Self(scalar), !is_zero)
+    }
+
+    /// Pick a random non-zero scalar
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            let scalar = Self::new(Scalar::random(&mut *rng));
+            if scalar.is_some().into() {
+                return scalar.unwrap();
+            }
+        }
+    }
+
+    /// The multiplicative inverse, which always exists
+    pub fn invert(&self) -> Self {
+        Self(self.0.invert())
+    }
+
+    /// The wrapped scalar
+    pub fn to_scalar(self) -> Scalar {
+        self.0
+    }
+}
+
+impl Deref for NonZeroScalar {
+    type Target = Scalar;
+
+    fn deref(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+impl AsRef<Scalar> for NonZeroScalar {
+    fn as_ref(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+impl From<NonZeroScalar> for Scalar {
+    fn from(scalar: NonZeroScalar) -> Scalar {
+        scalar.0
+    }
+}
+
+impl TryFrom<Scalar> for NonZeroScalar {
+    type Error = &'static str;
+
+    fn try_from(scalar: Scalar) -> Result<Self, Self::Error> {
+        Option::from(Self::new(scalar)).ok_or("scalar is zero")
+    }
+}
+
+impl ConstantTimeEq for NonZeroScalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for NonZeroScalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(Scalar::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Neg for NonZeroScalar {
+    type Output = NonZeroScalar;
+
+    fn neg(self) -> NonZeroScalar {
+        Self(-self.0)
+    }
+}
+
+/// The product of two non-zero elements of a field is not zero
+impl Mul for NonZeroScalar {
+    type Output = NonZeroScalar;
+
+    fn mul(self, other: NonZeroScalar) -> NonZeroScalar {
+        Self(self.0 * other.0)
+    }
+}
+
+/// Zeroizing sets the scalar to one, so that it stays non-zero
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for NonZeroScalar {
+    fn zeroize(&mut self) {
+        self.0 = Scalar::ONE;
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NonZeroScalar {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NonZeroScalar {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let scalar = Scalar::deserialize(d)?;
+        Self::try_from(scalar).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A point of a [`Group`] that is not the identity, such as
+/// `NonIdentity<EdwardsPoint>` or `NonIdentity<DecafPoint>`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NonIdentity<P>(P);
+
+impl<P: Group> NonIdentity<P> {
+    /// Wrap `point`, which fails if it is the identity
+    pub fn new(point: P) -> CtOption<Self> {
+        let is_identity = point.is_identity();
+        CtOption::new(Self(point), !is_identity)
+    }
+
+    /// Pick a random point other than the identity
+    pub fn random(mut rng: impl RngCore) -> Self {
+        loop {
+            let point = Self::new(P::random(&mut rng));
+            if point.is_some().into() {
+                return point.unwrap();
+            }
+        }
+    }
+}
+
+impl<P: Copy> NonIdentity<P> {
+    /// The wrapped point
+    pub fn to_point(self) -> P {
+        self.0
+    }
+}
+
+impl<P> Deref for NonIdentity<P> {
+    type Target = P;
+
+    fn deref(&self) -> &P {
+        &self.0
+    }
+}
+
+impl<P> AsRef<P> for NonIdentity<P> {
+    fn as_ref(&self) -> &P {
+        &self.0
+    }
+}
+
+impl<P: ConstantTimeEq> ConstantTimeEq for NonIdentity<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<P: ConditionallySelectable> ConditionallySelectable for NonIdentity<P> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(P::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<P: Group> Neg for NonIdentity<P> {
+    type Output = NonIdentity<P>;
+
+    fn neg(self) -> NonIdentity<P> {
+        Self(-self.0)
+    }
+}
+
+/// Only for groups of prime order, where a non-zero multiple of a point other than the
+/// identity is never the identity. In the full Edwards group a torsion point can vanish.
+impl<P: PrimeGroup<Scalar = Scalar>> Mul<NonZeroScalar> for NonIdentity<P> {
+    type Output = NonIdentity<P>;
+
+    fn mul(self, scalar: NonZeroScalar) -> NonIdentity<P> {
+        Self(self.0 * scalar.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: serde::Serialize> serde::Serialize for NonIdentity<P> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P: Group + serde::Deserialize<'de>> serde::Deserialize<'de> for NonIdentity<P> {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let point = P::deserialize(d)?;
+        Option::from(Self::new(point))
+            .ok_or_else(|| serde::de::Error::custom("point is the identity"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DecafPoint, EdwardsPoint};
+    use rand_core::OsRng;
+
+    #[test]
+    fn non_zero_scalar() {
+        assert!(bool::from(NonZeroScalar::new(Scalar::ZERO).is_none()));
+        assert!(NonZeroScalar::try_from(Scalar::ZERO).is_err());
+
+        let s = NonZeroScalar::random(&mut OsRng);
+        assert_eq!(NonZeroScalar::new(*s).unwrap(), s);
+        assert_eq!(*(s * s.invert()), Scalar::ONE);
+        assert_eq!(Scalar::from(-s), -s.to_scalar());
+        assert_eq!(EdwardsPoint::GENERATOR * *s, EdwardsPoint::GENERATOR * s.0);
+    }
+
+    #[test]
+    fn non_identity() {
+        assert!(bool::from(
+            NonIdentity::new(EdwardsPoint::IDENTITY).is_none()
+        ));
+        assert!(bool::from(NonIdentity::new(DecafPoint::IDENTITY).is_none()));
+
+        let p = NonIdentity::<DecafPoint>::random(&mut OsRng);
+        let s = NonZeroScalar::random(&mut OsRng);
+        assert_eq!((p * s).to_point(), *p * *s);
+        assert_eq!((-p).to_point(), -p.to_point());
+        assert_eq!(
+            NonIdentity::new(EdwardsPoint::GENERATOR)
+                .unwrap()
+                .compress(),
+            EdwardsPoint::GENERATOR.compress()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let s = NonZeroScalar::random(&mut OsRng);
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, serde_json::to_string(&*s).unwrap());
+        assert_eq!(serde_json::from_str::<NonZeroScalar>(&json).unwrap(), s);
+        let zero = serde_json::to_string(&Scalar::ZERO).unwrap();
+        assert!(serde_json::from_str::<NonZeroScalar>(&zero).is_err());
+
+        let p = NonIdentity::<EdwardsPoint>::random(&mut OsRng);
+        let bare = serde_bare::to_vec(&p).unwrap();
+        assert_eq!(
+            serde_bare::from_slice::<NonIdentity<EdwardsPoint>>(&bare).unwrap(),
+            p
+        );
+        let identity = serde_bare::to_vec(&EdwardsPoint::IDENTITY).unwrap();
+        assert!(serde_bare::from_slice::<NonIdentity<EdwardsPoint>>(&identity).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+//! The "Simplest OT" base oblivious transfer of Chou and Orlandi over decaf448.
+//!
+//! The sender learns two keys and the receiver learns exactly one of them, chosen by
+//! a bit the sender never sees. The keys can then encrypt the two messages of a
+//! 1-out-of-2 transfer or seed an OT extension. Over decaf448 this gives 224-bit security.
+//!
+//! The message flow is
+//! 1. the sender picks `a` and sends `A = [a]G`,
+//! 2. the receiver with choice bit `c` picks `b` and sends `B = [b]G + [c]A`,
+//! 3. the sender derives `k_0 = H(A, B, [a]B)` and `k_1 = H(A, B, [a](B - A))`,
+//!    the receiver derives `k_c = H(A, B, [b]A)`.
+//!
+//! One sender message can serve many receiver messages, one per transfer,
+//! as long as every transfer gets a distinct `index` in the key derivation.
+//!
+//! See <https://eprint.iacr.org/2015/267>.
+use crate::{CompressedDecaf, DecafPoint, Scalar};
+use rand_core::{CryptoRng, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use std::fmt::{self, Display, Formatter};
+use subtle::{Choice, ConditionallySelectable};
+
+/// The length of the derived keys
+pub const OT_KEY_LENGTH: usize = 56;
+
+const KEY_DST: &[u8] = b"decaf448_SimplestOT_XOF:SHAKE256";
+
+/// Errors produced during an oblivious transfer
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum OtError {
+    /// The other party's message is not a valid point, or is the identity
+    InvalidMessage,
+}
+
+impl Display for OtError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OtError::InvalidMessage => write!(f, "oblivious transfer message is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for OtError {}
+
+/// The sender's state
+#[derive(Clone)]
+pub struct OtSender {
+    a: Scalar,
+    public: CompressedDecaf,
+}
+
+impl OtSender {
+    /// Start a transfer with a fresh secret
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let a = Scalar::random(rng);
+        let public = (DecafPoint::GENERATOR * a).compress();
+        Self { a, public }
+    }
+
+    /// The message `A` for the receiver
+    pub fn message(&self) -> CompressedDecaf {
+        self.public
+    }
+
+    /// Derive both keys `(k_0, k_1)` of transfer `index` from the receiver's message `B`.
+    pub fn keys(
+        &self,
+        index: u64,
+        receiver_message: &CompressedDecaf,
+    ) -> Result<([u8; OT_KEY_LENGTH], [u8; OT_KEY_LENGTH]), OtError> {
+        let b = decode(receiver_message)?;
+        let a = decode(&self.public)?;
+        let k0 = derive_key(index, &self.public, receiver_message, &(b * self.a));
+        let k1 = derive_key(index, &self.public, receiver_message, &((b - a) * self.a));
+        Ok((k0, k1))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for OtSender {
+    fn zeroize(&mut self) {
+        self.a.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for OtSender {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for OtSender {}
+
+/// The receiver's state for a single transfer
+#[derive(Clone)]
+pub struct OtReceiver {
+    index: u64,
+    sender_message: CompressedDecaf,
+    message: CompressedDecaf,
+    key: [u8; OT_KEY_LENGTH],
+}
+
+impl OtReceiver {
+    /// Answer the sender's message `A` for transfer `index` with the secret choice bit `choice`.
+    ///
+    /// The choice only enters through constant-time selections.
+    pub fn new<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        index: u64,
+        sender_message: &CompressedDecaf,
+        choice: Choice,
+    ) -> Result<Self, OtError> {
+        let a = decode(sender_message)?;
+        #[allow(unused_mut)]
+        let mut b = Scalar::random(rng);
+        let offset = DecafPoint::conditional_select(&DecafPoint::IDENTITY, &a, choice);
+        let message = (DecafPoint::GENERATOR * b + offset).compress();
+        let key = derive_key(index, sender_message, &message, &(a * b));
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut b);
+
+        Ok(Self {
+            index,
+            sender_message: *sender_message,
+            message,
+            key,
+        })
+    }
+
+    /// The message `B` for the sender
+    pub fn message(&self) -> CompressedDecaf {
+        self.message
+    }
+
+    /// The index of this transfer
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// The sender's message this receiver answered
+    pub fn sender_message(&self) -> CompressedDecaf {
+        self.sender_message
+    }
+
+    /// The chosen key `k_c`
+    pub fn key(&self) -> [u8; OT_KEY_LENGTH] {
+        self.key
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for OtReceiver {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for OtReceiver {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for OtReceiver {}
+
+fn decode(message: &CompressedDecaf) -> Result<DecafPoint, OtError> {
+    Option::<DecafPoint>::from(message.decompress())
+        .filter(|p| *p != DecafPoint::IDENTITY)
+        .ok_or(OtError::InvalidMessage)
+}
+
+fn derive_key(
+    index: u64,
+    a: &CompressedDecaf,
+    b: &CompressedDecaf,
+    shared: &DecafPoint,
+) -> [u8; OT_KEY_LENGTH] {
+    let mut key = [0u8; OT_KEY_LENGTH];
+    Shake256::default()
+        .chain(KEY_DST)
+        .chain(index.to_le_bytes())
+        .chain(a.0)
+        .chain(b.0)
+        .chain(shared.compress().0)
+        .finalize_xof()
+        .read(&mut key);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn receiver_learns_chosen_key() {
+        let sender = OtSender::new(&mut OsRng);
+        for (index, choice) in [0u8, 1, 1, 0].into_iter().enumerate() {
+            let index = index as u64;
+            let receiver =
+                OtReceiver::new(&mut OsRng, index, &sender.message(), Choice::from(choice))
+                    .unwrap();
+            let (k0, k1) = sender.keys(index, &receiver.message()).unwrap();
+            assert_ne!(k0, k1);
+            if choice == 0 {
+                assert_eq!(receiver.key(), k0);
+            } else {
+                assert_eq!(receiver.key(), k1);
+            }
+
+            // Keys are bound to the transfer index
+            let (other0, other1) = sender.keys(index + 1, &receiver.message()).unwrap();
+            assert_ne!(receiver.key(), other0);
+            assert_ne!(receiver.key(), other1);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut sender = OtSender::new(&mut OsRng);
+        let mut receiver =
+            OtReceiver::new(&mut OsRng, 0, &sender.message(), Choice::from(1)).unwrap();
+        sender.zeroize();
+        receiver.zeroize();
+        assert_eq!(sender.a, Scalar::ZERO);
+        assert_eq!(receiver.key(), [0u8; OT_KEY_LENGTH]);
+    }
+
+    #[test]
+    fn rejects_identity() {
+        assert_eq!(
+            OtReceiver::new(&mut OsRng, 0, &CompressedDecaf::IDENTITY, Choice::from(0)).err(),
+            Some(OtError::InvalidMessage)
+        );
+        let sender = OtSender::new(&mut OsRng);
+        assert_eq!(
+            sender.keys(0, &CompressedDecaf::IDENTITY),
+            Err(OtError::InvalidMessage)
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Linkable spontaneous anonymous group (LSAG) signatures over decaf448.
+//!
+//! A signer holding one secret key out of a ring of public keys proves that
+//! some member of the ring signed, without revealing which one.
+//! Every signature carries a key image `I = x·H(P)` that only depends on the signer's
+//! key, so two signatures by the same key can be linked while staying anonymous.
+//!
+//! This follows Liu, Wei and Wong, "Linkable Spontaneous Anonymous Group Signature
+//! for Ad Hoc Groups", with the ring and key image bound into every challenge.
+//! Since decaf448 has prime order, key images need no cofactor handling.
+use crate::{CompressedDecaf, DecafPoint, EdwardsPoint, Scalar, ScalarBytes};
+use elliptic_curve::hash2curve::ExpandMsgXof;
+use rand_core::{CryptoRng, RngCore};
+use sha3::Shake256;
+use std::fmt::{self, Display, Formatter};
+use subtle::ConstantTimeEq;
+
+const HASH_TO_POINT_DST: &[u8] = b"decaf448_LSAG_XOF:SHAKE256_HASH_TO_POINT";
+const CHALLENGE_DST: &[u8] = b"decaf448_LSAG_XOF:SHAKE256_CHALLENGE";
+
+/// Errors produced when creating or checking ring signatures
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RingSignatureError {
+    /// The ring is empty or does not match the number of responses
+    InvalidRingSize,
+    /// The signer's public key is not at the given position in the ring
+    SignerNotInRing,
+    /// The encoding is malformed
+    InvalidEncoding,
+    /// The signature does not verify
+    Verify,
+}
+
+impl Display for RingSignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RingSignatureError::InvalidRingSize => write!(f, "ring size is invalid"),
+            RingSignatureError::SignerNotInRing => write!(f, "signer is not in the ring"),
+            RingSignatureError::InvalidEncoding => write!(f, "ring signature encoding is invalid"),
+            RingSignatureError::Verify => write!(f, "ring signature verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for RingSignatureError {}
+
+/// The key image `I = x·H(P)` of a secret key `x` with public key `P = x·G`.
+///
+/// Two signatures with the same key image were made with the same secret key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct KeyImage(DecafPoint);
+
+impl KeyImage {
+    /// Compute the key image of `secret`
+    pub fn new(secret: &Scalar) -> Self {
+        let public = DecafPoint::GENERATOR * secret;
+        KeyImage(hash_to_point(&public.compress()) * secret)
+    }
+
+    /// The compressed key image
+    pub fn compress(&self) -> CompressedDecaf {
+        self.0.compress()
+    }
+
+    /// The key image as a point
+    pub fn to_decaf(&self) -> DecafPoint {
+        self.0
+    }
+}
+
+/// A linkable ring signature.
+///
+/// It holds the first challenge `c_0`, one response per ring member and the
+/// signer's [`KeyImage`]. The ring itself is not part of the signature and must be
+/// supplied, in the same order, when verifying.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RingSignature {
+    c0: Scalar,
+    responses: Vec<Scalar>,
+    key_image: KeyImage,
+}
+
+impl RingSignature {
+    /// Sign `msg` as the member at position `signer` of `ring`, whose secret key is `secret`.
+    pub fn sign<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        msg: &[u8],
+        ring: &[DecafPoint],
+        signer: usize,
+        secret: &Scalar,
+    ) -> Result<Self, RingSignatureError> {
+        if ring.is_empty() {
+            return Err(RingSignatureError::InvalidRingSize);
+        }
+        if signer >= ring.len() || (DecafPoint::GENERATOR * secret) != ring[signer] {
+            return Err(RingSignatureError::SignerNotInRing);
+        }
+
+        let n = ring.len();
+        let key_image = KeyImage::new(secret);
+        let mut transcript = Transcript::new(msg, ring, &key_image);
+
+        let mut responses = (0..n).map(|_| Scalar::random(rng)).collect::<Vec<_>>();
+        let mut challenges = vec![Scalar::ZERO; n];
+
+        // Start the ring right after the signer with a commitment to `alpha`
+        let alpha = Scalar::random(rng);
+        let mut c = transcript.challenge(
+            &(DecafPoint::GENERATOR * alpha),
+            &(hash_to_point(&ring[signer].compress()) * alpha),
+        );
+
+        for k in 1..n {
+            let i = (signer + k) % n;
+            challenges[i] = c;
+            let (l, r) = commitments(&ring[i], &key_image, &c, &responses[i]);
+            c = transcript.challenge(&l, &r);
+        }
+
+        // Close the ring: s_signer = alpha - c_signer * x
+        challenges[signer] = c;
+        responses[signer] = alpha - c * secret;
+
+        Ok(Self {
+            c0: challenges[0],
+            responses,
+            key_image,
+        })
+    }
+
+    /// Check that a member of `ring` signed `msg`.
+    pub fn verify(&self, msg: &[u8], ring: &[DecafPoint]) -> Result<(), RingSignatureError> {
+        if ring.is_empty() || ring.len() != self.responses.len() {
+            return Err(RingSignatureError::InvalidRingSize);
+        }
+        if self.key_image.0 == DecafPoint::IDENTITY {
+            return Err(RingSignatureError::Verify);
+        }
+
+        let mut transcript = Transcript::new(msg, ring, &self.key_image);
+        let mut c = self.c0;
+        for (p, s) in ring.iter().zip(self.responses.iter()) {
+            let (l, r) = commitments(p, &self.key_image, &c, s);
+            c = transcript.challenge(&l, &r);
+        }
+
+        if c.ct_eq(&self.c0).into() {
+            Ok(())
+        } else {
+            Err(RingSignatureError::Verify)
+        }
+    }
+
+    /// The key image of the signer
+    pub fn key_image(&self) -> &KeyImage {
+        &self.key_image
+    }
+
+    /// Whether both signatures were made with the same secret key
+    pub fn is_linked(&self, other: &RingSignature) -> bool {
+        self.key_image == other.key_image
+    }
+
+    /// The number of ring members this signature was made for
+    pub fn ring_size(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Serialize as `I || c_0 || s_0 || ... || s_{n-1}`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(56 + 57 * (self.responses.len() + 1));
+        bytes.extend_from_slice(&self.key_image.compress().0);
+        bytes.extend_from_slice(&self.c0.to_bytes_rfc_8032());
+        for s in &self.responses {
+            bytes.extend_from_slice(&s.to_bytes_rfc_8032());
+        }
+        bytes
+    }
+
+    /// Deserialize from `I || c_0 || s_0 || ... || s_{n-1}`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RingSignatureError> {
+        if bytes.len() < 56 + 57 * 2 || !(bytes.len() - 56).is_multiple_of(57) {
+            return Err(RingSignatureError::InvalidEncoding);
+        }
+        let mut image = CompressedDecaf::IDENTITY;
+        image.0.copy_from_slice(&bytes[..56]);
+        let key_image = Option::<DecafPoint>::from(image.decompress())
+            .map(KeyImage)
+            .ok_or(RingSignatureError::InvalidEncoding)?;
+
+        let mut scalars = bytes[56..].chunks(57).map(|chunk| {
+            let mut s = ScalarBytes::default();
+            s.copy_from_slice(chunk);
+            Option::<Scalar>::from(Scalar::from_canonical_bytes(&s))
+                .ok_or(RingSignatureError::InvalidEncoding)
+        });
+        let c0 = scalars
+            .next()
+            .ok_or(RingSignatureError::InvalidEncoding)??;
+        let responses = scalars.collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            c0,
+            responses,
+            key_image,
+        })
+    }
+}
+
+/// `L = s·G + c·P` and `R = s·H(P) + c·I`
+fn commitments(
+    public: &DecafPoint,
+    key_image: &KeyImage,
+    c: &Scalar,
+    s: &Scalar,
+) -> (DecafPoint, DecafPoint) {
+    let l = DecafPoint::GENERATOR * s + public * c;
+    let r = hash_to_point(&public.compress()) * s + key_image.0 * c;
+    (l, r)
+}
+
+fn hash_to_point(public: &CompressedDecaf) -> DecafPoint {
+    let point = EdwardsPoint::hash::<ExpandMsgXof<Shake256>>(&public.0, HASH_TO_POINT_DST);
+    DecafPoint(point.to_twisted())
+}
+
+/// Hashes `ring || I || msg || L || R` into a challenge
+struct Transcript {
+    buffer: Vec<u8>,
+    prefix_len: usize,
+}
+
+impl Transcript {
+    fn new(msg: &[u8], ring: &[DecafPoint], key_image: &KeyImage) -> Self {
+        let mut buffer = Vec::with_capacity(56 * (ring.len() + 3) + msg.len());
+        for p in ring {
+            buffer.extend_from_slice(&p.compress().0);
+        }
+        buffer.extend_from_slice(&key_image.compress().0);
+        buffer.extend_from_slice(msg);
+        let prefix_len = buffer.len();
+        Self { buffer, prefix_len }
+    }
+
+    fn challenge(&mut self, l: &DecafPoint, r: &DecafPoint) -> Scalar {
+        self.buffer.truncate(self.prefix_len);
+        self.buffer.extend_from_slice(&l.compress().0);
+        self.buffer.extend_from_slice(&r.compress().0);
+        Scalar::hash::<ExpandMsgXof<Shake256>>(&self.buffer, CHALLENGE_DST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn keys(n: usize) -> (Vec<Scalar>, Vec<DecafPoint>) {
+        let secrets = (0..n)
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let ring = secrets.iter().map(|x| &DecafPoint::GENERATOR * x).collect();
+        (secrets, ring)
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let (secrets, ring) = keys(3);
+        for (i, x) in secrets.iter().enumerate() {
+            let sig = RingSignature::sign(&mut OsRng, b"message", &ring, i, x).unwrap();
+            assert_eq!(sig.ring_size(), 3);
+            assert!(sig.verify(b"message", &ring).is_ok());
+            assert_eq!(
+                sig.verify(b"other message", &ring),
+                Err(RingSignatureError::Verify)
+            );
+
+            let mut other_ring = ring.clone();
+            other_ring.swap(0, 1);
+            assert_eq!(
+                sig.verify(b"message", &other_ring),
+                Err(RingSignatureError::Verify)
+            );
+            assert_eq!(
+                sig.verify(b"message", &ring[..2]),
+                Err(RingSignatureError::InvalidRingSize)
+            );
+        }
+    }
+
+    #[test]
+    fn linkability() {
+        let (secrets, ring) = keys(2);
+        let a = RingSignature::sign(&mut OsRng, b"one", &ring, 0, &secrets[0]).unwrap();
+        let b = RingSignature::sign(&mut OsRng, b"two", &ring, 0, &secrets[0]).unwrap();
+        let c = RingSignature::sign(&mut OsRng, b"one", &ring, 1, &secrets[1]).unwrap();
+        assert!(a.is_linked(&b));
+        assert!(!a.is_linked(&c));
+        assert_eq!(*a.key_image(), KeyImage::new(&secrets[0]));
+    }
+
+    #[test]
+    fn rejects_wrong_signer() {
+        let (secrets, ring) = keys(2);
+        assert_eq!(
+            RingSignature::sign(&mut OsRng, b"", &ring, 1, &secrets[0]),
+            Err(RingSignatureError::SignerNotInRing)
+        );
+        assert_eq!(
+            RingSignature::sign(&mut OsRng, b"", &ring, 2, &secrets[0]),
+            Err(RingSignatureError::SignerNotInRing)
+        );
+        assert_eq!(
+            RingSignature::sign(&mut OsRng, b"", &[], 0, &secrets[0]),
+            Err(RingSignatureError::InvalidRingSize)
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let (secrets, ring) = keys(2);
+        let sig = RingSignature::sign(&mut OsRng, b"message", &ring, 1, &secrets[1]).unwrap();
+        let bytes = sig.to_bytes();
+        assert_eq!(bytes.len(), 56 + 57 * 3);
+        let decoded = RingSignature::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, sig);
+        assert!(decoded.verify(b"message", &ring).is_ok());
+
+        assert_eq!(
+            RingSignature::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(RingSignatureError::InvalidEncoding)
+        );
+    }
+}
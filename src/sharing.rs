@@ -0,0 +1,310 @@
+//! Shamir secret sharing of scalars with Feldman verifiable secret sharing.
+//!
+//! A dealer splits a secret into `limit` shares such that any `threshold` of them
+//! recover it with [`combine_shares`]. With [`split_secret_with_verifier`] the dealer also
+//! publishes commitments to the polynomial coefficients in any group with our [`Scalar`],
+//! so every participant can check its share with [`FeldmanVerifier::verify`] without
+//! learning anything about the secret beyond `[secret]G`.
+//!
+//! In a distributed key generation every participant acts as a dealer.
+//! Each participant adds the share values it received from all dealers,
+//! and [`FeldmanVerifier::aggregate`] sums the dealers' commitments into the verifier for
+//! the joint polynomial, whose first commitment is the group public key.
+use crate::Scalar;
+use elliptic_curve::group::Group;
+use rand_core::{CryptoRng, RngCore};
+use std::fmt::{self, Display, Formatter};
+
+/// Errors produced when splitting, combining or verifying shares
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SharingError {
+    /// The threshold is below 2 or above the number of shares
+    InvalidThreshold,
+    /// The identifiers are zero or repeated, or there are no shares
+    InvalidShares,
+    /// The share does not match the commitments
+    InvalidShare,
+    /// The verifiers have different thresholds
+    MismatchedVerifiers,
+}
+
+impl Display for SharingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SharingError::InvalidThreshold => write!(f, "threshold is invalid"),
+            SharingError::InvalidShares => write!(f, "share identifiers are invalid"),
+            SharingError::InvalidShare => write!(f, "share does not match the commitments"),
+            SharingError::MismatchedVerifiers => write!(f, "verifiers have different thresholds"),
+        }
+    }
+}
+
+impl std::error::Error for SharingError {}
+
+/// A share `(x, f(x))` of a secret `f(0)`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Share {
+    /// The non-zero evaluation point
+    pub identifier: Scalar,
+    /// The polynomial evaluated at `identifier`
+    pub value: Scalar,
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Share {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+/// Commitments `[a_i]G` to the coefficients of a sharing polynomial
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeldmanVerifier<G: Group<Scalar = Scalar>> {
+    /// The commitment to the constant term comes first
+    pub commitments: Vec<G>,
+}
+
+impl<G: Group<Scalar = Scalar>> FeldmanVerifier<G> {
+    /// The commitment to the secret, `[f(0)]G`, or `None` if there are no commitments
+    pub fn public_key(&self) -> Option<G> {
+        self.commitments.first().copied()
+    }
+
+    /// The number of shares needed to recover the secret
+    pub fn threshold(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Check that `[share.value]G` matches the committed polynomial at `share.identifier`.
+    pub fn verify(&self, share: &Share) -> Result<(), SharingError> {
+        if share.identifier.is_zero().into() {
+            return Err(SharingError::InvalidShares);
+        }
+        if self.commitments.is_empty() {
+            return Err(SharingError::InvalidShare);
+        }
+        // Horner's rule on the commitments
+        let expected = self
+            .commitments
+            .iter()
+            .rev()
+            .fold(G::identity(), |acc, c| acc * share.identifier + c);
+
+        if G::generator() * share.value == expected {
+            Ok(())
+        } else {
+            Err(SharingError::InvalidShare)
+        }
+    }
+
+    /// Sum the verifiers of several dealers into the verifier of their summed polynomial.
+    pub fn aggregate(verifiers: &[Self]) -> Result<Self, SharingError> {
+        let first = verifiers.first().ok_or(SharingError::MismatchedVerifiers)?;
+        if verifiers.iter().any(|v| v.threshold() != first.threshold()) {
+            return Err(SharingError::MismatchedVerifiers);
+        }
+        let commitments = (0..first.threshold())
+            .map(|i| verifiers.iter().map(|v| v.commitments[i]).sum())
+            .collect();
+        Ok(Self { commitments })
+    }
+}
+
+/// Split `secret` into `limit` shares, any `threshold` of which recover it.
+///
+/// Shares are identified by `1..=limit`.
+pub fn split_secret<R: RngCore + CryptoRng>(
+    secret: &Scalar,
+    threshold: usize,
+    limit: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, SharingError> {
+    let coefficients = random_polynomial(secret, threshold, limit, rng)?;
+    Ok(evaluate_shares(&coefficients, limit))
+}
+
+/// Split `secret` like [`split_secret`] and also return the Feldman commitments
+/// to the polynomial in the group `G`.
+pub fn split_secret_with_verifier<G, R>(
+    secret: &Scalar,
+    threshold: usize,
+    limit: usize,
+    rng: &mut R,
+) -> Result<(Vec<Share>, FeldmanVerifier<G>), SharingError>
+where
+    G: Group<Scalar = Scalar>,
+    R: RngCore + CryptoRng,
+{
+    let coefficients = random_polynomial(secret, threshold, limit, rng)?;
+    let shares = evaluate_shares(&coefficients, limit);
+    let commitments = coefficients.0.iter().map(|a| G::generator() * a).collect();
+    Ok((shares, FeldmanVerifier { commitments }))
+}
+
+/// Recover the secret from at least `threshold` shares by Lagrange interpolation at zero.
+///
+/// Passing fewer shares than the threshold silently yields a wrong secret,
+/// pair this with [`FeldmanVerifier::public_key`] to detect that.
+pub fn combine_shares(shares: &[Share]) -> Result<Scalar, SharingError> {
+    if shares.is_empty() {
+        return Err(SharingError::InvalidShares);
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if share.identifier.is_zero().into()
+            || shares[..i].iter().any(|s| s.identifier == share.identifier)
+        {
+            return Err(SharingError::InvalidShares);
+        }
+    }
+
+    let mut secret = Scalar::ZERO;
+    for share in shares {
+        // l_i(0) = prod_{j != i} x_j / (x_j - x_i)
+        let mut num = Scalar::ONE;
+        let mut den = Scalar::ONE;
+        for other in shares.iter().filter(|s| s.identifier != share.identifier) {
+            num *= other.identifier;
+            den *= other.identifier - share.identifier;
+        }
+        secret += share.value * num * den.invert();
+    }
+    Ok(secret)
+}
+
+/// The coefficients of a sharing polynomial, the secret first
+struct Polynomial(Vec<Scalar>);
+
+#[cfg(feature = "zeroize")]
+impl Drop for Polynomial {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+fn random_polynomial<R: RngCore + CryptoRng>(
+    secret: &Scalar,
+    threshold: usize,
+    limit: usize,
+    rng: &mut R,
+) -> Result<Polynomial, SharingError> {
+    if threshold < 2 || threshold > limit || limit > u32::MAX as usize {
+        return Err(SharingError::InvalidThreshold);
+    }
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(*secret);
+    coefficients.extend((1..threshold).map(|_| Scalar::random(&mut *rng)));
+    Ok(Polynomial(coefficients))
+}
+
+fn evaluate_shares(coefficients: &Polynomial, limit: usize) -> Vec<Share> {
+    (1..=limit as u32)
+        .map(|i| {
+            let identifier = Scalar::from(i);
+            let value = coefficients
+                .0
+                .iter()
+                .rev()
+                .fold(Scalar::ZERO, |acc, a| acc * identifier + a);
+            Share { identifier, value }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdwardsPoint;
+    use rand_core::OsRng;
+
+    #[test]
+    fn split_and_combine() {
+        let secret = Scalar::random(&mut OsRng);
+        let shares = split_secret(&secret, 3, 5, &mut OsRng).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert_eq!(combine_shares(&shares[..3]).unwrap(), secret);
+        assert_eq!(combine_shares(&shares[2..]).unwrap(), secret);
+        assert_eq!(combine_shares(&shares).unwrap(), secret);
+        assert_ne!(combine_shares(&shares[..2]).unwrap(), secret);
+
+        assert_eq!(
+            combine_shares(&[shares[0], shares[0]]),
+            Err(SharingError::InvalidShares)
+        );
+        assert_eq!(
+            split_secret(&secret, 1, 5, &mut OsRng),
+            Err(SharingError::InvalidThreshold)
+        );
+        assert_eq!(
+            split_secret(&secret, 6, 5, &mut OsRng),
+            Err(SharingError::InvalidThreshold)
+        );
+    }
+
+    #[test]
+    fn feldman_verify() {
+        let secret = Scalar::random(&mut OsRng);
+        let (shares, verifier) =
+            split_secret_with_verifier::<EdwardsPoint, _>(&secret, 2, 3, &mut OsRng).unwrap();
+        assert_eq!(
+            verifier.public_key(),
+            Some(EdwardsPoint::GENERATOR * secret)
+        );
+        for share in &shares {
+            assert!(verifier.verify(share).is_ok());
+        }
+
+        let mut bad = shares[1];
+        bad.value += Scalar::ONE;
+        assert_eq!(verifier.verify(&bad), Err(SharingError::InvalidShare));
+
+        let empty = FeldmanVerifier::<EdwardsPoint> {
+            commitments: Vec::new(),
+        };
+        assert_eq!(empty.public_key(), None);
+        let zero = Share {
+            identifier: Scalar::ONE,
+            value: Scalar::ZERO,
+        };
+        assert_eq!(empty.verify(&zero), Err(SharingError::InvalidShare));
+    }
+
+    #[test]
+    fn feldman_aggregate() {
+        // Three dealers, as in a distributed key generation
+        let dealings = (0..3)
+            .map(|_| {
+                let secret = Scalar::random(&mut OsRng);
+                let (shares, verifier) =
+                    split_secret_with_verifier::<EdwardsPoint, _>(&secret, 2, 3, &mut OsRng)
+                        .unwrap();
+                (secret, shares, verifier)
+            })
+            .collect::<Vec<_>>();
+
+        let verifiers = dealings.iter().map(|d| d.2.clone()).collect::<Vec<_>>();
+        let joint = FeldmanVerifier::aggregate(&verifiers).unwrap();
+        let joint_secret = dealings.iter().map(|d| d.0).sum::<Scalar>();
+        assert_eq!(
+            joint.public_key(),
+            Some(EdwardsPoint::GENERATOR * joint_secret)
+        );
+
+        let joint_shares = (0..3)
+            .map(|i| Share {
+                identifier: dealings[0].1[i].identifier,
+                value: dealings.iter().map(|d| d.1[i].value).sum(),
+            })
+            .collect::<Vec<_>>();
+        for share in &joint_shares {
+            assert!(joint.verify(share).is_ok());
+        }
+        assert_eq!(combine_shares(&joint_shares[1..]).unwrap(), joint_secret);
+
+        let (_, other) =
+            split_secret_with_verifier::<EdwardsPoint, _>(&Scalar::ONE, 3, 3, &mut OsRng).unwrap();
+        assert_eq!(
+            FeldmanVerifier::aggregate(&[joint, other]),
+            Err(SharingError::MismatchedVerifiers)
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Errors produced when encoding, decoding or checking signatures
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SigningError {
+    /// The signature is not 114 bytes long
+    InvalidSignatureLength,
+    /// The `R` component is not the encoding of a valid point
+    InvalidSignatureRComponent,
+    /// The `S` component is not a canonically encoded scalar
+    InvalidSignatureSComponent,
+    /// The public key bytes are not the encoding of a valid point
+    InvalidPublicKeyBytes,
+    /// The secret key is not 57 bytes long
+    InvalidSecretKeyLength,
+    /// The context string is longer than 255 bytes
+    InvalidContextLength,
+    /// The messages, signatures and keys of a batch have different lengths
+    InvalidBatchLength,
+    /// The signature does not verify
+    Verify,
+}
+
+impl Display for SigningError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SigningError::InvalidSignatureLength => write!(f, "signature length is invalid"),
+            SigningError::InvalidSignatureRComponent => {
+                write!(f, "signature R component is invalid")
+            }
+            SigningError::InvalidSignatureSComponent => {
+                write!(f, "signature S component is invalid")
+            }
+            SigningError::InvalidPublicKeyBytes => write!(f, "public key bytes are invalid"),
+            SigningError::InvalidSecretKeyLength => write!(f, "secret key length is invalid"),
+            SigningError::InvalidContextLength => write!(f, "context is longer than 255 bytes"),
+            SigningError::InvalidBatchLength => {
+                write!(f, "batch inputs have different lengths")
+            }
+            SigningError::Verify => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for SigningError {}
@@ -0,0 +1,154 @@
+//! Ed448 signatures as specified in [RFC 8032].
+//!
+//! Both the pure Ed448 mode and the prehashed Ed448ph mode are supported.
+//! Ed448ph signs the 64-byte SHAKE256 hash of the message, so a message
+//! can be streamed into a [`Shake256`] instance instead of being buffered.
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+mod batch;
+mod error;
+#[cfg(feature = "pkcs8")]
+mod pkcs;
+mod signature;
+mod signing_key;
+mod traits;
+mod verifying_key;
+
+pub use batch::verify_batch;
+pub use error::SigningError;
+pub use signature::Signature;
+pub use signing_key::SigningKey;
+pub use traits::PreHasher;
+pub use verifying_key::VerifyingKey;
+
+use crate::{CompressedEdwardsY, Scalar, WideScalarBytes};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// Length in bytes of a secret key seed
+pub const SECRET_KEY_LENGTH: usize = 57;
+/// Length in bytes of a public key
+pub const PUBLIC_KEY_LENGTH: usize = 57;
+/// Length in bytes of a signature, `R || S`
+pub const SIGNATURE_LENGTH: usize = 114;
+/// Length in bytes of the SHAKE256 prehash `PH(M)` of Ed448ph
+pub const PREHASH_LENGTH: usize = 64;
+
+/// The `dom4` prefix string from RFC 8032
+pub(crate) const HASH_HEAD: [u8; 8] = *b"SigEd448";
+
+/// Start a SHAKE256 instance with `dom4(phflag, context)` already absorbed.
+/// The caller must ensure the context is at most 255 bytes.
+pub(crate) fn dom4(phflag: u8, context: &[u8]) -> Shake256 {
+    debug_assert!(context.len() <= 255);
+    Shake256::default()
+        .chain(HASH_HEAD)
+        .chain([phflag, context.len() as u8])
+        .chain(context)
+}
+
+/// Finish a SHAKE256 instance that absorbed the message into the 64-byte Ed448ph prehash.
+pub(crate) fn prehash(hasher: Shake256) -> [u8; PREHASH_LENGTH] {
+    let mut ph = [0u8; PREHASH_LENGTH];
+    hasher.finalize_xof().read(&mut ph);
+    ph
+}
+
+/// The challenge `k = SHAKE256(dom4(F, C) || R || A || PH(M), 114)` reduced modulo ℓ.
+pub(crate) fn challenge(
+    phflag: u8,
+    context: &[u8],
+    r: &CompressedEdwardsY,
+    a: &CompressedEdwardsY,
+    message: &[u8],
+) -> Scalar {
+    let mut k = WideScalarBytes::default();
+    dom4(phflag, context)
+        .chain(r.as_bytes())
+        .chain(a.as_bytes())
+        .chain(message)
+        .finalize_xof()
+        .read(&mut k);
+    Scalar::from_bytes_mod_order_wide(&k)
+}
+
+/// Derive the deterministic nonce `r` of [RFC 8032 § 5.2.6],
+/// `SHAKE256(dom4(F, C) || prefix || M, 114)` reduced modulo ℓ.
+///
+/// `prefix` is the upper half of the expanded secret key, see [`SigningKey::nonce_prefix`],
+/// and `message` is `PH(M)`, i.e. the 64-byte prehash when `prehashed` is set.
+/// Schemes built on Ed448 can reuse this to derive nonces the same way, with their
+/// own prefix and context. Fails if the context is longer than 255 bytes.
+///
+/// [RFC 8032 § 5.2.6]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2.6
+pub fn derive_nonce(
+    prehashed: bool,
+    context: &[u8],
+    prefix: &[u8; SECRET_KEY_LENGTH],
+    message: &[u8],
+) -> Result<Scalar, SigningError> {
+    if context.len() > 255 {
+        return Err(SigningError::InvalidContextLength);
+    }
+    let mut r = WideScalarBytes::default();
+    dom4(prehashed as u8, context)
+        .chain(prefix)
+        .chain(message)
+        .finalize_xof()
+        .read(&mut r);
+    let nonce = Scalar::from_bytes_mod_order_wide(&r);
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut r[..]);
+    Ok(nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdwardsPoint;
+
+    #[test]
+    fn rfc8032_nonces() {
+        // The R half of the signatures in RFC 8032 § 7.4 and § 7.5 is [r]B
+        let vectors: [(&str, bool, &[u8], &str, &str); 3] = [
+            (
+                "6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b",
+                false,
+                b"",
+                "",
+                "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980",
+            ),
+            (
+                "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+                false,
+                b"foo",
+                "03",
+                "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea00",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49",
+                true,
+                b"",
+                "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4",
+                "822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae381f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b80",
+            ),
+        ];
+
+        for (secret, prehashed, context, message, r) in vectors {
+            let key = SigningKey::try_from(&hex::decode(secret).unwrap()[..]).unwrap();
+            let message = hex::decode(message).unwrap();
+            let nonce = derive_nonce(prehashed, context, key.nonce_prefix(), &message).unwrap();
+            assert_eq!(
+                hex::encode((EdwardsPoint::GENERATOR * nonce).compress().as_bytes()),
+                r
+            );
+        }
+
+        assert_eq!(
+            derive_nonce(false, &[0u8; 256], &[0u8; SECRET_KEY_LENGTH], b""),
+            Err(SigningError::InvalidContextLength)
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::sign::{SigningError, SIGNATURE_LENGTH};
+use crate::{CompressedEdwardsY, EdwardsPoint, Scalar, ScalarBytes};
+
+/// An Ed448 signature, the pair `(R, S)` from [RFC 8032 § 5.2.6].
+///
+/// `R` is kept in its compressed form and `S` as a canonical [`Scalar`],
+/// so a `Signature` that exists has already passed the encoding checks of
+/// [RFC 8032 § 5.2.7]. It does not imply that the signature verifies.
+///
+/// [RFC 8032 § 5.2.6]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2.6
+/// [RFC 8032 § 5.2.7]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2.7
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub(crate) r: CompressedEdwardsY,
+    pub(crate) s: Scalar,
+}
+
+impl Signature {
+    /// Build a signature from the commitment point `R` and the response scalar `S`.
+    pub fn from_components(r: &EdwardsPoint, s: &Scalar) -> Self {
+        Self {
+            r: r.compress(),
+            s: *s,
+        }
+    }
+
+    /// The encoded commitment point `R`
+    pub fn r_bytes(&self) -> &[u8; 57] {
+        self.r.as_bytes()
+    }
+
+    /// The encoded response scalar `S`
+    pub fn s_bytes(&self) -> [u8; 57] {
+        self.s.to_bytes_rfc_8032().into()
+    }
+
+    /// The compressed commitment point `R`
+    pub fn r(&self) -> CompressedEdwardsY {
+        self.r
+    }
+
+    /// The response scalar `S`
+    pub fn s(&self) -> Scalar {
+        self.s
+    }
+
+    /// Serialize the signature as `R || S`.
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_LENGTH] {
+        let mut bytes = [0u8; SIGNATURE_LENGTH];
+        bytes[..57].copy_from_slice(self.r_bytes());
+        bytes[57..].copy_from_slice(&self.s_bytes());
+        bytes
+    }
+
+    /// Deserialize a signature from `R || S`.
+    ///
+    /// Fails if `R` is not the canonical encoding of a point or if `S` is not reduced
+    /// modulo the group order.
+    pub fn from_bytes(bytes: &[u8; SIGNATURE_LENGTH]) -> Result<Self, SigningError> {
+        let mut r = CompressedEdwardsY::default();
+        r.0.copy_from_slice(&bytes[..57]);
+        Option::<EdwardsPoint>::from(r.decompress())
+            .filter(|point| point.compress() == r)
+            .ok_or(SigningError::InvalidSignatureRComponent)?;
+
+        let mut s = ScalarBytes::default();
+        s.copy_from_slice(&bytes[57..]);
+        let s = Option::<Scalar>::from(Scalar::from_canonical_bytes(&s))
+            .ok_or(SigningError::InvalidSignatureSComponent)?;
+
+        Ok(Self { r, s })
+    }
+}
+
+impl From<Signature> for [u8; SIGNATURE_LENGTH] {
+    fn from(signature: Signature) -> Self {
+        signature.to_bytes()
+    }
+}
+
+impl From<&Signature> for [u8; SIGNATURE_LENGTH] {
+    fn from(signature: &Signature) -> Self {
+        signature.to_bytes()
+    }
+}
+
+impl From<Signature> for Vec<u8> {
+    fn from(signature: Signature) -> Self {
+        signature.to_bytes().to_vec()
+    }
+}
+
+impl From<&Signature> for Vec<u8> {
+    fn from(signature: &Signature) -> Self {
+        signature.to_bytes().to_vec()
+    }
+}
+
+impl TryFrom<&[u8; SIGNATURE_LENGTH]> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8; SIGNATURE_LENGTH]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <&[u8; SIGNATURE_LENGTH]>::try_from(bytes)
+            .map_err(|_| SigningError::InvalidSignatureLength)?;
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(&bytes[..])
+    }
+}
+
+impl TryFrom<&Vec<u8>> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(&bytes[..])
+    }
+}
+
+impl TryFrom<Box<[u8]>> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: Box<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_ref())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Signature {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let bytes = self.to_bytes();
+        if s.is_human_readable() {
+            hex::encode(bytes).serialize(s)
+        } else {
+            let mut tupler = s.serialize_tuple(bytes.len())?;
+            for b in &bytes {
+                tupler.serialize_element(b)?;
+            }
+            tupler.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            let bytes = hex::decode(s).map_err(serde::de::Error::custom)?;
+            Signature::try_from(bytes).map_err(serde::de::Error::custom)
+        } else {
+            use serde::de::{SeqAccess, Visitor};
+
+            struct SignatureVisitor;
+
+            impl<'de> Visitor<'de> for SignatureVisitor {
+                type Value = Signature;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(f, "a sequence of {} bytes", SIGNATURE_LENGTH)
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut bytes = [0u8; SIGNATURE_LENGTH];
+                    for (i, b) in bytes.iter_mut().enumerate() {
+                        *b = seq
+                            .next_element()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                    }
+                    Signature::from_bytes(&bytes).map_err(serde::de::Error::custom)
+                }
+            }
+
+            d.deserialize_tuple(SIGNATURE_LENGTH, SignatureVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_signature() -> Signature {
+        let r = EdwardsPoint::GENERATOR * Scalar::from(7u8);
+        Signature::from_components(&r, &Scalar::from(42u8))
+    }
+
+    #[test]
+    fn components() {
+        let r = EdwardsPoint::GENERATOR * Scalar::from(7u8);
+        let sig = test_signature();
+        assert_eq!(sig.r(), r.compress());
+        assert_eq!(sig.r_bytes(), r.compress().as_bytes());
+        assert_eq!(sig.s(), Scalar::from(42u8));
+        assert_eq!(sig.s_bytes()[0], 42);
+        assert!(sig.s_bytes()[1..].iter().all(|b| *b == 0));
+
+        let bytes = sig.to_bytes();
+        assert_eq!(&bytes[..57], sig.r_bytes());
+        assert_eq!(&bytes[57..], &sig.s_bytes());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let sig = test_signature();
+        let bytes = sig.to_bytes();
+        assert_eq!(Signature::from_bytes(&bytes).unwrap(), sig);
+        assert_eq!(Signature::try_from(&bytes[..]).unwrap(), sig);
+        assert_eq!(Signature::try_from(bytes.to_vec()).unwrap(), sig);
+        assert_eq!(
+            Signature::try_from(&bytes[..113]),
+            Err(SigningError::InvalidSignatureLength)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_components() {
+        let bytes = test_signature().to_bytes();
+
+        // y = 2 has no matching x
+        let mut bad_r = bytes;
+        bad_r[..57].copy_from_slice(&[0; 57]);
+        bad_r[0] = 2;
+        assert_eq!(
+            Signature::from_bytes(&bad_r),
+            Err(SigningError::InvalidSignatureRComponent)
+        );
+
+        // R with junk in the reserved bits of its last byte
+        let mut bad_r = bytes;
+        bad_r[56] |= 1;
+        assert_eq!(
+            Signature::from_bytes(&bad_r),
+            Err(SigningError::InvalidSignatureRComponent)
+        );
+
+        // R as the identity with the sign bit set
+        let mut bad_r = bytes;
+        bad_r[..57].copy_from_slice(EdwardsPoint::IDENTITY.compress().as_bytes());
+        bad_r[56] = 0x80;
+        assert_eq!(
+            Signature::from_bytes(&bad_r),
+            Err(SigningError::InvalidSignatureRComponent)
+        );
+
+        // S = ℓ is not reduced
+        let mut bad_s = bytes;
+        bad_s[57..].copy_from_slice(&crate::constants::BASEPOINT_ORDER.to_bytes_rfc_8032());
+        assert_eq!(
+            Signature::from_bytes(&bad_s),
+            Err(SigningError::InvalidSignatureSComponent)
+        );
+
+        // The 57th byte of S must be zero
+        let mut bad_s = bytes;
+        bad_s[113] = 1;
+        assert_eq!(
+            Signature::from_bytes(&bad_s),
+            Err(SigningError::InvalidSignatureSComponent)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialization() {
+        let sig = test_signature();
+
+        let bytes = serde_bare::to_vec(&sig).unwrap();
+        assert_eq!(bytes, sig.to_bytes());
+        let sig2: Signature = serde_bare::from_slice(&bytes).unwrap();
+        assert_eq!(sig, sig2);
+
+        let string = serde_json::to_string(&sig).unwrap();
+        let sig3: Signature = serde_json::from_str(&string).unwrap();
+        assert_eq!(sig, sig3);
+
+        // Readers can only hand out owned strings
+        let sig4: Signature = serde_json::from_reader(string.as_bytes()).unwrap();
+        assert_eq!(sig, sig4);
+    }
+}
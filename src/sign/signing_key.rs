@@ -0,0 +1,419 @@
+use crate::sign::{
+    challenge, derive_nonce, prehash, Signature, SigningError, VerifyingKey, SECRET_KEY_LENGTH,
+};
+use crate::{EdwardsPoint, Scalar, WideScalarBytes};
+use rand_core::{CryptoRng, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use std::fmt::{self, Debug, Formatter};
+use subtle::{Choice, ConstantTimeEq};
+
+const DERIVE_DST: &[u8] = b"ed448-goldilocks-plus Ed448 derive_from_ikm SHAKE256";
+
+/// An Ed448 secret key.
+///
+/// The key is the 57-byte seed of [RFC 8032 § 5.2.5], the secret scalar and the
+/// public key are expanded from it once on construction.
+///
+/// [RFC 8032 § 5.2.5]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2.5
+#[derive(Clone)]
+pub struct SigningKey {
+    seed: [u8; SECRET_KEY_LENGTH],
+    pub(crate) scalar: Scalar,
+    /// The upper half of the expanded seed, which keys the nonce derivation
+    pub(crate) prefix: [u8; SECRET_KEY_LENGTH],
+    pub(crate) verifying_key: VerifyingKey,
+}
+
+impl Debug for SigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ConstantTimeEq for SigningKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.seed.ct_eq(&other.seed)
+    }
+}
+
+impl PartialEq for SigningKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SigningKey {}
+
+impl From<&[u8; SECRET_KEY_LENGTH]> for SigningKey {
+    fn from(seed: &[u8; SECRET_KEY_LENGTH]) -> Self {
+        Self::from_bytes(seed)
+    }
+}
+
+impl From<&SigningKey> for VerifyingKey {
+    fn from(key: &SigningKey) -> Self {
+        key.verifying_key()
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let seed = <&[u8; SECRET_KEY_LENGTH]>::try_from(bytes)
+            .map_err(|_| SigningError::InvalidSecretKeyLength)?;
+        Ok(Self::from_bytes(seed))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.scalar.zeroize();
+        self.prefix.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SigningKey {}
+
+impl SigningKey {
+    /// Expand a key from its 57-byte seed.
+    pub fn from_bytes(seed: &[u8; SECRET_KEY_LENGTH]) -> Self {
+        let (scalar, prefix) = expand(seed);
+        let point = EdwardsPoint::mul_base(&scalar);
+        Self {
+            seed: *seed,
+            scalar,
+            prefix,
+            verifying_key: VerifyingKey::from_point(point),
+        }
+    }
+
+    /// Generate a key from a fresh random seed.
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut seed = [0u8; SECRET_KEY_LENGTH];
+        rng.fill_bytes(&mut seed);
+        let key = Self::from_bytes(&seed);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut seed);
+        key
+    }
+
+    /// Derive a key from the input keying material `ikm`, bound to the label `info`.
+    ///
+    /// The seed is `SHAKE256(DST || len(ikm) || ikm || len(info) || info)` with 64-bit
+    /// little-endian lengths, so distinct `(ikm, info)` pairs never collide. Use this to
+    /// derive per-device or per-session keys from one master secret, with a distinct `info`
+    /// for each key. The security of every derived key rests on `ikm`, which should be
+    /// uniformly random and at least 57 bytes long.
+    pub fn derive_from_ikm(ikm: &[u8], info: &[u8]) -> Self {
+        let mut seed = [0u8; SECRET_KEY_LENGTH];
+        Shake256::default()
+            .chain(DERIVE_DST)
+            .chain((ikm.len() as u64).to_le_bytes())
+            .chain(ikm)
+            .chain((info.len() as u64).to_le_bytes())
+            .chain(info)
+            .finalize_xof()
+            .read(&mut seed);
+        let key = Self::from_bytes(&seed);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut seed);
+        key
+    }
+
+    /// The 57-byte seed of this key
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_LENGTH] {
+        self.seed
+    }
+
+    /// View the 57-byte seed of this key
+    pub fn as_bytes(&self) -> &[u8; SECRET_KEY_LENGTH] {
+        &self.seed
+    }
+
+    /// The secret scalar `s` expanded from the seed.
+    ///
+    /// This is secret material and should be handled with care.
+    pub fn to_scalar(&self) -> Scalar {
+        self.scalar
+    }
+
+    /// The nonce prefix expanded from the seed, to pass to [`derive_nonce`](crate::derive_nonce).
+    ///
+    /// This is secret material and should be handled with care.
+    pub fn nonce_prefix(&self) -> &[u8; SECRET_KEY_LENGTH] {
+        &self.prefix
+    }
+
+    /// The public key `[s]B`
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.verifying_key.clone()
+    }
+
+    /// Sign `msg` with pure Ed448 and an empty context.
+    ///
+    /// Signing is deterministic, the nonce is derived from the key and the message.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        self.sign_inner(0, &[], msg)
+            .expect("the empty context is short enough")
+    }
+
+    /// Sign `msg` with pure Ed448 and a context of at most 255 bytes.
+    ///
+    /// The context separates signatures made for different protocols or purposes,
+    /// a signature only verifies with the context it was made with.
+    pub fn sign_with_context(&self, context: &[u8], msg: &[u8]) -> Result<Signature, SigningError> {
+        self.sign_inner(0, context, msg)
+    }
+
+    /// Sign the message absorbed by `prehashed` with Ed448ph and an empty context.
+    ///
+    /// The message is hashed to 64 bytes with SHAKE256 before signing, see [RFC 8032 § 5.2].
+    ///
+    /// [RFC 8032 § 5.2]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2
+    pub fn sign_prehashed(&self, prehashed: Shake256) -> Signature {
+        self.sign_inner(1, &[], &prehash(prehashed))
+            .expect("the empty context is short enough")
+    }
+
+    /// Sign the message absorbed by `prehashed` with Ed448ph and a context of at most 255 bytes.
+    pub fn sign_prehashed_with_context(
+        &self,
+        context: &[u8],
+        prehashed: Shake256,
+    ) -> Result<Signature, SigningError> {
+        self.sign_inner(1, context, &prehash(prehashed))
+    }
+
+    /// Verify a pure Ed448 signature on `msg` against this key's public key.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), SigningError> {
+        self.verifying_key.verify(msg, signature)
+    }
+
+    pub(crate) fn sign_inner(
+        &self,
+        phflag: u8,
+        context: &[u8],
+        msg: &[u8],
+    ) -> Result<Signature, SigningError> {
+        let r = derive_nonce(phflag == 1, context, &self.prefix, msg)?;
+        Ok(self.sign_with_nonce(phflag, context, msg, r))
+    }
+
+    /// Sign with the given nonce, the caller must ensure the context is at most 255 bytes.
+    pub(crate) fn sign_with_nonce(
+        &self,
+        phflag: u8,
+        context: &[u8],
+        msg: &[u8],
+        #[allow(unused_mut)] mut r: Scalar,
+    ) -> Signature {
+        let big_r = EdwardsPoint::mul_base(&r).compress();
+        let k = challenge(phflag, context, &big_r, &self.verifying_key.compressed, msg);
+        // S = r + k * s
+        let s = k.mul_add(&self.scalar, &r);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut r);
+        Signature { r: big_r, s }
+    }
+}
+
+/// Hash the seed with SHAKE256, prune the lower half into the secret scalar
+/// and keep the upper half as the nonce prefix.
+fn expand(seed: &[u8; SECRET_KEY_LENGTH]) -> (Scalar, [u8; SECRET_KEY_LENGTH]) {
+    let mut h = WideScalarBytes::default();
+    Shake256::default().chain(seed).finalize_xof().read(&mut h);
+
+    // Clear the two lowest bits and the whole last byte, and set the highest bit of the second to last byte
+    let mut s = [0u8; SECRET_KEY_LENGTH];
+    s.copy_from_slice(&h[..SECRET_KEY_LENGTH]);
+    s[0] &= 0xfc;
+    s[55] |= 0x80;
+    s[56] = 0;
+    let scalar = Scalar::from_bytes_rfc_8032(&s);
+    let mut prefix = [0u8; SECRET_KEY_LENGTH];
+    prefix.copy_from_slice(&h[SECRET_KEY_LENGTH..]);
+
+    #[cfg(feature = "zeroize")]
+    {
+        zeroize::Zeroize::zeroize(&mut h[..]);
+        zeroize::Zeroize::zeroize(&mut s);
+    }
+    (scalar, prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    // Secret and public keys from RFC 8032 § 7.4
+    const KEYS: [(&str, &str); 3] = [
+        (
+            "6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b",
+            "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180",
+        ),
+        (
+            "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+            "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+        ),
+        (
+            "872d093780f5d3730df7c212664b37b8a0f24f56810daa8382cd4fa3f77634ec44dc54f1c2ed9bea86fafb7632d8be199ea165f5ad55dd9ce8",
+            "a81b2e8a70a5ac94ffdbcc9badfc3feb0801f258578bb114ad44ece1ec0e799da08effb81c5d685c0c56f64eecaef8cdf11cc38737838cf400",
+        ),
+    ];
+
+    #[test]
+    fn rfc8032_public_keys() {
+        for (secret, public) in KEYS {
+            let key = SigningKey::try_from(&hex::decode(secret).unwrap()[..]).unwrap();
+            assert_eq!(hex::encode(key.to_bytes()), secret);
+            assert_eq!(hex::encode(key.verifying_key().as_bytes()), public);
+        }
+        assert_eq!(
+            SigningKey::try_from(&[0u8; 56][..]),
+            Err(SigningError::InvalidSecretKeyLength)
+        );
+    }
+
+    #[test]
+    fn rfc8032_signatures() {
+        // RFC 8032 § 7.4, the first two keys above signing the empty message and 0x03
+        let messages = ["", "03"];
+        let signatures = [
+            "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4dbb61149f05a7363268c71d95808ff2e652600",
+            "26b8f91727bd62897af15e41eb43c377efb9c610d48f2335cb0bd0087810f4352541b143c4b981b7e18f62de8ccdf633fc1bf037ab7cd779805e0dbcc0aae1cbcee1afb2e027df36bc04dcecbf154336c19f0af7e0a6472905e799f1953d2a0ff3348ab21aa4adafd1d234441cf807c03a00",
+        ];
+        for ((secret, _), (message, signature)) in KEYS.iter().zip(messages.iter().zip(signatures))
+        {
+            let key = SigningKey::try_from(&hex::decode(secret).unwrap()[..]).unwrap();
+            let message = hex::decode(message).unwrap();
+            let sig = key.sign(&message);
+            assert_eq!(hex::encode(sig.to_bytes()), signature);
+            assert!(key.verify(&message, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn rfc8032_prehashed() {
+        // RFC 8032 § 7.5 with an empty context
+        let key = SigningKey::try_from(&hex!("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49")[..]).unwrap();
+        assert_eq!(key.verifying_key().to_bytes(), hex!("259b71c19f83ef77a7abd26524cbdb3161b590a48f7d17de3ee0ba9c52beb743c09428a131d6b1b57303d90d8132c276d5ed3d5d01c0f53880"));
+
+        let sig = key.sign_prehashed(Shake256::default().chain(b"abc"));
+        assert_eq!(sig.to_bytes(), hex!("822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae381f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3ad203df7dc7ce360c3cd3696d9d9fab90f00"));
+
+        let vk = key.verifying_key();
+        assert!(vk
+            .verify_prehashed(Shake256::default().chain(b"a").chain(b"bc"), &sig)
+            .is_ok());
+        assert_eq!(
+            vk.verify_prehashed(Shake256::default().chain(b"abd"), &sig),
+            Err(SigningError::Verify)
+        );
+        // Ed448 and Ed448ph signatures are not interchangeable
+        assert_eq!(vk.verify(b"abc", &sig), Err(SigningError::Verify));
+        assert_eq!(
+            vk.verify_prehashed(Shake256::default().chain(b"abc"), &key.sign(b"abc")),
+            Err(SigningError::Verify)
+        );
+    }
+
+    #[test]
+    fn rfc8032_context() {
+        // RFC 8032 § 7.4 and § 7.5 with the context "foo"
+        let key = SigningKey::try_from(&hex::decode(KEYS[1].0).unwrap()[..]).unwrap();
+        let sig = key.sign_with_context(b"foo", &[3]).unwrap();
+        assert_eq!(sig.to_bytes(), hex!("d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d5428407e85dcbc98a49155c13764e66c3c00"));
+        let vk = key.verifying_key();
+        assert!(vk.verify_with_context(b"foo", &[3], &sig).is_ok());
+        assert_eq!(vk.verify(&[3], &sig), Err(SigningError::Verify));
+        assert_eq!(
+            vk.verify_with_context(b"bar", &[3], &sig),
+            Err(SigningError::Verify)
+        );
+
+        let key = SigningKey::try_from(&hex!("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49")[..]).unwrap();
+        let sig = key
+            .sign_prehashed_with_context(b"foo", Shake256::default().chain(b"abc"))
+            .unwrap();
+        assert_eq!(sig.to_bytes(), hex!("c32299d46ec8ff02b54540982814dce9a05812f81962b649d528095916a2aa481065b1580423ef927ecf0af5888f90da0f6a9a85ad5dc3f280d91224ba9911a3653d00e484e2ce232521481c8658df304bb7745a73514cdb9bf3e15784ab71284f8d0704a608c54a6b62d97beb511d132100"));
+        assert!(key
+            .verifying_key()
+            .verify_prehashed_with_context(b"foo", Shake256::default().chain(b"abc"), &sig)
+            .is_ok());
+    }
+
+    #[test]
+    fn context_length() {
+        let key = SigningKey::generate(&mut OsRng);
+        let context = [0u8; 256];
+        assert!(key.sign_with_context(&context[..255], b"msg").is_ok());
+        assert_eq!(
+            key.sign_with_context(&context, b"msg"),
+            Err(SigningError::InvalidContextLength)
+        );
+        assert_eq!(
+            key.sign_prehashed_with_context(&context, Shake256::default()),
+            Err(SigningError::InvalidContextLength)
+        );
+        let sig = key.sign(b"msg");
+        assert_eq!(
+            key.verifying_key()
+                .verify_with_context(&context, b"msg", &sig),
+            Err(SigningError::InvalidContextLength)
+        );
+    }
+
+    #[test]
+    fn sign_verify() {
+        let key = SigningKey::generate(&mut OsRng);
+        let sig = key.sign(b"hello");
+        assert_eq!(sig, key.sign(b"hello"));
+        assert!(VerifyingKey::from(&key).verify(b"hello", &sig).is_ok());
+        assert_eq!(key.verify(b"hello!", &sig), Err(SigningError::Verify));
+        let other = SigningKey::generate(&mut OsRng);
+        assert_eq!(other.verify(b"hello", &sig), Err(SigningError::Verify));
+    }
+
+    #[test]
+    fn derive_from_ikm() {
+        let ikm = [7u8; 64];
+        let key = SigningKey::derive_from_ikm(&ikm, b"device 1");
+        assert_eq!(key, SigningKey::derive_from_ikm(&ikm, b"device 1"));
+        assert_ne!(key, SigningKey::derive_from_ikm(&ikm, b"device 2"));
+        assert_ne!(key, SigningKey::derive_from_ikm(&[8u8; 64], b"device 1"));
+        // Moving bytes between ikm and info changes the key
+        assert_ne!(
+            SigningKey::derive_from_ikm(&ikm[..63], &[7u8]),
+            SigningKey::derive_from_ikm(&ikm, &[])
+        );
+        assert_eq!(
+            key.verifying_key().to_edwards(),
+            EdwardsPoint::GENERATOR * key.to_scalar()
+        );
+    }
+
+    #[test]
+    fn generate() {
+        let key = SigningKey::generate(&mut OsRng);
+        assert_eq!(SigningKey::from_bytes(key.as_bytes()), key);
+        assert_ne!(SigningKey::generate(&mut OsRng), key);
+    }
+}
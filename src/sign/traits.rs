@@ -0,0 +1,170 @@
+//! Implementations of the RustCrypto `signature` traits.
+use crate::sign::{
+    derive_nonce, prehash, Signature, SigningError, SigningKey, VerifyingKey, SECRET_KEY_LENGTH,
+    SIGNATURE_LENGTH,
+};
+use crypto_signature::{
+    rand_core::CryptoRngCore, DigestSigner, DigestVerifier, Error, RandomizedSigner,
+    SignatureEncoding, Signer, Verifier,
+};
+use sha3::{
+    digest::{
+        consts::U64, ExtendableOutput, FixedOutput, HashMarker, Output, OutputSizeUser, Update,
+        XofReader,
+    },
+    Shake256,
+};
+
+/// The Ed448ph prehash `PH(M) = SHAKE256(M, 64)` as a fixed-output [`Digest`](sha3::Digest).
+///
+/// [`DigestSigner`] and [`DigestVerifier`] take a message digest rather than the message,
+/// this is the digest they expect. Absorb the message into it and hand it over.
+#[derive(Clone, Debug, Default)]
+pub struct PreHasher(Shake256);
+
+impl From<Shake256> for PreHasher {
+    fn from(hasher: Shake256) -> Self {
+        Self(hasher)
+    }
+}
+
+impl Update for PreHasher {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl OutputSizeUser for PreHasher {
+    type OutputSize = U64;
+}
+
+impl FixedOutput for PreHasher {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.0.finalize_xof().read(out);
+    }
+}
+
+impl HashMarker for PreHasher {}
+
+impl From<SigningError> for Error {
+    fn from(err: SigningError) -> Self {
+        Error::from_source(err)
+    }
+}
+
+impl SignatureEncoding for Signature {
+    type Repr = [u8; SIGNATURE_LENGTH];
+}
+
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        Ok(self.sign_inner(0, &[], msg)?)
+    }
+}
+
+/// Hedged signing: the nonce also depends on fresh randomness, which protects
+/// against fault attacks on the deterministic nonce. The signatures verify as usual.
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, Error> {
+        let mut randomness = [0u8; SECRET_KEY_LENGTH];
+        rng.try_fill_bytes(&mut randomness)?;
+        let mut prefix = [0u8; SECRET_KEY_LENGTH];
+        Shake256::default()
+            .chain(self.prefix)
+            .chain(randomness)
+            .finalize_xof()
+            .read(&mut prefix);
+
+        let r = derive_nonce(false, &[], &prefix, msg)?;
+        let signature = self.sign_with_nonce(0, &[], msg, r);
+        #[cfg(feature = "zeroize")]
+        {
+            zeroize::Zeroize::zeroize(&mut randomness);
+            zeroize::Zeroize::zeroize(&mut prefix);
+        }
+        Ok(signature)
+    }
+}
+
+impl DigestSigner<PreHasher, Signature> for SigningKey {
+    fn try_sign_digest(&self, digest: PreHasher) -> Result<Signature, Error> {
+        Ok(self.sign_inner(1, &[], &prehash(digest.0))?)
+    }
+}
+
+impl Verifier<Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        Ok(self.verify_inner(0, &[], msg, signature)?)
+    }
+}
+
+impl DigestVerifier<PreHasher, Signature> for VerifyingKey {
+    fn verify_digest(&self, digest: PreHasher, signature: &Signature) -> Result<(), Error> {
+        Ok(self.verify_inner(1, &[], &prehash(digest.0), signature)?)
+    }
+}
+
+impl Verifier<Signature> for SigningKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        Verifier::verify(&self.verifying_key, msg, signature)
+    }
+}
+
+impl DigestVerifier<PreHasher, Signature> for SigningKey {
+    fn verify_digest(&self, digest: PreHasher, signature: &Signature) -> Result<(), Error> {
+        self.verifying_key.verify_digest(digest, signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sign::PREHASH_LENGTH;
+    use rand_core::OsRng;
+    use sha3::Digest;
+
+    #[test]
+    fn signer_verifier() {
+        let key = SigningKey::generate(&mut OsRng);
+        let vk = key.verifying_key();
+
+        let sig: Signature = Signer::sign(&key, b"msg");
+        assert_eq!(sig, key.sign(b"msg"));
+        assert!(Verifier::verify(&vk, b"msg", &sig).is_ok());
+        assert!(Verifier::verify(&key, b"msg", &sig).is_ok());
+        assert!(Verifier::verify(&vk, b"other", &sig).is_err());
+
+        let hedged = key.sign_with_rng(&mut OsRng, b"msg");
+        assert_ne!(hedged, sig);
+        assert!(Verifier::verify(&vk, b"msg", &hedged).is_ok());
+
+        assert_eq!(SignatureEncoding::to_bytes(&sig), sig.to_bytes());
+    }
+
+    #[test]
+    fn digest_signer_verifier() {
+        let key = SigningKey::generate(&mut OsRng);
+        let vk = key.verifying_key();
+
+        let sig = key.sign_digest(PreHasher::new().chain_update(b"abc"));
+        assert_eq!(sig, key.sign_prehashed(Shake256::default().chain(b"abc")));
+        assert!(vk
+            .verify_digest(PreHasher::from(Shake256::default().chain(b"abc")), &sig)
+            .is_ok());
+        assert!(key
+            .verify_digest(PreHasher::new().chain_update(b"abd"), &sig)
+            .is_err());
+
+        // The digest is the 64-byte SHAKE256 output
+        let mut ph = [0u8; PREHASH_LENGTH];
+        Shake256::default()
+            .chain(b"abc")
+            .finalize_xof()
+            .read(&mut ph);
+        assert_eq!(PreHasher::digest(b"abc")[..], ph[..]);
+    }
+}
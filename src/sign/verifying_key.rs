@@ -0,0 +1,314 @@
+use crate::curve::scalar_mul::{
+    basepoint_naf_table, vartime_double_base,
+    window::wnaf::{NafLookupTable5, NafLookupTable8},
+};
+use crate::sign::{challenge, prehash, Signature, SigningError, PUBLIC_KEY_LENGTH};
+use crate::{CompressedEdwardsY, EdwardsPoint};
+use sha3::Shake256;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// An Ed448 public key.
+///
+/// The decompressed point is cached, so repeated verifications don't pay for decompression.
+/// Keys that verify many signatures can additionally carry a wNAF table for `-A`,
+/// see [`VerifyingKey::with_precomputation`].
+#[derive(Clone)]
+pub struct VerifyingKey {
+    pub(crate) compressed: CompressedEdwardsY,
+    pub(crate) point: EdwardsPoint,
+    /// Odd multiples of `-A`, mapped to the twisted curve
+    precomputed: Option<Box<NafLookupTable8>>,
+}
+
+impl Debug for VerifyingKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifyingKey")
+            .field("compressed", &self.compressed)
+            .field("precomputed", &self.precomputed.is_some())
+            .finish()
+    }
+}
+
+impl PartialEq for VerifyingKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.compressed == other.compressed
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+impl Hash for VerifyingKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.compressed.as_bytes().hash(state)
+    }
+}
+
+impl AsRef<[u8]> for VerifyingKey {
+    fn as_ref(&self) -> &[u8] {
+        self.compressed.as_bytes()
+    }
+}
+
+impl TryFrom<&[u8; PUBLIC_KEY_LENGTH]> for VerifyingKey {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <&[u8; PUBLIC_KEY_LENGTH]>::try_from(bytes)
+            .map_err(|_| SigningError::InvalidPublicKeyBytes)?;
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<VerifyingKey> for EdwardsPoint {
+    fn from(key: VerifyingKey) -> Self {
+        key.point
+    }
+}
+
+impl VerifyingKey {
+    pub(crate) fn from_point(point: EdwardsPoint) -> Self {
+        Self {
+            compressed: point.compress(),
+            point,
+            precomputed: None,
+        }
+    }
+
+    /// Decode a public key from its 57-byte encoding.
+    ///
+    /// Only the canonical encoding of a point is accepted, as RFC 8032 § 5.2.3 requires,
+    /// since these bytes are hashed into the challenge.
+    pub fn from_bytes(bytes: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self, SigningError> {
+        let compressed = CompressedEdwardsY(*bytes);
+        let point = Option::<EdwardsPoint>::from(compressed.decompress())
+            .filter(|point| point.compress() == compressed)
+            .ok_or(SigningError::InvalidPublicKeyBytes)?;
+        Ok(Self {
+            compressed,
+            point,
+            precomputed: None,
+        })
+    }
+
+    /// The 57-byte encoding of this key
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_LENGTH] {
+        self.compressed.to_bytes()
+    }
+
+    /// View the 57-byte encoding of this key
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_LENGTH] {
+        self.compressed.as_bytes()
+    }
+
+    /// The public key as a point
+    pub fn to_edwards(&self) -> EdwardsPoint {
+        self.point
+    }
+
+    /// Precompute a width-8 wNAF table for `-A`.
+    ///
+    /// Building the table costs 64 point additions and takes around 14KiB,
+    /// in exchange each later call to [`VerifyingKey::verify`] skips building
+    /// a small table and needs about a third fewer additions for the `[k]A` term.
+    /// This pays off for long-lived keys that check many signatures.
+    pub fn with_precomputation(mut self) -> Self {
+        if self.precomputed.is_none() {
+            let minus_a = self.point.negate().to_twisted();
+            self.precomputed = Some(Box::new(NafLookupTable8::from(&minus_a)));
+        }
+        self
+    }
+
+    /// Verify a pure Ed448 signature on `msg` with an empty context.
+    ///
+    /// This checks the cofactored equation `[4][S]B = [4]R + [4][k]A` and runs in variable time,
+    /// which is fine as all of its inputs are public.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), SigningError> {
+        self.verify_inner(0, &[], msg, signature)
+    }
+
+    /// Verify a pure Ed448 signature on `msg` made with `context`.
+    pub fn verify_with_context(
+        &self,
+        context: &[u8],
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        self.verify_inner(0, context, msg, signature)
+    }
+
+    /// Verify an Ed448ph signature on the message absorbed by `prehashed`, with an empty context.
+    pub fn verify_prehashed(
+        &self,
+        prehashed: Shake256,
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        self.verify_inner(1, &[], &prehash(prehashed), signature)
+    }
+
+    /// Verify an Ed448ph signature on the message absorbed by `prehashed`, made with `context`.
+    pub fn verify_prehashed_with_context(
+        &self,
+        context: &[u8],
+        prehashed: Shake256,
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        self.verify_inner(1, context, &prehash(prehashed), signature)
+    }
+
+    pub(crate) fn verify_inner(
+        &self,
+        phflag: u8,
+        context: &[u8],
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        if context.len() > 255 {
+            return Err(SigningError::InvalidContextLength);
+        }
+        let r = Option::<EdwardsPoint>::from(signature.r.decompress())
+            .ok_or(SigningError::InvalidSignatureRComponent)?;
+
+        let k = challenge(phflag, context, &signature.r, &self.compressed, msg);
+
+        // Compute [S]phi(B) - [k]phi(A) on the twisted curve, where the dual isogeny
+        // brings it back as [4]([S]B - [k]A)
+        let sb_minus_ka = match &self.precomputed {
+            Some(table) => {
+                vartime_double_base(&k, table.as_ref(), &signature.s, basepoint_naf_table())
+            }
+            None => {
+                let table = NafLookupTable5::from(&self.point.negate().to_twisted());
+                vartime_double_base(&k, &table, &signature.s, basepoint_naf_table())
+            }
+        };
+
+        if sb_minus_ka.to_untwisted().vartime_eq(&r.double().double()) {
+            Ok(())
+        } else {
+            Err(SigningError::Verify)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestVector {
+        q: &'static str,
+        m: &'static str,
+        sig: &'static str,
+    }
+
+    // Test vectors from RFC 8032 § 7.4 with an empty context
+    const TEST_VECTORS: [TestVector; 3] = [
+        TestVector {
+            q: "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180",
+            m: "",
+            sig: "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4dbb61149f05a7363268c71d95808ff2e652600",
+        },
+        TestVector {
+            q: "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+            m: "03",
+            sig: "26b8f91727bd62897af15e41eb43c377efb9c610d48f2335cb0bd0087810f4352541b143c4b981b7e18f62de8ccdf633fc1bf037ab7cd779805e0dbcc0aae1cbcee1afb2e027df36bc04dcecbf154336c19f0af7e0a6472905e799f1953d2a0ff3348ab21aa4adafd1d234441cf807c03a00",
+        },
+        TestVector {
+            q: "79756f014dcfe2079f5dd9e718be4171e2ef2486a08f25186f6bff43a9936b9bfe12402b08ae65798a3d81e22e9ec80e7690862ef3d4ed3a00",
+            m: "15777532b0bdd0d1389f636c5f6b9ba734c90af572877e2d272dd078aa1e567cfa80e12928bb542330e8409f3174504107ecd5efac61ae7504dabe2a602ede89e5cca6257a7c77e27a702b3ae39fc769fc54f2395ae6a1178cab4738e543072fc1c177fe71e92e25bf03e4ecb72f47b64d0465aaea4c7fad372536c8ba516a6039c3c2a39f0e4d832be432dfa9a706a6e5c7e19f397964ca4258002f7c0541b590316dbc5622b6b2a6fe7a4abffd96105eca76ea7b98816af0748c10df048ce012d901015a51f189f3888145c03650aa23ce894c3bd889e030d565071c59f409a9981b51878fd6fc110624dcbcde0bf7a69ccce38fabdf86f3bef6044819de11",
+            sig: "c650ddbb0601c19ca11439e1640dd931f43c518ea5bea70d3dcde5f4191fe53f00cf966546b72bcc7d58be2b9badef28743954e3a44a23f880e8d4f1cfce2d7a61452d26da05896f0a50da66a239a8a188b6d825b3305ad77b73fbac0836ecc60987fd08527c1a8e80d5823e65cafe2a3d00",
+        },
+    ];
+
+    fn decode(tv: &TestVector) -> (VerifyingKey, Vec<u8>, Signature) {
+        let key = VerifyingKey::try_from(&hex::decode(tv.q).unwrap()[..]).unwrap();
+        let msg = hex::decode(tv.m).unwrap();
+        let sig = Signature::try_from(hex::decode(tv.sig).unwrap()).unwrap();
+        (key, msg, sig)
+    }
+
+    #[test]
+    fn rfc8032_vectors() {
+        for tv in TEST_VECTORS.iter() {
+            let (key, msg, sig) = decode(tv);
+            assert_eq!(hex::encode(key.as_bytes()), tv.q);
+            assert!(key.verify(&msg, &sig).is_ok());
+
+            let mut bad_msg = msg.clone();
+            bad_msg.push(0);
+            assert_eq!(key.verify(&bad_msg, &sig), Err(SigningError::Verify));
+        }
+    }
+
+    #[test]
+    fn precomputed_matches() {
+        let keys = TEST_VECTORS
+            .iter()
+            .map(|tv| decode(tv).0.with_precomputation())
+            .collect::<Vec<_>>();
+
+        for (i, tv) in TEST_VECTORS.iter().enumerate() {
+            let (key, msg, sig) = decode(tv);
+            assert_eq!(key, keys[i]);
+            for (j, precomputed) in keys.iter().enumerate() {
+                assert_eq!(precomputed.verify(&msg, &sig).is_ok(), i == j);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert_eq!(
+            VerifyingKey::try_from(&[0u8; 56][..]),
+            Err(SigningError::InvalidPublicKeyBytes)
+        );
+        let mut bytes = [0u8; PUBLIC_KEY_LENGTH];
+        bytes[0] = 2;
+        assert_eq!(
+            VerifyingKey::from_bytes(&bytes),
+            Err(SigningError::InvalidPublicKeyBytes)
+        );
+    }
+
+    #[test]
+    fn rejects_non_canonical_keys() {
+        let key = decode(&TEST_VECTORS[0]).0;
+
+        // Junk in the reserved bits next to the sign bit
+        for bit in 0..7 {
+            let mut bytes = key.to_bytes();
+            bytes[56] |= 1 << bit;
+            assert_eq!(
+                VerifyingKey::from_bytes(&bytes),
+                Err(SigningError::InvalidPublicKeyBytes)
+            );
+        }
+
+        // The identity with the sign bit set decompresses to the identity
+        let mut bytes = EdwardsPoint::IDENTITY.compress().to_bytes();
+        bytes[56] = 0x80;
+        assert!(bool::from(CompressedEdwardsY(bytes).decompress().is_some()));
+        assert_eq!(
+            VerifyingKey::from_bytes(&bytes),
+            Err(SigningError::InvalidPublicKeyBytes)
+        );
+
+        // y = p + 1 = 2^448 - 2^224, which decodes to the identity
+        let mut bytes = [0u8; PUBLIC_KEY_LENGTH];
+        bytes[28..56].fill(0xff);
+        assert!(bool::from(CompressedEdwardsY(bytes).decompress().is_some()));
+        assert_eq!(
+            VerifyingKey::from_bytes(&bytes),
+            Err(SigningError::InvalidPublicKeyBytes)
+        );
+    }
+}
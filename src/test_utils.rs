@@ -0,0 +1,186 @@
+//! Deterministic generators for tests.
+//!
+//! Everything here is derived from a `u64` seed, so a failing test can be
+//! reproduced exactly. None of it is suitable for generating real keys.
+use crate::constants::BASEPOINT_ORDER;
+use crate::field::FieldElement;
+use crate::{AffinePoint, CompressedDecaf, CompressedEdwardsY, EdwardsPoint, Scalar, ScalarBytes};
+use rand_core::{CryptoRng, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256, Shake256Reader,
+};
+
+/// A seeded random number generator backed by SHAKE256.
+///
+/// It implements [`CryptoRng`] so it can be passed to functions like [`Scalar::random`],
+/// but the output is fully determined by the seed.
+pub struct TestRng(Shake256Reader);
+
+impl TestRng {
+    /// Create a generator from `seed`
+    pub fn from_seed(seed: u64) -> Self {
+        let reader = Shake256::default()
+            .chain(b"ed448-goldilocks-plus test rng")
+            .chain(seed.to_le_bytes())
+            .finalize_xof();
+        Self(reader)
+    }
+}
+
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.0.read(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.0.read(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.read(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.read(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+/// A uniformly random scalar
+pub fn scalar(rng: &mut TestRng) -> Scalar {
+    Scalar::random(rng)
+}
+
+/// A random point in the prime-order subgroup
+pub fn torsion_free_point(rng: &mut TestRng) -> EdwardsPoint {
+    EdwardsPoint::GENERATOR * scalar(rng)
+}
+
+/// A random point with a non-zero torsion component
+pub fn torsioned_point(rng: &mut TestRng) -> EdwardsPoint {
+    let torsion = small_order_points();
+    let i = 1 + (rng.next_u32() % 3) as usize;
+    torsion_free_point(rng) + torsion[i]
+}
+
+/// The four points of order dividing 4: the identity, `(0, -1)` of order 2 and `(±1, 0)` of order 4
+pub fn small_order_points() -> [EdwardsPoint; 4] {
+    [
+        EdwardsPoint::IDENTITY,
+        AffinePoint {
+            x: FieldElement::ZERO,
+            y: FieldElement::MINUS_ONE,
+        }
+        .to_edwards(),
+        AffinePoint {
+            x: FieldElement::ONE,
+            y: FieldElement::ZERO,
+        }
+        .to_edwards(),
+        AffinePoint {
+            x: FieldElement::MINUS_ONE,
+            y: FieldElement::ZERO,
+        }
+        .to_edwards(),
+    ]
+}
+
+/// Encodings that [`CompressedEdwardsY::decompress`] rejects
+pub fn invalid_edwards_encodings() -> Vec<CompressedEdwardsY> {
+    // y = 2 is not on the curve
+    let mut not_on_curve = [0u8; 57];
+    not_on_curve[0] = 2;
+
+    let mut encodings = vec![CompressedEdwardsY(not_on_curve)];
+    // Points with a torsion component
+    encodings.extend(small_order_points()[1..].iter().map(|p| p.compress()));
+    encodings.push((EdwardsPoint::GENERATOR + small_order_points()[1]).compress());
+    encodings
+}
+
+/// Encodings that [`Scalar::from_canonical_bytes`] rejects
+pub fn invalid_scalar_encodings() -> Vec<ScalarBytes> {
+    let order = BASEPOINT_ORDER.to_bytes_rfc_8032();
+
+    let mut order_plus_one = order;
+    order_plus_one[0] += 1;
+
+    let mut high_byte = ScalarBytes::default();
+    high_byte[56] = 1;
+
+    vec![
+        order,
+        order_plus_one,
+        high_byte,
+        ScalarBytes::from([0xff; 57]),
+    ]
+}
+
+/// Encodings that [`CompressedDecaf::decompress`] rejects
+pub fn invalid_decaf_encodings() -> Vec<CompressedDecaf> {
+    // s = 1 is negative
+    let mut negative = [0u8; 56];
+    negative[0] = 1;
+
+    // s = p is not canonical
+    let mut non_canonical = [0xff; 56];
+    non_canonical[28] = 0xfe;
+
+    vec![
+        CompressedDecaf(negative),
+        CompressedDecaf(non_canonical),
+        CompressedDecaf([0xff; 56]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let a = scalar(&mut TestRng::from_seed(1));
+        assert_eq!(a, scalar(&mut TestRng::from_seed(1)));
+        assert_ne!(a, scalar(&mut TestRng::from_seed(2)));
+
+        let mut rng = TestRng::from_seed(7);
+        let p = torsion_free_point(&mut rng);
+        assert_eq!(p, torsion_free_point(&mut TestRng::from_seed(7)));
+        assert_eq!(p.is_torsion_free().unwrap_u8(), 1);
+        assert_eq!(torsioned_point(&mut rng).is_torsion_free().unwrap_u8(), 0);
+    }
+
+    #[test]
+    fn small_order() {
+        let points = small_order_points();
+        for p in &points {
+            assert_eq!(p.is_on_curve().unwrap_u8(), 1);
+            assert_eq!(p.double().double(), EdwardsPoint::IDENTITY);
+        }
+        assert_eq!(points[1].double(), EdwardsPoint::IDENTITY);
+        assert_ne!(points[2].double(), EdwardsPoint::IDENTITY);
+        assert_ne!(points[3].double(), EdwardsPoint::IDENTITY);
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        for encoding in invalid_edwards_encodings() {
+            assert!(bool::from(encoding.decompress().is_none()));
+        }
+        for encoding in invalid_scalar_encodings() {
+            assert!(bool::from(
+                Scalar::from_canonical_bytes(&encoding).is_none()
+            ));
+        }
+        for encoding in invalid_decaf_encodings() {
+            assert!(bool::from(encoding.decompress().is_none()));
+        }
+    }
+}
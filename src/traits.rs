@@ -0,0 +1,298 @@
+//! Traits for multiscalar multiplication, in the shape of `curve25519-dalek`'s.
+//!
+//! They are implemented for [`EdwardsPoint`] and [`DecafPoint`] and take any iterators of
+//! scalars and points, so that aggregation code can be written once for either group.
+//! The inherent [`EdwardsPoint::multiscalar_mul`] takes slices and shadows the trait method
+//! of the same name, call the trait one as `<EdwardsPoint as MultiscalarMul>::multiscalar_mul`.
+//!
+//! The `Vartime` traits run in variable time, with the same caveats as the [`crate::vartime`] module.
+use crate::{DecafPoint, EdwardsPoint, Scalar};
+use core::borrow::Borrow;
+
+/// The sum of `scalars[i] * points[i]` in constant time.
+pub trait MultiscalarMul {
+    /// The type of point being multiplied
+    type Point;
+
+    /// Compute the sum of `scalars[i] * points[i]` in constant time.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::Point>;
+}
+
+/// The sum of `scalars[i] * points[i]` in variable time.
+pub trait VartimeMultiscalarMul {
+    /// The type of point being multiplied
+    type Point;
+
+    /// Compute the sum of `scalars[i] * points[i]` in variable time,
+    /// or `None` if any of the points is `None`.
+    ///
+    /// This lets a caller decompress points lazily and give up on the first invalid one.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<Self::Point>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<Self::Point>>;
+
+    /// Compute the sum of `scalars[i] * points[i]` in variable time.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    fn vartime_multiscalar_mul<I, J>(scalars: I, points: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::Point>,
+        Self::Point: Clone,
+    {
+        Self::optional_multiscalar_mul(
+            scalars,
+            points.into_iter().map(|p| Some(p.borrow().clone())),
+        )
+        .expect("every point is present")
+    }
+}
+
+/// Variable-time multiscalar multiplication where some of the points are known in
+/// advance, and have larger tables precomputed for them.
+///
+/// Only worth it when the same static points are used in many multiplications.
+pub trait VartimePrecomputedMultiscalarMul: Sized {
+    /// The type of point being multiplied
+    type Point: Clone;
+
+    /// Precompute the tables for `static_points`.
+    fn new<I>(static_points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Point>;
+
+    /// Compute the sum of `static_scalars[i] * static_points[i]` in variable time.
+    ///
+    /// # Panics
+    ///
+    /// If there is not one scalar per static point.
+    fn vartime_multiscalar_mul<I>(&self, static_scalars: I) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+    {
+        self.vartime_mixed_multiscalar_mul(
+            static_scalars,
+            core::iter::empty::<Scalar>(),
+            core::iter::empty::<Self::Point>(),
+        )
+    }
+
+    /// Compute the sum of `static_scalars[i] * static_points[i]` plus
+    /// `dynamic_scalars[i] * dynamic_points[i]` in variable time.
+    ///
+    /// # Panics
+    ///
+    /// If there is not one scalar per static point, or `dynamic_scalars` and
+    /// `dynamic_points` have different lengths.
+    fn vartime_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator,
+        K::Item: Borrow<Self::Point>,
+    {
+        self.optional_mixed_multiscalar_mul(
+            static_scalars,
+            dynamic_scalars,
+            dynamic_points.into_iter().map(|p| Some(p.borrow().clone())),
+        )
+        .expect("every point is present")
+    }
+
+    /// As [`Self::vartime_mixed_multiscalar_mul`], but `None` if any of the dynamic points is `None`.
+    ///
+    /// # Panics
+    ///
+    /// If there is not one scalar per static point, or `dynamic_scalars` and
+    /// `dynamic_points` have different lengths.
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<Self::Point>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<Self::Point>>;
+}
+
+impl MultiscalarMul for EdwardsPoint {
+    type Point = EdwardsPoint;
+
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        #[allow(unused_mut)]
+        let mut scalars = scalars.into_iter().map(|s| *s.borrow()).collect::<Vec<_>>();
+        let points = points.into_iter().map(|p| *p.borrow()).collect::<Vec<_>>();
+        let result = EdwardsPoint::multiscalar_mul(&scalars, &points);
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut scalars);
+
+        result
+    }
+}
+
+impl MultiscalarMul for DecafPoint {
+    type Point = DecafPoint;
+
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> DecafPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<DecafPoint>,
+    {
+        #[allow(unused_mut)]
+        let mut scalars = scalars.into_iter().map(|s| *s.borrow()).collect::<Vec<_>>();
+        let points = points.into_iter().map(|p| *p.borrow()).collect::<Vec<_>>();
+        let result = DecafPoint::sum_of_products(&scalars, &points);
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut scalars);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vartime::{VartimeDecafPrecomputation, VartimeEdwardsPrecomputation};
+    use rand_core::OsRng;
+
+    fn random_scalars(n: usize) -> Vec<Scalar> {
+        (0..n).map(|_| Scalar::random(&mut OsRng)).collect()
+    }
+
+    #[test]
+    fn edwards() {
+        let scalars = random_scalars(6);
+        let points = (1..7u8)
+            .map(|i| EdwardsPoint::GENERATOR * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let expected = EdwardsPoint::multiscalar_mul(&scalars, &points);
+
+        assert_eq!(
+            <EdwardsPoint as MultiscalarMul>::multiscalar_mul(&scalars, &points),
+            expected
+        );
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul(scalars.iter(), points.iter()),
+            expected
+        );
+        assert_eq!(
+            EdwardsPoint::optional_multiscalar_mul(&scalars, points.iter().map(|p| Some(*p))),
+            Some(expected)
+        );
+        let mut missing = points.iter().map(|p| Some(*p)).collect::<Vec<_>>();
+        missing[3] = None;
+        assert_eq!(
+            EdwardsPoint::optional_multiscalar_mul(&scalars, missing),
+            None
+        );
+
+        let precomputation = VartimeEdwardsPrecomputation::new(&points[..4]);
+        assert_eq!(
+            precomputation.vartime_mixed_multiscalar_mul(
+                &scalars[..4],
+                &scalars[4..],
+                &points[4..]
+            ),
+            expected
+        );
+        let static_only = EdwardsPoint::multiscalar_mul(&scalars[..4], &points[..4]);
+        assert_eq!(
+            precomputation.vartime_multiscalar_mul(&scalars[..4]),
+            static_only
+        );
+    }
+
+    #[test]
+    fn decaf() {
+        let scalars = random_scalars(6);
+        let points = (1..7u8)
+            .map(|i| DecafPoint::GENERATOR * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .map(|(s, p)| *p * s)
+            .fold(DecafPoint::IDENTITY, |acc, p| acc + p);
+
+        assert_eq!(DecafPoint::multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(
+            DecafPoint::vartime_multiscalar_mul(&scalars, &points),
+            expected
+        );
+        assert_eq!(
+            DecafPoint::optional_multiscalar_mul(&scalars, [None; 6]),
+            None
+        );
+
+        let precomputation = VartimeDecafPrecomputation::new(&points[..2]);
+        assert_eq!(
+            precomputation.vartime_mixed_multiscalar_mul(
+                &scalars[..2],
+                &scalars[2..],
+                &points[2..]
+            ),
+            expected
+        );
+        assert_eq!(
+            DecafPoint::multiscalar_mul(
+                core::iter::empty::<Scalar>(),
+                core::iter::empty::<DecafPoint>()
+            ),
+            DecafPoint::IDENTITY
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn decaf_precomputation_rejects_extra_scalars() {
+        let scalars = random_scalars(3);
+        let precomputation = VartimeDecafPrecomputation::new([DecafPoint::GENERATOR; 2]);
+        precomputation.vartime_mixed_multiscalar_mul(
+            &scalars,
+            core::iter::empty::<Scalar>(),
+            core::iter::empty::<DecafPoint>(),
+        );
+    }
+}
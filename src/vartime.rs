@@ -0,0 +1,354 @@
+//! Variable-time operations.
+//!
+//! Everything in this module runs in time that depends on its inputs, and must only
+//! be used when the scalars and points are public, such as in signature verification.
+//! The rest of the crate is constant time unless a function says otherwise, so
+//! a `vartime::` path at a call site is the thing to look for when auditing for timing leaks.
+//!
+//! Scalar multiplications here use width-5 NAFs, or a width-8 NAF table for the generator,
+//! on the twisted curve. As in [`EdwardsPoint::scalar_mul`] the scalar is split as `4q + r`,
+//! so points with a torsion component are multiplied exactly.
+//!
+//! The [`VartimeMultiscalarMul`] and [`VartimePrecomputedMultiscalarMul`] traits are
+//! implemented here, for [`EdwardsPoint`] and [`DecafPoint`].
+use crate::curve::scalar_mul::{
+    basepoint_naf_table, vartime_pippenger, vartime_straus,
+    window::wnaf::{NafLookupTable5, NafLookupTable8},
+};
+use crate::curve::twedwards::extended::ExtendedPoint;
+use crate::traits::{VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
+use crate::{DecafPoint, EdwardsPoint, Scalar};
+use core::borrow::Borrow;
+
+/// Compute `scalar * point` in variable time.
+pub fn mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
+    multiscalar_mul(&[*scalar], &[*point])
+}
+
+/// Compute `scalar * G` in variable time, using a cached table for the generator.
+pub fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+    let (q, r) = split(scalar);
+    vartime_straus(&[], &[(q, basepoint_naf_table())])
+        .to_untwisted()
+        .add(&small_multiple(&EdwardsPoint::GENERATOR, r))
+}
+
+/// Compute `a * A + b * G` in variable time.
+pub fn double_scalar_mul_basepoint(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
+    let (qa, ra) = split(a);
+    let (qb, rb) = split(b);
+    let terms = [(qa, NafLookupTable5::from(&A.to_twisted()))];
+    vartime_straus(&terms, &[(qb, basepoint_naf_table())])
+        .to_untwisted()
+        .add(&small_multiple(A, ra))
+        .add(&small_multiple(&EdwardsPoint::GENERATOR, rb))
+}
+
+/// From this many points on, [`multiscalar_mul`] uses Pippenger's method instead of Straus'
+const PIPPENGER_THRESHOLD: usize = 500;
+
+/// Compute the sum of `scalars[i] * points[i]` in variable time.
+///
+/// Small sums use Straus' method with a table per point, and sums of at least
+/// 500 points use Pippenger's bucket method.
+///
+/// # Panics
+///
+/// If `scalars` and `points` have different lengths.
+pub fn multiscalar_mul(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+    assert_eq!(scalars.len(), points.len());
+
+    let mut remainder = EdwardsPoint::IDENTITY;
+    let quotients = scalars
+        .iter()
+        .zip(points.iter())
+        .map(|(s, p)| {
+            let (q, r) = split(s);
+            remainder = remainder.add(&small_multiple(p, r));
+            q
+        })
+        .collect::<Vec<_>>();
+
+    let twisted = points.iter().map(|p| p.to_twisted()).collect::<Vec<_>>();
+    twisted_multiscalar_mul(&quotients, &twisted)
+        .to_untwisted()
+        .add(&remainder)
+}
+
+/// The sum of `scalars[i] * points[i]` on the twisted curve, by Straus' or Pippenger's method
+fn twisted_multiscalar_mul(scalars: &[Scalar], points: &[ExtendedPoint]) -> ExtendedPoint {
+    if points.len() >= PIPPENGER_THRESHOLD {
+        vartime_pippenger(scalars, points)
+    } else {
+        vartime_straus(&naf_terms(scalars, points), &[])
+    }
+}
+
+fn naf_terms(scalars: &[Scalar], points: &[ExtendedPoint]) -> Vec<(Scalar, NafLookupTable5)> {
+    scalars
+        .iter()
+        .zip(points.iter())
+        .map(|(s, p)| (*s, NafLookupTable5::from(p)))
+        .collect()
+}
+
+impl VartimeMultiscalarMul for EdwardsPoint {
+    type Point = EdwardsPoint;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let scalars = scalars.into_iter().map(|s| *s.borrow()).collect::<Vec<_>>();
+        let points = points.into_iter().collect::<Option<Vec<_>>>()?;
+        Some(multiscalar_mul(&scalars, &points))
+    }
+}
+
+impl VartimeMultiscalarMul for DecafPoint {
+    type Point = DecafPoint;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<DecafPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<DecafPoint>>,
+    {
+        let scalars = scalars.into_iter().map(|s| *s.borrow()).collect::<Vec<_>>();
+        let points = points
+            .into_iter()
+            .map(|p| p.map(|p| p.0))
+            .collect::<Option<Vec<_>>>()?;
+        assert_eq!(scalars.len(), points.len());
+        Some(DecafPoint(twisted_multiscalar_mul(&scalars, &points)))
+    }
+}
+
+/// Width-8 NAF tables for a fixed set of [`EdwardsPoint`]s, see [`VartimePrecomputedMultiscalarMul`].
+pub struct VartimeEdwardsPrecomputation {
+    points: Vec<EdwardsPoint>,
+    tables: Vec<NafLookupTable8>,
+}
+
+impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
+    type Point = EdwardsPoint;
+
+    fn new<I>(static_points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<EdwardsPoint>,
+    {
+        let points = static_points
+            .into_iter()
+            .map(|p| *p.borrow())
+            .collect::<Vec<_>>();
+        let tables = points
+            .iter()
+            .map(|p| NafLookupTable8::from(&p.to_twisted()))
+            .collect();
+        Self { points, tables }
+    }
+
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let static_scalars = static_scalars
+            .into_iter()
+            .map(|s| *s.borrow())
+            .collect::<Vec<_>>();
+        let dynamic_scalars = dynamic_scalars
+            .into_iter()
+            .map(|s| *s.borrow())
+            .collect::<Vec<_>>();
+        let dynamic_points = dynamic_points.into_iter().collect::<Option<Vec<_>>>()?;
+        assert_eq!(static_scalars.len(), self.points.len());
+        assert_eq!(dynamic_scalars.len(), dynamic_points.len());
+
+        let mut remainder = EdwardsPoint::IDENTITY;
+        let static_terms = static_scalars
+            .iter()
+            .zip(self.points.iter().zip(self.tables.iter()))
+            .map(|(s, (p, table))| {
+                let (q, r) = split(s);
+                remainder = remainder.add(&small_multiple(p, r));
+                (q, table)
+            })
+            .collect::<Vec<_>>();
+        let dynamic_terms = dynamic_scalars
+            .iter()
+            .zip(dynamic_points.iter())
+            .map(|(s, p)| {
+                let (q, r) = split(s);
+                remainder = remainder.add(&small_multiple(p, r));
+                (q, NafLookupTable5::from(&p.to_twisted()))
+            })
+            .collect::<Vec<_>>();
+
+        let partial_result = vartime_straus(&dynamic_terms, &static_terms);
+        Some(partial_result.to_untwisted().add(&remainder))
+    }
+}
+
+/// Width-8 NAF tables for a fixed set of [`DecafPoint`]s, see [`VartimePrecomputedMultiscalarMul`].
+pub struct VartimeDecafPrecomputation(Vec<NafLookupTable8>);
+
+impl VartimePrecomputedMultiscalarMul for VartimeDecafPrecomputation {
+    type Point = DecafPoint;
+
+    fn new<I>(static_points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<DecafPoint>,
+    {
+        Self(
+            static_points
+                .into_iter()
+                .map(|p| NafLookupTable8::from(&p.borrow().0))
+                .collect(),
+        )
+    }
+
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<DecafPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<DecafPoint>>,
+    {
+        let static_scalars = static_scalars
+            .into_iter()
+            .map(|s| *s.borrow())
+            .collect::<Vec<_>>();
+        let dynamic_scalars = dynamic_scalars
+            .into_iter()
+            .map(|s| *s.borrow())
+            .collect::<Vec<_>>();
+        let dynamic_points = dynamic_points
+            .into_iter()
+            .map(|p| p.map(|p| p.0))
+            .collect::<Option<Vec<_>>>()?;
+        assert_eq!(static_scalars.len(), self.0.len());
+        assert_eq!(dynamic_scalars.len(), dynamic_points.len());
+
+        let static_terms = static_scalars
+            .into_iter()
+            .zip(self.0.iter())
+            .collect::<Vec<_>>();
+        let dynamic_terms = naf_terms(&dynamic_scalars, &dynamic_points);
+        Some(DecafPoint(vartime_straus(&dynamic_terms, &static_terms)))
+    }
+}
+
+/// Compare two public points, see [`EdwardsPoint::vartime_eq`].
+pub fn eq(a: &EdwardsPoint, b: &EdwardsPoint) -> bool {
+    a.vartime_eq(b)
+}
+
+/// Split `s` into `(s div 4, s mod 4)`
+fn split(s: &Scalar) -> (Scalar, u32) {
+    let mut q = *s;
+    q.div_by_four_floor();
+    (q, (s.0.as_words()[0] & 3) as u32)
+}
+
+fn small_multiple(point: &EdwardsPoint, r: u32) -> EdwardsPoint {
+    match r {
+        0 => EdwardsPoint::IDENTITY,
+        1 => *point,
+        2 => point.double(),
+        _ => point.double().add(point),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    // A point with a torsion component, to check the `4q + r` split
+    fn torsioned(p: &EdwardsPoint) -> EdwardsPoint {
+        let order_four = crate::AffinePoint {
+            x: crate::field::FieldElement::ONE,
+            y: crate::field::FieldElement::ZERO,
+        };
+        p.add(&order_four.to_edwards())
+    }
+
+    #[test]
+    fn matches_constant_time() {
+        let p = EdwardsPoint::GENERATOR * Scalar::from(1234u32);
+        for point in [p, torsioned(&p)] {
+            for _ in 0..4 {
+                let s = Scalar::random(&mut OsRng);
+                assert_eq!(mul(&point, &s), point * s);
+            }
+            assert_eq!(mul(&point, &Scalar::ZERO), EdwardsPoint::IDENTITY);
+            assert_eq!(mul(&point, &Scalar::from(3u8)), point.mul_u64(3));
+        }
+
+        let s = Scalar::random(&mut OsRng);
+        assert_eq!(mul_base(&s), EdwardsPoint::GENERATOR * s);
+    }
+
+    #[test]
+    fn double_and_multi() {
+        let a = Scalar::random(&mut OsRng);
+        let b = Scalar::random(&mut OsRng);
+        let A = torsioned(&(EdwardsPoint::GENERATOR * Scalar::from(99u8)));
+        assert_eq!(
+            double_scalar_mul_basepoint(&a, &A, &b),
+            A * a + EdwardsPoint::GENERATOR * b
+        );
+
+        let scalars = (0..5)
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let points = (1..6u8)
+            .map(|i| EdwardsPoint::GENERATOR * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .map(|(s, p)| p * s)
+            .sum::<EdwardsPoint>();
+        assert_eq!(multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(multiscalar_mul(&[], &[]), EdwardsPoint::IDENTITY);
+    }
+
+    #[test]
+    fn pippenger() {
+        let mut p = torsioned(&EdwardsPoint::GENERATOR);
+        let points = (0..PIPPENGER_THRESHOLD + 10)
+            .map(|_| {
+                p = p.double().add(&EdwardsPoint::GENERATOR);
+                p
+            })
+            .collect::<Vec<_>>();
+        let scalars = points
+            .iter()
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            multiscalar_mul(&scalars, &points),
+            EdwardsPoint::multiscalar_mul(&scalars, &points)
+        );
+    }
+}
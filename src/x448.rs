@@ -0,0 +1,417 @@
+//! X448 Diffie-Hellman key agreement, as specified in [RFC 7748 § 6.2].
+//!
+//! The API follows `x25519-dalek`: an [`EphemeralSecret`] is used for a single
+//! key agreement and consumed by it, a [`StaticSecret`] can be kept and reused,
+//! and both produce a [`PublicKey`] to send to the peer and a [`SharedSecret`]
+//! from the peer's public key.
+//!
+//! ```
+//! use ed448_goldilocks_plus::x448::{EphemeralSecret, PublicKey};
+//! use rand_core::OsRng;
+//!
+//! let alice = EphemeralSecret::random_from_rng(&mut OsRng);
+//! let alice_public = PublicKey::from(&alice);
+//! let bob = EphemeralSecret::random_from_rng(&mut OsRng);
+//! let bob_public = PublicKey::from(&bob);
+//!
+//! let alice_shared = alice.diffie_hellman(&bob_public);
+//! let bob_shared = bob.diffie_hellman(&alice_public);
+//! assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+//! ```
+//!
+//! The shared secret is a curve point, not a uniform key, hash it with a KDF before use.
+//!
+//! These are also the `448` DH functions of the [Noise Protocol Framework]:
+//! `GENERATE_KEYPAIR` is [`StaticSecret::random_from_rng`], `DH` is
+//! [`StaticSecret::diffie_hellman`] and `DHLEN` is [`KEY_LENGTH`], so a Noise
+//! library's DH trait is a thin wrapper around a [`StaticSecret`] and its [`PublicKey`].
+//!
+//! [Noise Protocol Framework]: https://noiseprotocol.org/noise.html
+//! [RFC 7748 § 6.2]: https://www.rfc-editor.org/rfc/rfc7748#section-6.2
+use crate::MontgomeryPoint;
+use rand_core::{CryptoRng, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use std::fmt::{self, Debug, Formatter};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+/// The length in bytes of X448 secret keys, public keys and shared secrets
+pub const KEY_LENGTH: usize = 56;
+
+/// The u-coordinate of the base point, 5
+pub const X448_BASEPOINT_BYTES: [u8; KEY_LENGTH] = MontgomeryPoint::generator().0;
+
+const DERIVE_DST: &[u8] = b"ed448-goldilocks-plus X448 derive_from_ikm SHAKE256";
+
+/// Clamp the bytes of an X448 secret key as in [RFC 7748 § 5]: clear the two lowest
+/// bits so the scalar is a multiple of the cofactor 4, and set the highest bit.
+///
+/// [RFC 7748 § 5]: https://www.rfc-editor.org/rfc/rfc7748#section-5
+pub const fn clamp_integer(mut bytes: [u8; KEY_LENGTH]) -> [u8; KEY_LENGTH] {
+    bytes[0] &= 252;
+    bytes[55] |= 128;
+    bytes
+}
+
+/// The X448 function of RFC 7748: clamp the scalar `k` and multiply the point with u-coordinate `u`.
+///
+/// This is the raw function for protocols and test vectors that need it,
+/// prefer the key types for key agreement.
+pub fn x448(k: [u8; KEY_LENGTH], u: [u8; KEY_LENGTH]) -> [u8; KEY_LENGTH] {
+    MontgomeryPoint(u).mul_clamped(k).0
+}
+
+/// An X448 secret key for a single key agreement.
+///
+/// [`EphemeralSecret::diffie_hellman`] consumes the secret, so it can't be reused by mistake.
+pub struct EphemeralSecret([u8; KEY_LENGTH]);
+
+impl Debug for EphemeralSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EphemeralSecret").finish_non_exhaustive()
+    }
+}
+
+impl EphemeralSecret {
+    /// Generate a fresh random secret.
+    pub fn random_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; KEY_LENGTH];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Compute the shared secret with the peer's public key.
+    pub fn diffie_hellman(self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret(their_public.0.mul_clamped(self.0))
+    }
+
+    /// Compute the shared secret with the peer's public key,
+    /// which fails if it was not contributory, see [`SharedSecret::was_contributory`].
+    pub fn diffie_hellman_checked(self, their_public: &PublicKey) -> CtOption<SharedSecret> {
+        self.diffie_hellman(their_public).checked()
+    }
+}
+
+/// An X448 secret key that can be used for many key agreements.
+///
+/// Prefer [`EphemeralSecret`] unless the key really is long-lived.
+#[derive(Clone)]
+pub struct StaticSecret([u8; KEY_LENGTH]);
+
+impl Debug for StaticSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticSecret").finish_non_exhaustive()
+    }
+}
+
+impl From<[u8; KEY_LENGTH]> for StaticSecret {
+    fn from(bytes: [u8; KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl StaticSecret {
+    /// Generate a fresh random secret.
+    pub fn random_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; KEY_LENGTH];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Derive a secret from the input keying material `ikm`, bound to the label `info`.
+    ///
+    /// This is the X448 counterpart of [`SigningKey::derive_from_ikm`](crate::SigningKey::derive_from_ikm),
+    /// with its own domain separation so the two never derive related keys.
+    pub fn derive_from_ikm(ikm: &[u8], info: &[u8]) -> Self {
+        let mut bytes = [0u8; KEY_LENGTH];
+        Shake256::default()
+            .chain(DERIVE_DST)
+            .chain((ikm.len() as u64).to_le_bytes())
+            .chain(ikm)
+            .chain((info.len() as u64).to_le_bytes())
+            .chain(info)
+            .finalize_xof()
+            .read(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Compute the shared secret with the peer's public key.
+    pub fn diffie_hellman(&self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret(their_public.0.mul_clamped(self.0))
+    }
+
+    /// Compute the shared secret with the peer's public key,
+    /// which fails if it was not contributory, see [`SharedSecret::was_contributory`].
+    pub fn diffie_hellman_checked(&self, their_public: &PublicKey) -> CtOption<SharedSecret> {
+        self.diffie_hellman(their_public).checked()
+    }
+
+    /// The unclamped bytes of this secret
+    pub fn to_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.0
+    }
+
+    /// View the unclamped bytes of this secret
+    pub fn as_bytes(&self) -> &[u8; KEY_LENGTH] {
+        &self.0
+    }
+}
+
+/// An X448 public key, the u-coordinate of `[k]G`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicKey(pub(crate) MontgomeryPoint);
+
+impl From<[u8; KEY_LENGTH]> for PublicKey {
+    fn from(bytes: [u8; KEY_LENGTH]) -> Self {
+        Self(MontgomeryPoint(bytes))
+    }
+}
+
+impl From<MontgomeryPoint> for PublicKey {
+    fn from(point: MontgomeryPoint) -> Self {
+        Self(point)
+    }
+}
+
+impl From<&EphemeralSecret> for PublicKey {
+    fn from(secret: &EphemeralSecret) -> Self {
+        Self(MontgomeryPoint::generator().mul_clamped(secret.0))
+    }
+}
+
+impl From<&StaticSecret> for PublicKey {
+    fn from(secret: &StaticSecret) -> Self {
+        Self(MontgomeryPoint::generator().mul_clamped(secret.0))
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl PublicKey {
+    /// The 56 bytes of the u-coordinate
+    pub fn to_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.0 .0
+    }
+
+    /// View the 56 bytes of the u-coordinate
+    pub fn as_bytes(&self) -> &[u8; KEY_LENGTH] {
+        self.0.as_bytes()
+    }
+}
+
+/// The result of a key agreement.
+pub struct SharedSecret(MontgomeryPoint);
+
+impl Debug for SharedSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+impl SharedSecret {
+    /// Whether the peer's public key contributed to the shared secret.
+    ///
+    /// A low order public key gives the all-zero shared secret whatever our secret is,
+    /// which [RFC 7748 § 6.2] says protocols may want to reject, for example when the
+    /// shared secret should bind both parties.
+    ///
+    /// [RFC 7748 § 6.2]: https://www.rfc-editor.org/rfc/rfc7748#section-6.2
+    pub fn was_contributory(&self) -> bool {
+        (!self.is_zero()).into()
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0 .0.ct_eq(&[0u8; KEY_LENGTH])
+    }
+
+    fn checked(self) -> CtOption<Self> {
+        let is_zero = self.is_zero();
+        CtOption::new(self, !is_zero)
+    }
+
+    /// The 56 bytes of the shared secret
+    pub fn to_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.0 .0
+    }
+
+    /// View the 56 bytes of the shared secret
+    pub fn as_bytes(&self) -> &[u8; KEY_LENGTH] {
+        self.0.as_bytes()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+macro_rules! zeroize_on_drop {
+    ($($ty:ident),+) => {$(
+        impl zeroize::Zeroize for $ty {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        impl Drop for $ty {
+            fn drop(&mut self) {
+                zeroize::Zeroize::zeroize(self);
+            }
+        }
+
+        impl zeroize::ZeroizeOnDrop for $ty {}
+    )+};
+}
+
+#[cfg(feature = "zeroize")]
+zeroize_on_drop!(EphemeralSecret, StaticSecret, SharedSecret);
+
+/// SubjectPublicKeyInfo encoding of public keys with the id-X448 identifier of RFC 8410.
+#[cfg(feature = "pkcs8")]
+mod spki {
+    use super::{PublicKey, KEY_LENGTH};
+    use pkcs8::{
+        der::{asn1::BitStringRef, Document},
+        spki::{AlgorithmIdentifierRef, EncodePublicKey, Error, Result, SubjectPublicKeyInfoRef},
+        ObjectIdentifier,
+    };
+
+    const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.111");
+
+    impl EncodePublicKey for PublicKey {
+        fn to_public_key_der(&self) -> Result<Document> {
+            let public_key_info = SubjectPublicKeyInfoRef {
+                algorithm: AlgorithmIdentifierRef {
+                    oid: ALGORITHM_OID,
+                    parameters: None,
+                },
+                subject_public_key: BitStringRef::from_bytes(self.as_bytes())?,
+            };
+            Ok(Document::encode_msg(&public_key_info)?)
+        }
+    }
+
+    impl TryFrom<SubjectPublicKeyInfoRef<'_>> for PublicKey {
+        type Error = Error;
+
+        fn try_from(info: SubjectPublicKeyInfoRef<'_>) -> Result<Self> {
+            info.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+            if info.algorithm.parameters.is_some() {
+                return Err(Error::KeyMalformed);
+            }
+            let bytes = info
+                .subject_public_key
+                .as_bytes()
+                .ok_or(Error::KeyMalformed)?;
+            let bytes = <[u8; KEY_LENGTH]>::try_from(bytes).map_err(|_| Error::KeyMalformed)?;
+            Ok(PublicKey::from(bytes))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    // RFC 7748 § 6.2
+    const ALICE: [u8; 56] = hex!("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b");
+    const ALICE_PUBLIC: [u8; 56] = hex!("9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0");
+    const BOB: [u8; 56] = hex!("1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d");
+    const BOB_PUBLIC: [u8; 56] = hex!("3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609");
+    const SHARED: [u8; 56] = hex!("07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d");
+
+    #[test]
+    fn rfc7748_key_agreement() {
+        let alice = StaticSecret::from(ALICE);
+        let bob = StaticSecret::from(BOB);
+        assert_eq!(PublicKey::from(&alice).to_bytes(), ALICE_PUBLIC);
+        assert_eq!(PublicKey::from(&bob).to_bytes(), BOB_PUBLIC);
+        assert_eq!(
+            alice
+                .diffie_hellman(&PublicKey::from(BOB_PUBLIC))
+                .to_bytes(),
+            SHARED
+        );
+        assert_eq!(
+            bob.diffie_hellman(&PublicKey::from(ALICE_PUBLIC))
+                .to_bytes(),
+            SHARED
+        );
+        assert_eq!(x448(ALICE, BOB_PUBLIC), SHARED);
+        assert_eq!(x448(ALICE, X448_BASEPOINT_BYTES), ALICE_PUBLIC);
+    }
+
+    #[test]
+    fn ephemeral() {
+        let alice = EphemeralSecret::random_from_rng(&mut OsRng);
+        let bob = StaticSecret::random_from_rng(&mut OsRng);
+        let alice_public = PublicKey::from(&alice);
+        let shared = alice.diffie_hellman(&PublicKey::from(&bob));
+        assert_eq!(
+            shared.as_bytes(),
+            bob.diffie_hellman(&alice_public).as_bytes()
+        );
+    }
+
+    #[test]
+    fn contributory() {
+        let secret = StaticSecret::random_from_rng(&mut OsRng);
+        let peer = PublicKey::from(&StaticSecret::random_from_rng(&mut OsRng));
+        assert!(secret.diffie_hellman(&peer).was_contributory());
+        assert!(bool::from(secret.diffie_hellman_checked(&peer).is_some()));
+
+        // The low order points 0, 1 and -1
+        let mut minus_one = [0xffu8; 56];
+        minus_one[0] = 0xfe;
+        minus_one[28] = 0xfe;
+        let mut one = [0u8; 56];
+        one[0] = 1;
+        for low_order in [[0u8; 56], one, minus_one] {
+            let low_order = PublicKey::from(low_order);
+            assert!(!secret.diffie_hellman(&low_order).was_contributory());
+            assert!(bool::from(
+                secret.diffie_hellman_checked(&low_order).is_none()
+            ));
+            let ephemeral = EphemeralSecret::random_from_rng(&mut OsRng);
+            assert!(bool::from(
+                ephemeral.diffie_hellman_checked(&low_order).is_none()
+            ));
+        }
+    }
+
+    #[test]
+    fn derive_from_ikm() {
+        let a = StaticSecret::derive_from_ikm(b"master secret", b"device 1");
+        let b = StaticSecret::derive_from_ikm(b"master secret", b"device 2");
+        assert_eq!(
+            a.as_bytes(),
+            StaticSecret::derive_from_ikm(b"master secret", b"device 1").as_bytes()
+        );
+        assert_ne!(a.as_bytes(), b.as_bytes());
+        // The length prefixes keep the split between ikm and info unambiguous
+        assert_ne!(
+            StaticSecret::derive_from_ikm(b"ab", b"c").as_bytes(),
+            StaticSecret::derive_from_ikm(b"a", b"bc").as_bytes()
+        );
+    }
+
+    #[cfg(feature = "pkcs8")]
+    #[test]
+    fn spki() {
+        use pkcs8::{DecodePublicKey, EncodePublicKey};
+
+        let mut der = hex!("3042300506032b656f033900").to_vec();
+        der.extend_from_slice(&ALICE_PUBLIC);
+        let key = PublicKey::from(ALICE_PUBLIC);
+        assert_eq!(key.to_public_key_der().unwrap().as_bytes(), &der[..]);
+        assert_eq!(PublicKey::from_public_key_der(&der).unwrap(), key);
+
+        // id-Ed448
+        der[11] = 0x71;
+        assert!(PublicKey::from_public_key_der(&der).is_err());
+    }
+}
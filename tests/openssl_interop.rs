@@ -0,0 +1,169 @@
+//! Differential tests against OpenSSL's Ed448 and X448 implementations.
+//!
+//! Run with `cargo test --features openssl-interop`.
+#![cfg(feature = "openssl-interop")]
+
+use ed448_goldilocks_plus::{
+    x448, CompressedEdwardsY, MontgomeryPoint, Signature, SigningKey, VerifyingKey,
+};
+use openssl::{
+    derive::Deriver,
+    pkey::{Id, PKey},
+    sign::{Signer, Verifier},
+};
+
+const ITERATIONS: usize = 16;
+
+fn x448(k: &[u8], u: &[u8]) -> [u8; 56] {
+    x448::x448(k.try_into().unwrap(), u.try_into().unwrap())
+}
+
+fn ed448_public_key(seed: &[u8]) -> CompressedEdwardsY {
+    let key = SigningKey::try_from(seed).unwrap();
+    CompressedEdwardsY(key.verifying_key().to_bytes())
+}
+
+#[test]
+fn x448_public_keys_match() {
+    for _ in 0..ITERATIONS {
+        let key = PKey::generate_x448().unwrap();
+        let secret = key.raw_private_key().unwrap();
+        let public = key.raw_public_key().unwrap();
+        assert_eq!(
+            x448(&secret, MontgomeryPoint::generator().as_bytes()),
+            &public[..]
+        );
+    }
+}
+
+#[test]
+fn x448_shared_secrets_match() {
+    for _ in 0..ITERATIONS {
+        let alice = PKey::generate_x448().unwrap();
+        let bob = PKey::generate_x448().unwrap();
+
+        let mut deriver = Deriver::new(&alice).unwrap();
+        deriver.set_peer(&bob).unwrap();
+        let expected = deriver.derive_to_vec().unwrap();
+
+        let alice_secret = alice.raw_private_key().unwrap();
+        let bob_public = bob.raw_public_key().unwrap();
+        assert_eq!(x448(&alice_secret, &bob_public), &expected[..]);
+
+        // And the other direction, with our public key handed to OpenSSL
+        let bob_secret = bob.raw_private_key().unwrap();
+        let alice_public = x448(&alice_secret, MontgomeryPoint::generator().as_bytes());
+        let alice_public = PKey::public_key_from_raw_bytes(&alice_public, Id::X448).unwrap();
+        let bob = PKey::private_key_from_raw_bytes(&bob_secret, Id::X448).unwrap();
+        let mut deriver = Deriver::new(&bob).unwrap();
+        deriver.set_peer(&alice_public).unwrap();
+        assert_eq!(deriver.derive_to_vec().unwrap(), expected);
+    }
+}
+
+#[test]
+fn x448_key_types_match() {
+    for _ in 0..ITERATIONS {
+        let alice = PKey::generate_x448().unwrap();
+        let bob = PKey::generate_x448().unwrap();
+        let mut deriver = Deriver::new(&alice).unwrap();
+        deriver.set_peer(&bob).unwrap();
+        let expected = deriver.derive_to_vec().unwrap();
+
+        let secret: [u8; 56] = alice.raw_private_key().unwrap().try_into().unwrap();
+        let secret = x448::StaticSecret::from(secret);
+        assert_eq!(
+            &x448::PublicKey::from(&secret).to_bytes()[..],
+            &alice.raw_public_key().unwrap()[..]
+        );
+        let bob_public: [u8; 56] = bob.raw_public_key().unwrap().try_into().unwrap();
+        let shared = secret.diffie_hellman(&x448::PublicKey::from(bob_public));
+        assert_eq!(&shared.as_bytes()[..], &expected[..]);
+    }
+}
+
+#[test]
+fn ed448_public_keys_match() {
+    for _ in 0..ITERATIONS {
+        let key = PKey::generate_ed448().unwrap();
+        let seed = key.raw_private_key().unwrap();
+        let public = key.raw_public_key().unwrap();
+
+        let ours = ed448_public_key(&seed);
+        assert_eq!(&ours.0[..], &public[..]);
+
+        let decoded = CompressedEdwardsY(public.try_into().unwrap());
+        assert_eq!(decoded.decompress().unwrap().compress(), ours);
+        assert!(PKey::public_key_from_raw_bytes(&ours.0, Id::ED448).is_ok());
+    }
+}
+
+#[test]
+fn ed448_signatures_match() {
+    for i in 0..ITERATIONS {
+        let key = PKey::generate_ed448().unwrap();
+        let ours = SigningKey::try_from(&key.raw_private_key().unwrap()[..]).unwrap();
+        let msg = vec![i as u8; i * 13];
+
+        // Signing is deterministic, so both sides produce the same bytes
+        let mut signer = Signer::new_without_digest(&key).unwrap();
+        let expected = signer.sign_oneshot_to_vec(&msg).unwrap();
+        let signature = ours.sign(&msg);
+        assert_eq!(&signature.to_bytes()[..], &expected[..]);
+
+        let public = VerifyingKey::try_from(&key.raw_public_key().unwrap()[..]).unwrap();
+        let theirs = Signature::try_from(&expected[..]).unwrap();
+        assert!(public.verify(&msg, &theirs).is_ok());
+
+        let mut verifier = Verifier::new_without_digest(&key).unwrap();
+        assert!(verifier
+            .verify_oneshot(&signature.to_bytes(), &msg)
+            .unwrap());
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn ed448_pkcs8_matches() {
+    use ed448_goldilocks_plus::pkcs8::{DecodePrivateKey, EncodePrivateKey};
+
+    for _ in 0..ITERATIONS {
+        let key = PKey::generate_ed448().unwrap();
+        let der = key.private_key_to_pkcs8().unwrap();
+        let ours = SigningKey::from_pkcs8_der(&der).unwrap();
+        assert_eq!(&ours.to_bytes()[..], &key.raw_private_key().unwrap()[..]);
+
+        let encoded = ours.to_pkcs8_der().unwrap();
+        assert_eq!(encoded.as_bytes(), &der[..]);
+        let theirs = PKey::private_key_from_pkcs8(encoded.as_bytes()).unwrap();
+        assert_eq!(theirs.id(), Id::ED448);
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn ed448_spki_matches() {
+    use ed448_goldilocks_plus::pkcs8::{DecodePublicKey, EncodePublicKey};
+
+    for _ in 0..ITERATIONS {
+        let key = PKey::generate_ed448().unwrap();
+        let der = key.public_key_to_der().unwrap();
+        let ours = VerifyingKey::from_public_key_der(&der).unwrap();
+        assert_eq!(&ours.to_bytes()[..], &key.raw_public_key().unwrap()[..]);
+        assert_eq!(ours.to_public_key_der().unwrap().as_bytes(), &der[..]);
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn x448_spki_matches() {
+    use ed448_goldilocks_plus::pkcs8::{DecodePublicKey, EncodePublicKey};
+
+    for _ in 0..ITERATIONS {
+        let key = PKey::generate_x448().unwrap();
+        let der = key.public_key_to_der().unwrap();
+        let ours = x448::PublicKey::from_public_key_der(&der).unwrap();
+        assert_eq!(&ours.to_bytes()[..], &key.raw_public_key().unwrap()[..]);
+        assert_eq!(ours.to_public_key_der().unwrap().as_bytes(), &der[..]);
+    }
+}